@@ -49,6 +49,7 @@ impl Default for CaptureState {
 fn main() {
     // Create screenshots directory
     std::fs::create_dir_all("screenshots").expect("Failed to create screenshots directory");
+    let settings = GameSettings::load();
 
     App::new()
         .add_plugins(
@@ -68,8 +69,11 @@ fn main() {
                 })
                 .set(ImagePlugin::default_nearest()),
         )
-        // Core resources and startup (reuse game's CorePlugin setup)
-        .insert_resource(ClearColor(Color::srgb(0.07, 0.07, 0.16)))
+        // Core resources and startup (reuse game's CorePlugin setup); captures use the saved
+        // palette, glyph and high-contrast settings
+        .insert_resource(ClearColor(settings.background_color()))
+        .insert_resource(settings)
+        .init_resource::<PowerUpRegistry>()
         .init_resource::<Score>()
         .init_resource::<Level>()
         .init_resource::<GameSounds>()
@@ -77,6 +81,9 @@ fn main() {
         .init_resource::<ComboTracker>()
         .insert_resource(HighScores::default())
         .init_resource::<LevelStats>()
+        .init_resource::<Lives>()
+        .init_resource::<BonusLetters>()
+        .init_resource::<DropRng>()
         .init_resource::<CaptureState>()
         .init_state::<GameState>()
         .add_event::<CollisionEvent>()
@@ -89,12 +96,22 @@ fn main() {
         )
         // Keep level text updated so HUD shows correct level number
         .add_systems(Update, update_level_text)
+        // Palette, wall colors and glyph overlays, as in the game
+        .add_systems(
+            Update,
+            (
+                apply_display_settings,
+                apply_block_palette,
+                (label_glyph_overlays, update_glyph_overlays).chain(),
+            ),
+        )
         // Orchestration system runs every frame
         .add_systems(Update, orchestrate_capture)
         .run();
 }
 
 /// Orchestration system: waits for rendering, captures screenshot, advances level
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 fn orchestrate_capture(
     mut commands: Commands,
     mut capture: ResMut<CaptureState>,
@@ -1,14 +1,123 @@
 use bevy::prelude::*;
 use serde::{Serialize, Deserialize};
 
+use std::collections::VecDeque;
+
+use crate::constants::{STUCK_CONTACT_HISTORY, STUCK_CONTACT_RADIUS, VERSUS_FIELD_SPACING};
+
 /// Paddle marker component
 #[derive(Component)]
+#[require(Player, PlayfieldId, PaddleVelocity)]
 pub struct Paddle;
 
+/// Horizontal paddle speed over the last frame (world units/sec), passed to the ball as english
+#[derive(Component, Clone, Copy, Default, PartialEq, Debug)]
+pub struct PaddleVelocity(pub f32);
+
+/// Owning player: controls a paddle, or is credited for a ball's or laser's blocks (0 outside co-op)
+#[derive(Component, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct Player(pub usize);
+
+/// Playfield an entity belongs to (0 outside versus); entities only interact within their field
+#[derive(Component, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct PlayfieldId(pub usize);
+
+impl PlayfieldId {
+    /// World X of the field's center (fields sit side by side, each seen by its own camera)
+    pub fn origin_x(self) -> f32 {
+        self.0 as f32 * VERSUS_FIELD_SPACING
+    }
+}
+
 /// Ball marker component
 #[derive(Component)]
+#[require(PlayfieldId, BallIdle, BallKind, BlockContacts)]
 pub struct Ball;
 
+/// Time since a ball last touched a paddle or a breakable block, and where it has bounced since,
+/// for stuck-loop detection
+#[derive(Component, Default, Debug)]
+pub struct BallIdle {
+    pub secs: f32,
+    /// Nudges given since the last real contact (alternates the nudge direction)
+    pub nudges: u32,
+    /// Recent wall / unbreakable-block bounce positions, oldest first
+    pub contacts: VecDeque<Vec2>,
+}
+
+impl BallIdle {
+    /// Real progress: a paddle or breakable-block contact
+    pub fn reset(&mut self) {
+        self.secs = 0.0;
+        self.nudges = 0;
+        self.contacts.clear();
+    }
+
+    /// Remember a bounce that made no progress; a ball still touching its last contact is skipped
+    pub fn record(&mut self, pos: Vec2) {
+        if self.contacts.back().is_some_and(|last| last.distance(pos) < STUCK_CONTACT_RADIUS) {
+            return;
+        }
+        if self.contacts.len() == STUCK_CONTACT_HISTORY {
+            self.contacts.pop_front();
+        }
+        self.contacts.push_back(pos);
+    }
+
+    /// The latest bounce landed where an earlier one did: the ball is retracing a loop
+    pub fn repeating(&self) -> bool {
+        let Some(last) = self.contacts.back() else {
+            return false;
+        };
+        self.contacts.iter().rev().skip(1).any(|pos| pos.distance(*last) < STUCK_CONTACT_RADIUS)
+    }
+}
+
+/// How a ball treats blocks; granted by power-ups or set per level
+#[derive(Component, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum BallKind {
+    #[default]
+    Normal,
+    /// Breaks Durable blocks in one hit, but is pulled down by gravity
+    Heavy,
+    /// Speeds up on every block hit
+    Bouncy,
+    /// Splits in two on its first block hit (both halves become Normal)
+    Splitter,
+    /// Passes through every other breakable block it touches
+    Ghost,
+}
+
+impl BallKind {
+    pub fn color(self) -> Color {
+        match self {
+            BallKind::Normal => Color::srgb(1.0, 0.96, 0.88),   // Warm white
+            BallKind::Heavy => Color::srgb(0.55, 0.58, 0.65),    // Iron grey
+            BallKind::Bouncy => Color::srgb(0.45, 0.95, 0.45),   // Lime
+            BallKind::Splitter => Color::srgb(0.95, 0.80, 0.30), // Gold
+            BallKind::Ghost => Color::srgb(0.78, 0.72, 1.0),     // Pale violet
+        }
+    }
+
+    /// Trail particle color (alpha is set by the trail)
+    pub fn trail_color(self) -> Color {
+        match self {
+            BallKind::Normal => Color::srgb(1.0, 0.96, 0.88),
+            BallKind::Heavy => Color::srgb(0.35, 0.36, 0.42),
+            BallKind::Bouncy => Color::srgb(0.60, 1.0, 0.55),
+            BallKind::Splitter => Color::srgb(1.0, 0.65, 0.20),
+            BallKind::Ghost => Color::srgb(0.60, 0.50, 0.95),
+        }
+    }
+}
+
+/// Breakable-block contacts a ball has made, and the block a ghost ball is currently passing through
+#[derive(Component, Default, Debug)]
+pub struct BlockContacts {
+    pub count: u32,
+    pub passing: Option<Entity>,
+}
+
 /// Velocity component for moving entities
 #[derive(Component)]
 pub struct Velocity(pub Vec2);
@@ -22,12 +131,48 @@ pub enum BlockType {
     Explosive,
 }
 
-/// Block component with type information
+/// Block footprint in grid cells (columns x rows)
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct BlockSpan {
+    pub cols: usize,
+    pub rows: usize,
+}
+
+impl BlockSpan {
+    /// Standard single-cell block
+    pub const SINGLE: Self = Self { cols: 1, rows: 1 };
+
+    pub const fn new(cols: usize, rows: usize) -> Self {
+        Self { cols, rows }
+    }
+
+    /// Whether this span covers more than one cell
+    pub fn is_multi_cell(&self) -> bool {
+        self.cols > 1 || self.rows > 1
+    }
+}
+
+impl Default for BlockSpan {
+    fn default() -> Self {
+        Self::SINGLE
+    }
+}
+
+/// Block component with type information (size lives in the Collider)
 #[derive(Component)]
+#[require(PlayfieldId, BlockRow)]
 pub struct Block {
     pub block_type: BlockType,
 }
 
+/// Grid row a block was laid out on (picks its color in the row-striped palettes)
+#[derive(Component, Clone, Copy, Default, Debug)]
+pub struct BlockRow(pub usize);
+
+/// Glyph text drawn over its parent block or power-up capsule (see `GameSettings::glyphs`)
+#[derive(Component)]
+pub struct GlyphOverlay;
+
 /// Combo popup UI marker
 #[derive(Component)]
 pub struct ComboPopup {
@@ -36,6 +181,7 @@ pub struct ComboPopup {
 
 /// Wall type for collision handling
 #[derive(Component)]
+#[require(PlayfieldId)]
 pub enum Wall {
     Top,
     Left,
@@ -73,6 +219,34 @@ pub struct LevelClearUI;
 #[derive(Component)]
 pub struct BgmMusic;
 
+/// Push a field zone gives balls and falling items inside it
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum FieldForce {
+    /// Constant acceleration in px/s² (gravity pulls down, wind pushes sideways)
+    Accel(Vec2),
+    /// Factor on how far things move per frame while inside (below 1 slows, above 1 speeds up)
+    Speed(f32),
+}
+
+impl FieldForce {
+    /// Tint of the zone's background and motes
+    pub fn color(self) -> Color {
+        match self {
+            FieldForce::Accel(a) if a.y < 0.0 => Color::srgb(0.60, 0.55, 1.0), // Violet (gravity)
+            FieldForce::Accel(_) => Color::srgb(0.80, 0.95, 1.0),             // Pale cyan (wind)
+            FieldForce::Speed(s) if s < 1.0 => Color::srgb(0.40, 0.60, 1.0),  // Blue (slow)
+            FieldForce::Speed(_) => Color::srgb(1.0, 0.55, 0.30),             // Orange (fast)
+        }
+    }
+}
+
+/// Physics region from the level data, centered on its Transform
+#[derive(Component, Clone, Copy, Debug)]
+pub struct FieldZone {
+    pub force: FieldForce,
+    pub size: Vec2,
+}
+
 /// Particle component for block destruction effects and ball trail
 #[derive(Component)]
 pub struct Particle {
@@ -88,14 +262,110 @@ pub enum PowerUpType {
     MultiBall,
     SlowBall,
     FireBall,
+    Laser,
+    Catch,
+    Barrier,
+    ExtraLife,
+    Magnet,
+    // Ball kinds (instant: change every ball in play)
+    HeavyBall,
+    BouncyBall,
+    SplitBall,
+    GhostBall,
+    // Hazards
+    ShrinkPaddle,
+    FastBall,
+    ReverseControls,
+    Fog,
+}
+
+impl PowerUpType {
+    /// Acts on the balls or fog a whole field shares rather than on the paddle that picked it up
+    pub fn field_wide(self) -> bool {
+        matches!(self, PowerUpType::SlowBall | PowerUpType::FastBall | PowerUpType::Fog)
+    }
 }
 
 /// Marker component for falling power-up items
 #[derive(Component)]
+#[require(PlayfieldId)]
 pub struct PowerUp {
     pub power_type: PowerUpType,
 }
 
+/// Score-only pickup kinds (no timed effect)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PickupKind {
+    Coin,
+    Gem,
+    /// Index into `BONUS_WORD`
+    Letter(usize),
+}
+
+/// Falling score pickup dropped from blocks
+#[derive(Component)]
+#[require(PlayfieldId)]
+pub struct Pickup {
+    pub kind: PickupKind,
+}
+
+/// Collected bonus word letters UI marker
+#[derive(Component)]
+pub struct BonusWordText;
+
+/// Laser projectile fired from the paddle
+#[derive(Component)]
+#[require(PlayfieldId)]
+pub struct Laser;
+
+/// Temporary safety bar above the bottom edge; bounces balls once then vanishes
+#[derive(Component)]
+#[require(PlayfieldId)]
+pub struct Barrier {
+    pub timer: Timer,
+}
+
+/// Spare lives text UI marker
+#[derive(Component)]
+pub struct LivesText;
+
+/// HUD strip listing active power-up effects
+#[derive(Component)]
+pub struct PowerUpHud {
+    pub font: Handle<Font>,
+    /// Player whose paddle effects the strip lists
+    pub player: usize,
+}
+
+/// One active effect entry in the power-up HUD
+#[derive(Component)]
+pub struct PowerUpHudRow {
+    pub effect_type: PowerUpType,
+    pub player: usize,
+}
+
+/// Draining timer bar of a power-up HUD entry
+#[derive(Component)]
+pub struct PowerUpHudBar {
+    pub effect_type: PowerUpType,
+    pub player: usize,
+}
+
+/// Fog overlay hiding the upper block rows
+#[derive(Component)]
+#[require(PlayfieldId)]
+pub struct Fog;
+
+/// Ball held on the paddle by the catch power-up
+#[derive(Component)]
+pub struct CaughtBall {
+    /// Paddle holding the ball
+    pub paddle: Entity,
+    /// Horizontal offset from the paddle center at contact
+    pub offset: f32,
+    pub hold: Timer,
+}
+
 /// Single active power-up effect entry
 pub struct ActiveEffect {
     pub effect_type: PowerUpType,
@@ -126,18 +396,12 @@ pub struct PowerUpEffects {
 #[derive(Component)]
 pub struct SettingsUI;
 
-/// Settings screen: currently selected row (0=BGM, 1=SFX)
-#[derive(Resource)]
+/// Pause overlay: currently selected volume row (0=BGM, 1=SFX)
+#[derive(Resource, Default)]
 pub struct SettingsSelection {
     pub index: usize,
 }
 
-impl Default for SettingsSelection {
-    fn default() -> Self {
-        Self { index: 0 }
-    }
-}
-
 /// Settings screen: BGM volume text marker
 #[derive(Component)]
 pub struct SettingsBgmText;
@@ -154,6 +418,159 @@ pub struct SettingsCursor;
 #[derive(Component)]
 pub struct SettingsButton;
 
+/// Settings screen row, in the option list
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SettingsOption {
+    BgmVolume,
+    SfxVolume,
+    ScreenShake,
+    Particles,
+    BallTrail,
+    PaddleSpeed,
+    HighContrast,
+    BlockPalette,
+    BlockGlyphs,
+    ReducedFlashing,
+    StickDeadzone,
+    StickAcceleration,
+    MouseMode,
+    MouseSensitivity,
+    /// Opens the key binding screen
+    Controls,
+}
+
+impl SettingsOption {
+    /// Rows in screen order
+    pub const ALL: [SettingsOption; 15] = [
+        SettingsOption::BgmVolume,
+        SettingsOption::SfxVolume,
+        SettingsOption::ScreenShake,
+        SettingsOption::Particles,
+        SettingsOption::BallTrail,
+        SettingsOption::PaddleSpeed,
+        SettingsOption::HighContrast,
+        SettingsOption::BlockPalette,
+        SettingsOption::BlockGlyphs,
+        SettingsOption::ReducedFlashing,
+        SettingsOption::StickDeadzone,
+        SettingsOption::StickAcceleration,
+        SettingsOption::MouseMode,
+        SettingsOption::MouseSensitivity,
+        SettingsOption::Controls,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SettingsOption::BgmVolume => "BGM",
+            SettingsOption::SfxVolume => "効果音",
+            SettingsOption::ScreenShake => "画面の揺れ",
+            SettingsOption::Particles => "パーティクル",
+            SettingsOption::BallTrail => "ボールの軌跡",
+            SettingsOption::PaddleSpeed => "パドル速度",
+            SettingsOption::HighContrast => "ハイコントラスト",
+            SettingsOption::BlockPalette => "ブロック配色",
+            SettingsOption::BlockGlyphs => "ブロックの記号",
+            SettingsOption::ReducedFlashing => "点滅を抑える",
+            SettingsOption::StickDeadzone => "スティック遊び",
+            SettingsOption::StickAcceleration => "スティック加速",
+            SettingsOption::MouseMode => "マウス操作",
+            SettingsOption::MouseSensitivity => "マウス感度",
+            SettingsOption::Controls => "キー設定",
+        }
+    }
+}
+
+/// Scrolling list of option rows (see `spawn_option_list`); `selected` is the highlighted row
+#[derive(Component, Default, Debug)]
+pub struct OptionList {
+    pub selected: usize,
+    pub len: usize,
+}
+
+impl OptionList {
+    /// Move the selection by `rows`, staying on the list
+    pub fn select_by(&mut self, rows: i32) {
+        let last = self.len.saturating_sub(1) as i32;
+        self.selected = (self.selected as i32 + rows).clamp(0, last) as usize;
+    }
+}
+
+/// Option list: selection cursor of a row
+#[derive(Component)]
+pub struct OptionCursor(pub usize);
+
+/// Option list: value text of a row
+#[derive(Component)]
+pub struct OptionValue(pub usize);
+
+/// Option list: tappable < / > arrow stepping `row` by `step`
+#[derive(Component)]
+pub struct OptionStep {
+    pub row: usize,
+    pub step: i32,
+}
+
+/// Option list: tappable action row (a row without a value)
+#[derive(Component)]
+pub struct OptionActivate(pub usize);
+
+/// Option list: tappable ▲ / ▼ hint moving the selection, shown while rows are hidden that way
+#[derive(Component)]
+pub struct OptionScroll(pub i32);
+
+/// Menu button reachable with the gamepad focus cursor
+#[derive(Component)]
+pub struct MenuFocusable;
+
+/// Short-lived gamepad connect / disconnect notice
+#[derive(Component)]
+pub struct GamepadNotice {
+    pub timer: Timer,
+}
+
+/// Key binding screen UI marker
+#[derive(Component)]
+pub struct ControlsUI;
+
+/// Key binding screen: selected action row and key slot, and whether a new key is awaited
+#[derive(Resource, Default)]
+pub struct ControlsSelection {
+    pub row: usize,
+    pub slot: usize,
+    pub waiting: bool,
+}
+
+/// Key binding screen: selection cursor for one action row
+#[derive(Component)]
+pub struct ControlsCursor(pub usize);
+
+/// Key binding screen: tappable key slot of one action row
+#[derive(Component, Clone, Copy)]
+pub struct ControlsSlot {
+    pub row: usize,
+    pub slot: usize,
+}
+
+/// Key binding screen: scrolling container of the action rows
+#[derive(Component)]
+pub struct ControlsList;
+
+/// Key binding screen: tappable ▲ / ▼ hint moving the selection, shown while rows are hidden that way
+#[derive(Component)]
+pub struct ControlsScroll(pub i32);
+
+/// Key binding screen: status / conflict message
+#[derive(Component)]
+pub struct ControlsMessage;
+
+/// Key binding screen: restore every action's factory keys
+#[derive(Component)]
+pub struct ControlsResetButton;
+
+/// Key binding screen: back to settings
+#[derive(Component)]
+pub struct ControlsBackButton;
+
 /// Pause overlay UI marker
 #[derive(Component)]
 pub struct PauseUI;
@@ -193,10 +610,21 @@ pub struct GridCell {
     pub col: usize,
 }
 
+/// Glyph text inside an editor grid cell (row, col)
+#[derive(Component)]
+pub struct GridCellGlyph {
+    pub row: usize,
+    pub col: usize,
+}
+
 /// Tool palette button (None = eraser)
 #[derive(Component)]
 pub struct ToolButton(pub Option<BlockType>);
 
+/// Block size palette button
+#[derive(Component)]
+pub struct SizeButton(pub BlockSpan);
+
 /// Share button marker
 #[derive(Component)]
 pub struct ShareButton;
@@ -218,3 +646,89 @@ pub struct ShareFeedback {
 /// Editor button in menu screen
 #[derive(Component)]
 pub struct EditorButton;
+
+/// Time attack start button in menu screen
+#[derive(Component)]
+pub struct TimeAttackButton;
+
+/// Time attack clock text UI marker
+#[derive(Component)]
+pub struct TimeAttackText;
+
+/// Endless mode start button in menu screen
+#[derive(Component)]
+pub struct EndlessButton;
+
+/// Daily challenge start button in menu screen
+#[derive(Component)]
+pub struct DailyButton;
+
+/// Co-op start button in menu screen
+#[derive(Component)]
+pub struct CoopButton;
+
+/// Versus mode start button in menu screen
+#[derive(Component)]
+pub struct VersusButton;
+
+/// Camera showing one versus playfield in its half of the window
+#[derive(Component)]
+pub struct FieldCamera;
+
+/// Versus HUD line for one player (serves left, score, rows sent)
+#[derive(Component)]
+pub struct VersusStatusText {
+    pub field: usize,
+}
+
+/// Endless mode danger line marker (a block reaching it ends the run)
+#[derive(Component)]
+pub struct DangerLine;
+
+/// Puzzle mode start button in menu screen
+#[derive(Component)]
+pub struct PuzzleButton;
+
+/// Puzzle HUD text showing the shots left
+#[derive(Component)]
+pub struct PuzzleShotsText;
+
+/// Puzzle serve that has not been launched yet (the shot is spent on launch)
+#[derive(Component)]
+pub struct PuzzleServe;
+
+/// Launch direction guide drawn from a held puzzle serve
+#[derive(Component)]
+pub struct AimGuide;
+
+/// Practice mode start button in menu screen
+#[derive(Component)]
+pub struct PracticeButton;
+
+/// Practice tuning panel root
+#[derive(Component)]
+pub struct PracticePanel;
+
+/// Practice tuning action (panel button or shortcut key)
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PracticeAction {
+    LevelDown,
+    LevelUp,
+    SpeedDown,
+    SpeedUp,
+    PrevPowerUp,
+    NextPowerUp,
+    SpawnPowerUp,
+    ToggleInvincible,
+    Restart,
+    Quit,
+}
+
+/// Practice panel text showing one tuned value
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PracticeLabel {
+    Level,
+    Speed,
+    PowerUp,
+    Invincible,
+}
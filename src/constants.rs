@@ -7,10 +7,41 @@ pub const PADDLE_WIDTH: f32 = 100.0;
 pub const PADDLE_HEIGHT: f32 = 20.0;
 pub const PADDLE_SPEED: f32 = 500.0;
 pub const PADDLE_Y: f32 = -350.0;
+pub const PADDLE_ENGLISH: f32 = 0.3; // Share of the paddle's velocity added to the ball's X velocity on a hit
 
 // Ball
 pub const BALL_SIZE: f32 = 15.0;
 pub const BALL_SPEED: f32 = 400.0;
+pub const BALL_MIN_VERTICAL_ANGLE: f32 = 20.0; // Degrees above horizontal a paddle bounce always keeps
+pub const SPEED_RAMP_PADDLE_HITS: u32 = 8; // Paddle hits per small speed-up
+pub const SPEED_RAMP_STEP: f32 = 0.04; // Speed-up per SPEED_RAMP_PADDLE_HITS paddle hits
+pub const SPEED_RAMP_TOP_WALL: f32 = 0.1; // One-time speed-up when a ball first reaches the top wall
+pub const SPEED_RAMP_TOP_ROWS: f32 = 0.1; // One-time speed-up when a top-row block first breaks
+pub const SPEED_RAMP_TOP_ROWS_Y: f32 = BLOCKS_START_Y - (BLOCK_HEIGHT + BLOCK_GAP) - 1.0; // Top two rows
+pub const SPEED_RAMP_MAX_BASE: f32 = 0.15; // Cap on the speed-up within level 1 (share of the level speed)
+pub const SPEED_RAMP_MAX_PER_LEVEL: f32 = 0.03; // Cap raise per later level
+pub const SPEED_RAMP_MAX: f32 = 0.3; // Highest cap on the speed-up within any level
+pub const STUCK_BALL_TIME: f32 = 8.0; // Seconds without a paddle or breakable-block contact before a looping ball is nudged
+pub const STUCK_NUDGE_INTERVAL: f32 = 2.0; // Seconds between further nudges while still stuck
+pub const STUCK_NUDGE_ANGLE: f32 = 12.0; // Degrees the ball's direction is turned per nudge
+pub const STUCK_CONTACT_HISTORY: usize = 32; // Wall / unbreakable-block bounces remembered per ball
+pub const STUCK_CONTACT_RADIUS: f32 = BALL_SIZE; // A bounce this close to an earlier one retraces a loop
+pub const HEAVY_BALL_GRAVITY: f32 = 80.0; // Downward pull on a heavy ball (px/s²)
+pub const BOUNCY_BALL_SPEEDUP: f32 = 1.05; // Speed factor per block hit for a bouncy ball
+pub const BOUNCY_BALL_MAX: f32 = 1.5; // Bouncy speed cap (share of the field's ramped speed)
+pub const SPLITTER_ANGLE: f32 = 25.0; // Degrees between a split ball and its twin
+
+// Field modifiers
+pub const FIELD_GRAVITY: f32 = 120.0; // Level-wide gravity on balls (px/s²; bends the path, keeps the speed)
+pub const FIELD_WIND: f32 = 220.0; // Sideways push inside a wind zone (px/s²)
+pub const FIELD_SLOW_SCALE: f32 = 0.6;
+pub const FIELD_FAST_SCALE: f32 = 1.4;
+pub const FIELD_ITEM_DRIFT_MAX: f32 = 90.0; // Cap on the sideways speed wind gives falling items
+pub const FIELD_ZONE_ALPHA: f32 = 0.05; // Background tint of a zone
+pub const FIELD_MOTE_INTERVAL: f32 = 0.12; // Seconds between background motes per zone
+pub const FIELD_MOTE_LIFETIME: f32 = 1.2;
+pub const FIELD_MOTE_ALPHA: f32 = 0.3;
+pub const FIELD_MOTE_SPEED: f32 = 60.0;
 
 // Blocks
 pub const BLOCK_WIDTH: f32 = 70.0;
@@ -63,6 +94,120 @@ pub const WIDE_PADDLE_MULTIPLIER: f32 = 1.5;
 pub const SLOW_BALL_DURATION: f32 = 6.0;
 pub const SLOW_BALL_MULTIPLIER: f32 = 0.6;
 pub const FIREBALL_DURATION: f32 = 8.0;
+pub const LASER_DURATION: f32 = 8.0;
+pub const LASER_COOLDOWN: f32 = 0.35;
+pub const LASER_SPEED: f32 = 700.0;
+pub const LASER_WIDTH: f32 = 4.0;
+pub const LASER_HEIGHT: f32 = 16.0;
+pub const CATCH_DURATION: f32 = 10.0;
+pub const CATCH_HOLD_TIME: f32 = 2.0;
+pub const SHRINK_PADDLE_DURATION: f32 = 8.0;
+pub const SHRINK_PADDLE_MULTIPLIER: f32 = 0.65;
+pub const FAST_BALL_DURATION: f32 = 6.0;
+pub const FAST_BALL_MULTIPLIER: f32 = 1.4;
+pub const REVERSE_CONTROLS_DURATION: f32 = 6.0;
+pub const FOG_DURATION: f32 = 8.0;
+pub const FOG_ROWS: usize = 3;
+pub const FOG_ALPHA: f32 = 0.95;
+pub const HAZARD_RATIO_PER_LEVEL: f32 = 0.05;
+pub const HAZARD_RATIO_MAX: f32 = 0.4;
+pub const BARRIER_DURATION: f32 = 10.0;
+pub const BARRIER_FLASH_TIME: f32 = 2.0;
+pub const BARRIER_Y: f32 = -385.0;
+pub const BARRIER_HEIGHT: f32 = 6.0;
+pub const MAX_SPARE_LIVES: u32 = 5;
+pub const MAGNET_DURATION: f32 = 10.0;
+pub const MAGNET_PULL_SPEED: f32 = 220.0;
+pub const POWERUP_WARNING_TIME: f32 = 1.5;
+pub const POWERUP_HUD_BAR_WIDTH: f32 = 40.0;
+
+// Score pickups (coins, gems, bonus letters)
+pub const PICKUP_DROP_CHANCE: f32 = 0.25;
+pub const PICKUP_SIZE: f32 = 14.0;
+pub const GEM_SHARE: f32 = 0.2;
+pub const LETTER_SHARE: f32 = 0.05;
+pub const COIN_SCORE: u32 = 50;
+pub const GEM_SCORE: u32 = 250;
+pub const BONUS_WORD: &str = "BONUS";
+pub const BONUS_WORD_SCORE: u32 = 5000;
+
+// Time attack
+pub const TIME_ATTACK_START: f32 = 90.0;
+pub const TIME_ATTACK_CLEAR_BONUS: f32 = 15.0;
+pub const TIME_ATTACK_PAR_TIME: f32 = 60.0;
+pub const TIME_ATTACK_SPEED_BONUS_RATE: f32 = 0.5;
+pub const TIME_ATTACK_WARNING_TIME: f32 = 10.0;
+
+// Endless
+pub const ENDLESS_START_ROWS: u32 = 4;
+pub const ENDLESS_BASE_LEVEL: u32 = 9; // Generated-level probabilities the first rows use
+pub const ENDLESS_ROWS_PER_LEVEL: u32 = 5; // Rows pushed in before difficulty rises
+pub const ENDLESS_DESCENT_SPEED: f32 = 4.0; // px/s
+pub const ENDLESS_DESCENT_SPEED_STEP: f32 = 1.0; // px/s added per difficulty step
+pub const ENDLESS_DESCENT_SPEED_MAX: f32 = 12.0;
+pub const ENDLESS_DANGER_Y: f32 = PADDLE_Y + 70.0;
+
+// Daily challenge
+pub const DAILY_LEVEL: u32 = 12; // Generated-level probabilities the daily layout uses
+pub const DAILY_ROWS: usize = 6;
+pub const DAILY_GAP_CHANCE: f32 = 0.15;
+
+// Co-op
+pub const COOP_PLAYERS: usize = 2;
+pub const COOP_PADDLE_OFFSET: f32 = 180.0; // Starting distance of each paddle from the center
+
+// Versus
+pub const VERSUS_FIELDS: usize = 2;
+pub const VERSUS_FIELD_SPACING: f32 = WINDOW_WIDTH * 2.0; // World distance between playfield centers
+pub const VERSUS_LEVEL: u32 = 9; // Generated-level probabilities both fields use
+pub const VERSUS_ROWS: usize = 5;
+pub const VERSUS_LIVES: u32 = 3; // Serves per player
+pub const VERSUS_GARBAGE_COMBO: u32 = 4; // Every Nth block in one combo sends a row
+pub const VERSUS_DANGER_Y: f32 = ENDLESS_DANGER_Y;
+
+// Puzzle
+pub const PUZZLE_STAGE_COUNT: usize = 5;
+pub const PUZZLE_AIM_SPEED: f32 = 90.0; // Paddle-offset px/s the aim moves while a serve is held
+pub const PUZZLE_AIM_TIME: f32 = 20.0; // A held serve launches by itself after this long
+pub const PUZZLE_GUIDE_LENGTH: f32 = 140.0;
+
+// Practice
+pub const PRACTICE_MAX_LEVEL: u32 = 20;
+pub const PRACTICE_SPEED_STEP: f32 = 0.1;
+pub const PRACTICE_SPEED_MIN: f32 = 0.5;
+pub const PRACTICE_SPEED_MAX: f32 = 2.0;
+pub const PRACTICE_DROP_Y: f32 = 0.0; // Height panel-spawned power-ups start falling from
+
+// Gamepad
+pub const STICK_DEADZONE_DEFAULT: f32 = 0.2; // Stick travel ignored around the center
+pub const STICK_DEADZONE_MAX: f32 = 0.5;
+pub const STICK_DEADZONE_STEP: f32 = 0.05;
+pub const STICK_ACCEL_DEFAULT: f32 = 0.2; // Seconds of held pad input to reach full paddle speed
+pub const STICK_ACCEL_MAX: f32 = 0.6;
+pub const STICK_ACCEL_STEP: f32 = 0.1;
+pub const GAMEPAD_NOTICE_SECS: f32 = 2.5;
+
+// Mouse
+pub const MOUSE_SENSITIVITY_DEFAULT: f32 = 1.0; // World units of paddle travel per pixel of relative motion
+pub const MOUSE_SENSITIVITY_MIN: f32 = 0.25;
+pub const MOUSE_SENSITIVITY_MAX: f32 = 3.0;
+pub const MOUSE_SENSITIVITY_STEP: f32 = 0.25;
+
+// Gameplay, accessibility and visual options
+pub const SHAKE_SCALE_MAX: f32 = 1.5; // Screen shake intensity range 0..max (1.0 = default)
+pub const SHAKE_SCALE_STEP: f32 = 0.25;
+pub const PARTICLE_DENSITY_STEP: f32 = 0.25; // Share of particles kept, 0..1
+pub const PADDLE_SPEED_SCALE_MIN: f32 = 0.6;
+pub const PADDLE_SPEED_SCALE_MAX: f32 = 1.6;
+pub const PADDLE_SPEED_SCALE_STEP: f32 = 0.1;
+pub const HIGH_CONTRAST_LIGHTEN: f32 = 0.12; // Lightness added to block colors in high-contrast mode
+pub const GLYPH_BLOCK_FONT_SIZE: f32 = 18.0; // Letters / hit counts drawn on blocks
+pub const GLYPH_POWERUP_FONT_SIZE: f32 = 10.0; // Icons drawn on power-up capsules
+pub const REDUCED_FLASH_ALPHA: f32 = 0.55; // Steady dim used instead of blinking
+pub const OPTION_LIST_VISIBLE_ROWS: usize = 7;
+pub const OPTION_ROW_HEIGHT: f32 = 40.0;
+pub const CONTROLS_VISIBLE_ROWS: usize = 16;
+pub const CONTROLS_ROW_HEIGHT: f32 = 30.0;
 
 // Countdown
 pub const COUNTDOWN_STEP_DURATION: f32 = 1.0;
@@ -1,11 +1,12 @@
 use bevy::prelude::*;
 
-use crate::components::{Block, BgmMusic, CountdownDisplay, Paddle};
+use crate::components::{Block, BgmMusic, CountdownDisplay, FieldCamera, Paddle, PracticePanel};
 use crate::resources::*;
 use crate::states::GameState;
 use crate::systems::audio::CollisionEvent;
 use crate::systems::*;
 use crate::systems::effects::TrailTimer;
+use crate::systems::powerup::LaserCooldown;
 
 /// Core plugin: resources, events, startup systems, and always-running systems
 pub struct CorePlugin;
@@ -13,22 +14,42 @@ pub struct CorePlugin;
 impl Plugin for CorePlugin {
     fn build(&self, app: &mut App) {
         app
-            .insert_resource(ClearColor(Color::srgb(0.07, 0.07, 0.16)))
+            .insert_resource(ClearColor(GameSettings::default().background_color()))
             .init_resource::<Score>()
             .init_resource::<Level>()
             .init_resource::<GameSounds>()
             .init_resource::<ScreenShake>()
             .init_resource::<ComboTracker>()
-            .insert_resource(HighScores::load())
+            .insert_resource(HighScores::load(ScoreTable::Normal))
             .init_resource::<LevelStats>()
             .init_resource::<TrailTimer>()
+            .init_resource::<LaserCooldown>()
+            .init_resource::<Lives>()
+            .init_resource::<PowerUpRegistry>()
+            .init_resource::<BonusLetters>()
+            .init_resource::<DropRng>()
+            .init_resource::<SpeedRamp>()
+            .insert_resource(DailyRecord::load())
             .insert_resource(AudioSettings::load())
+            .insert_resource(KeyBindings::load())
+            .insert_resource(StickSettings::load())
+            .insert_resource(MouseSettings::load())
+            .insert_resource(GameSettings::load())
             .init_state::<GameState>()
             .add_event::<CollisionEvent>()
             .add_systems(Startup, (setup_camera, load_sounds))
-            .add_systems(Update, update_ui_scale)
+            .add_systems(Update, (update_ui_scale, update_cursor_grab))
             .add_systems(Update, (update_particles, apply_screen_shake, update_combo_popup))
-            .add_systems(Update, play_collision_sounds);
+            .add_systems(
+                Update,
+                (
+                    apply_display_settings,
+                    apply_block_palette,
+                    (label_glyph_overlays, update_glyph_overlays).chain(),
+                ),
+            )
+            .add_systems(Update, play_collision_sounds)
+            .add_systems(Update, (pause_on_gamepad_loss, show_gamepad_notice, update_gamepad_notice));
     }
 }
 
@@ -38,9 +59,14 @@ pub struct MenuPlugin;
 impl Plugin for MenuPlugin {
     fn build(&self, app: &mut App) {
         app
-            .add_systems(OnEnter(GameState::Menu), setup_menu)
+            .add_systems(OnEnter(GameState::Menu), (setup_menu, teardown_versus_cameras))
             .add_systems(OnExit(GameState::Menu), cleanup_menu)
-            .add_systems(Update, start_game_input.run_if(in_state(GameState::Menu)));
+            .add_systems(
+                Update,
+                (menu_gamepad_focus, start_game_input)
+                    .chain()
+                    .run_if(in_state(GameState::Menu)),
+            );
     }
 }
 
@@ -54,7 +80,7 @@ impl Plugin for GameplayPlugin {
             // Normal game: spawn level-based blocks
             .add_systems(
                 OnEnter(GameState::Countdown),
-                (spawn_paddle, spawn_ball, spawn_blocks, spawn_walls, spawn_ui, record_level_start_score)
+                (spawn_paddle, spawn_ball, spawn_blocks, spawn_field_zones, spawn_walls, spawn_ui, record_level_start_score)
                     .run_if(not(any_with_component::<Block>).and(not(resource_exists::<TestPlayMode>))),
             )
             // Test play: spawn blocks from editor grid
@@ -67,10 +93,17 @@ impl Plugin for GameplayPlugin {
                 OnEnter(GameState::Countdown),
                 spawn_countdown.run_if(not(any_with_component::<CountdownDisplay>)),
             )
+            // Versus: one camera per field, each on its half of the window
+            .add_systems(
+                OnEnter(GameState::Countdown),
+                setup_versus_cameras
+                    .run_if(resource_exists::<VersusMode>.and(not(any_with_component::<FieldCamera>))),
+            )
+            .add_systems(Update, update_versus_viewports.run_if(resource_exists::<VersusMode>))
             // Countdown state - update (paddle movable, countdown ticking)
             .add_systems(
                 Update,
-                (paddle_input, update_countdown)
+                (paddle_input.run_if(paddle_unlocked), update_countdown)
                     .run_if(in_state(GameState::Countdown)),
             )
             // Countdown state - exit
@@ -90,15 +123,17 @@ impl Plugin for GameplayPlugin {
             .add_systems(
                 Update,
                 (
-                    paddle_input,
+                    paddle_input.run_if(paddle_unlocked),
                     pause_input,
                     ball_movement,
                     ball_paddle_collision,
                     ball_wall_collision,
                     ball_block_collision,
-                    check_level_clear,
+                    check_level_clear
+                        .run_if(not(resource_exists::<EndlessMode>).and(not(resource_exists::<VersusMode>))),
                     update_score_text,
                     update_level_text,
+                    update_lives_text,
                     update_high_score_text,
                     update_level_time,
                     powerup_movement,
@@ -111,6 +146,87 @@ impl Plugin for GameplayPlugin {
                 )
                     .run_if(in_state(GameState::Playing)),
             )
+            // Playing state - power-up entities (laser, catch, barrier), timer HUD and score pickups
+            .add_systems(
+                Update,
+                (
+                    fire_lasers,
+                    laser_movement,
+                    laser_block_collision,
+                    caught_ball_follow_paddle,
+                    release_caught_balls,
+                    update_barrier,
+                    update_powerup_hud,
+                    blink_expiring_effects.after(update_fireball_visual),
+                    paddle_pickup_collision,
+                    label_letter_pickups,
+                    update_bonus_word_text,
+                    nudge_stuck_balls.after(ball_block_collision),
+                    spawn_field_motes,
+                    apply_speed_ramp
+                        .after(ball_paddle_collision)
+                        .after(ball_wall_collision)
+                        .after(ball_block_collision),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // Playing state - time attack clock
+            .add_systems(
+                Update,
+                (time_attack_clock.after(update_level_time), update_time_attack_text)
+                    .run_if(in_state(GameState::Playing).and(resource_exists::<TimeAttackMode>)),
+            )
+            // Playing state - endless field descent
+            .add_systems(
+                Update,
+                (
+                    endless_descend,
+                    endless_danger_check.after(endless_descend),
+                    update_endless_level_text.after(update_level_text),
+                )
+                    .run_if(in_state(GameState::Playing).and(resource_exists::<EndlessMode>)),
+            )
+            // Playing state - versus combos, garbage rows and match end
+            .add_systems(
+                Update,
+                (
+                    tick_versus_combos,
+                    push_garbage_rows.after(ball_block_collision),
+                    versus_danger_check.after(push_garbage_rows),
+                    versus_clear_check.after(ball_block_collision),
+                    update_versus_hud,
+                )
+                    .run_if(in_state(GameState::Playing).and(resource_exists::<VersusMode>)),
+            )
+            // Practice: tuning panel
+            .add_systems(
+                OnEnter(GameState::Countdown),
+                setup_practice_panel
+                    .run_if(resource_exists::<PracticeMode>.and(not(any_with_component::<PracticePanel>))),
+            )
+            .add_systems(
+                Update,
+                (practice_input, update_practice_panel.after(practice_input))
+                    .run_if(in_state(GameState::Playing).and(resource_exists::<PracticeMode>)),
+            )
+            // Puzzle: serves wait on the paddle, aimed before launch
+            .add_systems(
+                Update,
+                serve_puzzle_ball.run_if(
+                    resource_exists::<PuzzleMode>
+                        .and(in_state(GameState::Countdown).or(in_state(GameState::Playing))),
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    puzzle_aim_input,
+                    update_aim_guide.after(puzzle_aim_input),
+                    count_puzzle_shots.after(release_caught_balls),
+                    update_puzzle_hud.after(update_level_text).after(count_puzzle_shots),
+                )
+                    .run_if(in_state(GameState::Playing).and(resource_exists::<PuzzleMode>)),
+            )
             // Paused state
             .add_systems(OnEnter(GameState::Paused), (pause_bgm, setup_pause))
             .add_systems(OnExit(GameState::Paused), (resume_bgm, cleanup_pause))
@@ -124,17 +240,38 @@ pub struct GameOverPlugin;
 impl Plugin for GameOverPlugin {
     fn build(&self, app: &mut App) {
         app
-            .add_systems(OnEnter(GameState::GameOver), (setup_game_over, stop_bgm))
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                (
+                    setup_game_over.run_if(
+                        not(resource_exists::<DailyChallenge>)
+                            .and(not(resource_exists::<VersusMode>))
+                            .and(not(resource_exists::<PuzzleMode>))
+                            .and(not(resource_exists::<PracticeMode>)),
+                    ),
+                    setup_daily_result.run_if(resource_exists::<DailyChallenge>),
+                    setup_versus_result.run_if(resource_exists::<VersusMode>),
+                    setup_puzzle_result.run_if(resource_exists::<PuzzleMode>),
+                    setup_practice_result.run_if(resource_exists::<PracticeMode>),
+                    stop_bgm,
+                ),
+            )
             .add_systems(OnExit(GameState::GameOver), (cleanup_game_over, reset_game))
             .add_systems(Update, (
                 restart_input,
                 update_new_record_flash,
                 update_rank_marker,
-            ).run_if(in_state(GameState::GameOver)));
+            ).run_if(in_state(GameState::GameOver)))
+            // Daily challenge result sharing
+            .add_systems(
+                Update,
+                (daily_share_input, update_share_feedback)
+                    .run_if(in_state(GameState::GameOver).and(resource_exists::<DailyChallenge>)),
+            );
     }
 }
 
-/// Settings plugin: settings screen systems
+/// Settings plugin: settings and key binding screen systems
 pub struct SettingsPlugin;
 
 impl Plugin for SettingsPlugin {
@@ -142,7 +279,20 @@ impl Plugin for SettingsPlugin {
         app
             .add_systems(OnEnter(GameState::Settings), setup_settings)
             .add_systems(OnExit(GameState::Settings), cleanup_settings)
-            .add_systems(Update, settings_input.run_if(in_state(GameState::Settings)));
+            .add_systems(Update, (settings_input, update_option_list).chain().run_if(in_state(GameState::Settings)))
+            // Key binding screen
+            .add_systems(OnEnter(GameState::Controls), setup_controls)
+            .add_systems(OnExit(GameState::Controls), cleanup_controls)
+            .add_systems(
+                Update,
+                (
+                    controls_input,
+                    update_controls_screen,
+                    persist_key_bindings.run_if(resource_changed::<KeyBindings>),
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Controls)),
+            );
     }
 }
 
@@ -152,13 +302,27 @@ pub struct LevelClearPlugin;
 impl Plugin for LevelClearPlugin {
     fn build(&self, app: &mut App) {
         app
-            .add_systems(OnEnter(GameState::LevelClear), (setup_level_clear, stop_bgm))
+            .add_systems(
+                OnEnter(GameState::LevelClear),
+                (
+                    award_time_bonus
+                        .run_if(resource_exists::<TimeAttackMode>)
+                        .before(setup_level_clear),
+                    mark_daily_cleared.run_if(resource_exists::<DailyChallenge>),
+                    record_puzzle_stars
+                        .run_if(resource_exists::<PuzzleMode>)
+                        .before(setup_level_clear),
+                    setup_level_clear,
+                    stop_bgm,
+                ),
+            )
             .add_systems(
                 OnExit(GameState::LevelClear),
                 (
                     cleanup_level_clear,
                     cleanup_for_next_level,
                     advance_level.run_if(not(resource_exists::<TestPlayMode>)),
+                    advance_puzzle_stage.run_if(resource_exists::<PuzzleMode>),
                 ),
             )
             .add_systems(
@@ -184,6 +348,7 @@ impl Plugin for EditorPlugin {
                 (
                     editor_grid_input,
                     editor_tool_select,
+                    editor_size_select,
                     editor_share,
                     update_share_feedback,
                     editor_test_play,
@@ -205,10 +370,12 @@ fn enter_test_play(
     mut next_state: ResMut<NextState<GameState>>,
     mut level: ResMut<Level>,
     mut score: ResMut<Score>,
+    mut lives: ResMut<Lives>,
 ) {
     commands.insert_resource(TestPlayMode);
     level.current = 1;
     score.value = 0;
+    *lives = Lives::default();
     next_state.set(GameState::Countdown);
 }
 
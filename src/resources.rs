@@ -1,5 +1,17 @@
 use bevy::prelude::*;
-use crate::components::BlockType;
+use crate::components::{BallKind, BlockSpan, BlockType, PowerUpType, PracticeAction};
+use crate::constants::{
+    BONUS_WORD, COOP_PLAYERS, EDITOR_COLS, EDITOR_ROWS, ENDLESS_BASE_LEVEL, ENDLESS_DESCENT_SPEED,
+    ENDLESS_DESCENT_SPEED_MAX, ENDLESS_DESCENT_SPEED_STEP, ENDLESS_ROWS_PER_LEVEL,
+    HIGH_CONTRAST_LIGHTEN, MOUSE_SENSITIVITY_DEFAULT, MOUSE_SENSITIVITY_MAX, MOUSE_SENSITIVITY_MIN,
+    MOUSE_SENSITIVITY_STEP, PADDLE_SPEED_SCALE_MAX, PADDLE_SPEED_SCALE_MIN,
+    PADDLE_SPEED_SCALE_STEP, PARTICLE_DENSITY_STEP, PUZZLE_STAGE_COUNT, SHAKE_SCALE_MAX,
+    SHAKE_SCALE_STEP, SPEED_RAMP_PADDLE_HITS, SPEED_RAMP_STEP, SPEED_RAMP_TOP_ROWS,
+    SPEED_RAMP_TOP_WALL, STICK_ACCEL_DEFAULT, STICK_ACCEL_MAX, STICK_ACCEL_STEP,
+    STICK_DEADZONE_DEFAULT, STICK_DEADZONE_MAX, STICK_DEADZONE_STEP, TIME_ATTACK_CLEAR_BONUS,
+    TIME_ATTACK_PAR_TIME, TIME_ATTACK_SPEED_BONUS_RATE, TIME_ATTACK_START, VERSUS_FIELDS,
+    VERSUS_GARBAGE_COMBO, VERSUS_LIVES,
+};
 
 /// Score tracking resource
 #[derive(Resource, Default)]
@@ -11,18 +23,287 @@ pub struct Score {
 #[derive(Resource)]
 pub struct Level {
     pub current: u32,
+    /// Extra factor on the level speed (tuned in practice mode, otherwise 1.0)
+    pub speed_scale: f32,
 }
 
 impl Default for Level {
     fn default() -> Self {
-        Self { current: 1 }
+        Self {
+            current: 1,
+            speed_scale: 1.0,
+        }
     }
 }
 
 impl Level {
     /// Get the ball speed multiplier based on current level
     pub fn speed_multiplier(&self) -> f32 {
-        1.0 + (self.current - 1) as f32 * crate::constants::SPEED_INCREASE_PER_LEVEL
+        (1.0 + (self.current - 1) as f32 * crate::constants::SPEED_INCREASE_PER_LEVEL) * self.speed_scale
+    }
+
+    /// Cap on the speed-up earned within this level (share of the level speed)
+    pub fn speed_ramp_max(&self) -> f32 {
+        (crate::constants::SPEED_RAMP_MAX_BASE
+            + (self.current - 1) as f32 * crate::constants::SPEED_RAMP_MAX_PER_LEVEL)
+            .min(crate::constants::SPEED_RAMP_MAX)
+    }
+
+    /// Share of power-up drops that are hazards (none on level 1, rising with level)
+    pub fn hazard_ratio(&self) -> f32 {
+        ((self.current - 1) as f32 * crate::constants::HAZARD_RATIO_PER_LEVEL)
+            .min(crate::constants::HAZARD_RATIO_MAX)
+    }
+}
+
+/// Ball speed-up earned within the current level, per playfield.
+/// Paddle hits, the first top-wall bounce and the first top-row break each raise it, up to
+/// `Level::speed_ramp_max` above the level speed; cleared when the level is left.
+#[derive(Resource, Default, Debug)]
+pub struct SpeedRamp {
+    fields: [FieldRamp; VERSUS_FIELDS],
+}
+
+#[derive(Default, Clone, Copy, Debug)]
+struct FieldRamp {
+    bonus: f32,
+    /// Bonus the field's balls were last scaled to
+    applied: f32,
+    paddle_hits: u32,
+    top_wall: bool,
+    top_rows: bool,
+}
+
+impl SpeedRamp {
+    /// Multiplier on the level speed for a field
+    pub fn factor(&self, field: usize) -> f32 {
+        1.0 + self.fields[field].bonus
+    }
+
+    /// Ball speed for a field: the level speed with the ramp applied
+    pub fn speed(&self, level: &Level, field: usize) -> f32 {
+        crate::constants::BALL_SPEED * level.speed_multiplier() * self.factor(field)
+    }
+
+    pub fn paddle_hit(&mut self, level: &Level, field: usize) {
+        let ramp = &mut self.fields[field];
+        ramp.paddle_hits += 1;
+        if ramp.paddle_hits.is_multiple_of(SPEED_RAMP_PADDLE_HITS) {
+            self.raise(level, field, SPEED_RAMP_STEP);
+        }
+    }
+
+    pub fn top_wall_hit(&mut self, level: &Level, field: usize) {
+        if !std::mem::replace(&mut self.fields[field].top_wall, true) {
+            self.raise(level, field, SPEED_RAMP_TOP_WALL);
+        }
+    }
+
+    pub fn top_row_broken(&mut self, level: &Level, field: usize) {
+        if !std::mem::replace(&mut self.fields[field].top_rows, true) {
+            self.raise(level, field, SPEED_RAMP_TOP_ROWS);
+        }
+    }
+
+    fn raise(&mut self, level: &Level, field: usize, amount: f32) {
+        let ramp = &mut self.fields[field];
+        ramp.bonus = (ramp.bonus + amount).min(level.speed_ramp_max());
+    }
+
+    /// Scale factor bringing a field's balls from the last applied ramp to the current one
+    /// (relative, so a slowed or sped-up ball keeps its power-up ratio), or None if unchanged
+    pub fn take_change(&mut self, field: usize) -> Option<f32> {
+        let ramp = &mut self.fields[field];
+        if ramp.applied == ramp.bonus {
+            return None;
+        }
+        let scale = (1.0 + ramp.bonus) / (1.0 + ramp.applied);
+        ramp.applied = ramp.bonus;
+        Some(scale)
+    }
+}
+
+/// Spare lives: losing every ball with a spare left restarts the countdown instead of ending the game
+#[derive(Resource, Default)]
+pub struct Lives {
+    pub spare: u32,
+}
+
+/// How a repeated pickup of an already-active timed effect is handled
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StackRule {
+    /// Restart the running timer at full duration
+    Refresh,
+    /// Add the full duration to the time remaining
+    Extend,
+    /// Run an independent timer; the effect lasts until the last one expires
+    Stack,
+}
+
+/// Static description of a single power-up
+#[derive(Clone, Debug)]
+pub struct PowerUpDef {
+    pub power_type: PowerUpType,
+    /// Relative drop weight within its group (beneficial or hazard)
+    pub weight: f32,
+    /// Effect length in seconds; `None` for instant pickups
+    pub duration: Option<f32>,
+    pub stacking: StackRule,
+    pub color: Color,
+    /// Short HUD label
+    pub icon: &'static str,
+    pub hazard: bool,
+    /// Active effects that this pickup cancels instead of stacking with
+    pub conflicts: &'static [PowerUpType],
+}
+
+/// Drop chance and weighted candidates for one level/block combination
+#[derive(Clone, Debug)]
+pub struct DropTable {
+    pub chance: f32,
+    pub entries: Vec<(PowerUpType, f32)>,
+}
+
+impl DropTable {
+    /// Pick an entry with a roll in [0, 1)
+    pub fn pick(&self, roll: f32) -> Option<PowerUpType> {
+        let total: f32 = self.entries.iter().map(|(_, w)| w).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut target = roll * total;
+        for (power_type, weight) in &self.entries {
+            if target < *weight {
+                return Some(*power_type);
+            }
+            target -= weight;
+        }
+        self.entries.last().map(|(power_type, _)| *power_type)
+    }
+}
+
+/// Data-driven power-up definitions and drop rules
+#[derive(Resource)]
+pub struct PowerUpRegistry {
+    pub defs: Vec<PowerUpDef>,
+    /// Drop chance per block kind (Steel never breaks, so it has none)
+    pub normal_drop_chance: f32,
+    pub durable_drop_chance: f32,
+    pub explosive_drop_chance: f32,
+}
+
+impl Default for PowerUpRegistry {
+    fn default() -> Self {
+        use crate::constants::*;
+        use PowerUpType::*;
+
+        #[allow(clippy::too_many_arguments)]
+        fn def(
+            power_type: PowerUpType,
+            weight: f32,
+            duration: Option<f32>,
+            stacking: StackRule,
+            color: Color,
+            icon: &'static str,
+            hazard: bool,
+            conflicts: &'static [PowerUpType],
+        ) -> PowerUpDef {
+            PowerUpDef {
+                power_type,
+                weight,
+                duration,
+                stacking,
+                color,
+                icon,
+                hazard,
+                conflicts,
+            }
+        }
+
+        Self {
+            defs: vec![
+                def(WidePaddle, 14.0, Some(WIDE_PADDLE_DURATION), StackRule::Refresh,
+                    Color::srgb(0.95, 0.40, 0.80), "W", false, &[ShrinkPaddle]),
+                def(MultiBall, 14.0, None, StackRule::Refresh,
+                    Color::srgb(0.40, 0.90, 0.95), "M", false, &[]),
+                def(SlowBall, 12.0, Some(SLOW_BALL_DURATION), StackRule::Refresh,
+                    Color::srgb(0.60, 0.95, 0.40), "S", false, &[FastBall]),
+                def(FireBall, 12.0, Some(FIREBALL_DURATION), StackRule::Extend,
+                    Color::srgb(1.0, 0.35, 0.15), "F", false, &[]),
+                def(Laser, 12.0, Some(LASER_DURATION), StackRule::Stack,
+                    Color::srgb(1.0, 0.85, 0.25), "L", false, &[]),
+                def(Catch, 12.0, Some(CATCH_DURATION), StackRule::Refresh,
+                    Color::srgb(0.35, 0.55, 1.0), "C", false, &[]),
+                def(Barrier, 10.0, None, StackRule::Refresh,
+                    Color::srgb(0.30, 0.95, 0.75), "B", false, &[]),
+                def(Magnet, 10.0, Some(MAGNET_DURATION), StackRule::Refresh,
+                    Color::srgb(0.75, 0.78, 0.85), "U", false, &[]),
+                def(ExtraLife, 4.0, None, StackRule::Refresh,
+                    Color::srgb(1.0, 0.55, 0.65), "1UP", false, &[]),
+                def(HeavyBall, 3.0, None, StackRule::Refresh,
+                    BallKind::Heavy.color(), "Hv", false, &[]),
+                def(BouncyBall, 3.0, None, StackRule::Refresh,
+                    BallKind::Bouncy.color(), "Bn", false, &[]),
+                def(SplitBall, 3.0, None, StackRule::Refresh,
+                    BallKind::Splitter.color(), "Sp", false, &[]),
+                def(GhostBall, 3.0, None, StackRule::Refresh,
+                    BallKind::Ghost.color(), "Gh", false, &[]),
+                def(ShrinkPaddle, 1.0, Some(SHRINK_PADDLE_DURATION), StackRule::Refresh,
+                    Color::srgb(0.55, 0.20, 0.30), "-W", true, &[WidePaddle]),
+                def(FastBall, 1.0, Some(FAST_BALL_DURATION), StackRule::Refresh,
+                    Color::srgb(0.80, 0.10, 0.10), "+S", true, &[SlowBall]),
+                def(ReverseControls, 1.0, Some(REVERSE_CONTROLS_DURATION), StackRule::Refresh,
+                    Color::srgb(0.45, 0.15, 0.60), "R", true, &[]),
+                def(Fog, 1.0, Some(FOG_DURATION), StackRule::Refresh,
+                    Color::srgb(0.45, 0.45, 0.50), "?", true, &[]),
+            ],
+            normal_drop_chance: POWERUP_DROP_CHANCE,
+            durable_drop_chance: POWERUP_DROP_CHANCE * 1.5,
+            explosive_drop_chance: POWERUP_DROP_CHANCE,
+        }
+    }
+}
+
+impl PowerUpRegistry {
+    /// Definition for a power-up type (every variant is registered)
+    pub fn get(&self, power_type: PowerUpType) -> &PowerUpDef {
+        self.defs
+            .iter()
+            .find(|d| d.power_type == power_type)
+            .expect("power-up type missing from registry")
+    }
+
+    /// Drop table for a block destroyed on the given level.
+    /// Hazards share `level.hazard_ratio()` of the weight; the rest goes to beneficial drops.
+    pub fn drop_table(&self, level: &Level, block_type: BlockType) -> DropTable {
+        let chance = match block_type {
+            BlockType::Normal => self.normal_drop_chance,
+            BlockType::Durable { .. } => self.durable_drop_chance,
+            BlockType::Explosive => self.explosive_drop_chance,
+            BlockType::Steel => 0.0,
+        };
+
+        let hazard_ratio = level.hazard_ratio();
+        let group_total = |hazard: bool| -> f32 {
+            self.defs.iter().filter(|d| d.hazard == hazard).map(|d| d.weight).sum()
+        };
+        let (good_total, hazard_total) = (group_total(false), group_total(true));
+
+        let entries = self
+            .defs
+            .iter()
+            .map(|d| {
+                let share = if d.hazard {
+                    hazard_ratio * d.weight / hazard_total
+                } else {
+                    (1.0 - hazard_ratio) * d.weight / good_total
+                };
+                (d.power_type, share)
+            })
+            .filter(|(_, w)| *w > 0.0)
+            .collect();
+
+        DropTable { chance, entries }
     }
 }
 
@@ -35,146 +316,1477 @@ pub struct ScreenShake {
     pub trauma: f32,
 }
 
-/// Combo tracking resource
-#[derive(Resource)]
-pub struct ComboTracker {
-    pub count: u32,
-    pub timer: Timer,
-    pub last_score_gained: u32,
+/// Combo tracking resource
+#[derive(Resource)]
+pub struct ComboTracker {
+    pub count: u32,
+    pub timer: Timer,
+    pub last_score_gained: u32,
+}
+
+impl Default for ComboTracker {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            timer: Timer::from_seconds(crate::constants::COMBO_WINDOW, TimerMode::Once),
+            last_score_gained: 0,
+        }
+    }
+}
+
+/// Which persisted leaderboard a ranking belongs to
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ScoreTable {
+    #[default]
+    Normal,
+    TimeAttack,
+    Endless,
+    Coop,
+}
+
+impl ScoreTable {
+    fn storage_key(self) -> &'static str {
+        match self {
+            ScoreTable::Normal => crate::storage::SCORES_KEY,
+            ScoreTable::TimeAttack => crate::storage::TIME_ATTACK_SCORES_KEY,
+            ScoreTable::Endless => crate::storage::ENDLESS_SCORES_KEY,
+            ScoreTable::Coop => crate::storage::COOP_SCORES_KEY,
+        }
+    }
+}
+
+/// Top-3 high score ranking (persisted across sessions).
+/// Holds the table of the mode being played; modes swap it in when a run starts.
+#[derive(Resource, Default)]
+pub struct HighScores {
+    pub scores: [u32; 3], // Descending order
+    pub table: ScoreTable,
+}
+
+impl HighScores {
+    /// Load a ranking table from persistent storage
+    pub fn load(table: ScoreTable) -> Self {
+        Self {
+            scores: crate::storage::load_scores(table.storage_key()),
+            table,
+        }
+    }
+
+    /// Save scores to persistent storage
+    pub fn save(&self) {
+        crate::storage::save_scores(self.table.storage_key(), &self.scores);
+    }
+
+    /// Best (1st place) score
+    pub fn best(&self) -> u32 {
+        self.scores[0]
+    }
+
+    /// Try to insert a score into the ranking.
+    /// Returns the rank index (0, 1, or 2) if the score qualifies, None otherwise.
+    /// Automatically saves after insertion.
+    pub fn try_insert(&mut self, score: u32) -> Option<usize> {
+        if score == 0 {
+            return None;
+        }
+        for i in 0..3 {
+            if score > self.scores[i] {
+                // Shift lower scores down
+                for j in (i + 1..3).rev() {
+                    self.scores[j] = self.scores[j - 1];
+                }
+                self.scores[i] = score;
+                self.save();
+                return Some(i);
+            }
+        }
+        None
+    }
+}
+
+/// Per-level statistics resource
+#[derive(Resource, Default)]
+pub struct LevelStats {
+    pub blocks_destroyed: u32,
+    pub max_combo: u32,
+    pub score_at_level_start: u32,
+    pub time_elapsed: f32,
+    /// Coins and gems picked up (combo-independent score channel)
+    pub coins_collected: u32,
+    pub coin_score: u32,
+    /// Paid out for spelling the bonus word
+    pub word_bonus: u32,
+    /// Score earned by each player's balls, lasers and pickups (co-op breakdown)
+    pub player_scores: [u32; COOP_PLAYERS],
+}
+
+/// Time attack run state (present only while a time attack run is active)
+#[derive(Resource)]
+pub struct TimeAttackMode {
+    /// Seconds available for the current level; the clock is this minus `LevelStats::time_elapsed`
+    pub level_budget: f32,
+    /// Seconds awarded at the last level clear
+    pub last_bonus: f32,
+}
+
+impl Default for TimeAttackMode {
+    fn default() -> Self {
+        Self {
+            level_budget: TIME_ATTACK_START,
+            last_bonus: 0.0,
+        }
+    }
+}
+
+impl TimeAttackMode {
+    /// Seconds left on the clock
+    pub fn remaining(&self, stats: &LevelStats) -> f32 {
+        (self.level_budget - stats.time_elapsed).max(0.0)
+    }
+
+    /// Bonus seconds for clearing a level in `elapsed` seconds (faster than par earns more)
+    pub fn clear_bonus(elapsed: f32) -> f32 {
+        TIME_ATTACK_CLEAR_BONUS + (TIME_ATTACK_PAR_TIME - elapsed).max(0.0) * TIME_ATTACK_SPEED_BONUS_RATE
+    }
+}
+
+/// Local two-player co-op run marker (present only while a co-op run is active)
+#[derive(Resource)]
+pub struct CoopMode;
+
+/// One versus player's side: serves left, own combo and score, and garbage rows in transit
+pub struct VersusField {
+    pub lives: u32,
+    /// This field's score (the shared `Score` stays untouched in versus)
+    pub score: Score,
+    /// This field's combo; every `VERSUS_GARBAGE_COMBO`th block of it sends a row
+    pub combo: ComboTracker,
+    /// Rows the opponent has sent that have not been pushed in yet
+    pub garbage_pending: u32,
+    pub garbage_sent: u32,
+    /// Gap columns for rows pushed into this field; both fields start from the match seed,
+    /// so the nth row either player receives has the same gap
+    pub garbage_rng: DropRng,
+}
+
+impl VersusField {
+    fn new(seed: u32) -> Self {
+        Self {
+            lives: VERSUS_LIVES,
+            score: Score::default(),
+            combo: ComboTracker::default(),
+            garbage_pending: 0,
+            garbage_sent: 0,
+            garbage_rng: DropRng::seeded(seed),
+        }
+    }
+}
+
+/// Versus match state (present only while a versus match is active)
+#[derive(Resource)]
+pub struct VersusMode {
+    pub fields: [VersusField; VERSUS_FIELDS],
+    /// Set when the match is decided
+    pub winner: Option<usize>,
+}
+
+impl Default for VersusMode {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl VersusMode {
+    /// A fresh match whose garbage rows come from `seed`
+    pub fn new(seed: u32) -> Self {
+        Self {
+            fields: std::array::from_fn(|_| VersusField::new(seed)),
+            winner: None,
+        }
+    }
+
+    /// Send the opponent a row for every `VERSUS_GARBAGE_COMBO`th block `field`'s combo
+    /// reached since it stood at `combo_before`
+    pub fn send_garbage(&mut self, field: usize, combo_before: u32) {
+        let combo = self.fields[field].combo.count;
+        let sent = combo / VERSUS_GARBAGE_COMBO - combo_before.min(combo) / VERSUS_GARBAGE_COMBO;
+        self.fields[field].garbage_sent += sent;
+        self.fields[1 - field].garbage_pending += sent;
+    }
+
+    /// End the match in favour of `winner` (the first decision stands)
+    pub fn decide(&mut self, winner: usize) {
+        self.winner.get_or_insert(winner);
+    }
+}
+
+/// Practice session settings (present only while practice mode is active)
+#[derive(Resource)]
+pub struct PracticeMode {
+    /// Balls bounce off the bottom instead of being lost
+    pub invincible: bool,
+    /// Power-up the tuning panel spawns
+    pub power_up: PowerUpType,
+}
+
+impl Default for PracticeMode {
+    fn default() -> Self {
+        Self {
+            invincible: false,
+            power_up: PowerUpType::WidePaddle,
+        }
+    }
+}
+
+/// Puzzle run state (present only while puzzle mode is active)
+#[derive(Resource, Default)]
+pub struct PuzzleMode {
+    /// Index of the current stage
+    pub stage: usize,
+    /// Shots left in the current stage, counting a serve still held on the paddle
+    pub shots_left: u32,
+    /// Shots launched in the current stage
+    pub shots_used: u32,
+    /// Stars earned on the last cleared stage
+    pub last_stars: u32,
+    /// Best stars earned on each stage
+    pub best_stars: [u32; PUZZLE_STAGE_COUNT],
+}
+
+impl PuzzleMode {
+    /// Start a run with the saved stars
+    pub fn start() -> Self {
+        Self::with_best_stars(crate::storage::load_puzzle_stars())
+    }
+
+    /// Start at the first stage without stars (from the top once every stage is solved)
+    pub fn with_best_stars(best_stars: [u32; PUZZLE_STAGE_COUNT]) -> Self {
+        let stage = best_stars.iter().position(|&stars| stars == 0).unwrap_or(0);
+        Self {
+            stage,
+            best_stars,
+            ..default()
+        }
+    }
+
+    /// Record the stars for clearing the current stage, keeping the best
+    pub fn record_stars(&mut self, stars: u32) {
+        self.last_stars = stars;
+        let best = &mut self.best_stars[self.stage];
+        *best = (*best).max(stars);
+    }
+
+    /// Save the best stars to persistent storage
+    pub fn save(&self) {
+        crate::storage::save_puzzle_stars(&self.best_stars);
+    }
+}
+
+/// Endless survival run state (present only while an endless run is active)
+#[derive(Resource, Default)]
+pub struct EndlessMode {
+    /// Rows pushed in from the top so far (drives difficulty)
+    pub rows_pushed: u32,
+    /// Distance the field has descended since the last row was inserted
+    pub scroll: f32,
+}
+
+impl EndlessMode {
+    /// Generated-level number whose block probabilities the next row uses
+    pub fn difficulty_level(&self) -> u32 {
+        ENDLESS_BASE_LEVEL + self.rows_pushed / ENDLESS_ROWS_PER_LEVEL
+    }
+
+    /// Field descent speed in px/s
+    pub fn descent_speed(&self) -> f32 {
+        let steps = (self.difficulty_level() - ENDLESS_BASE_LEVEL) as f32;
+        (ENDLESS_DESCENT_SPEED + steps * ENDLESS_DESCENT_SPEED_STEP).min(ENDLESS_DESCENT_SPEED_MAX)
+    }
+}
+
+/// Random source for block drops; seeded during a daily challenge so every player sees the same drops
+#[derive(Resource, Default, PartialEq, Debug)]
+pub struct DropRng {
+    /// LCG state when seeded; `None` uses the shared global generator
+    state: Option<u32>,
+    /// Versus matches are played without items
+    disabled: bool,
+}
+
+impl DropRng {
+    pub fn seeded(seed: u32) -> Self {
+        Self {
+            state: Some(seed),
+            disabled: false,
+        }
+    }
+
+    /// A source that never drops anything
+    pub fn disabled() -> Self {
+        Self {
+            state: None,
+            disabled: true,
+        }
+    }
+
+    /// Next random value in [0, 1), or 1.0 (above every drop chance) when disabled
+    pub fn roll(&mut self) -> f32 {
+        if self.disabled {
+            return 1.0;
+        }
+        match &mut self.state {
+            Some(state) => {
+                *state = state.wrapping_mul(1103515245).wrapping_add(12345);
+                // Top 24 bits fit an f32 exactly, so the value never rounds up to 1.0
+                (*state >> 8) as f32 / (1u32 << 24) as f32
+            }
+            None => crate::utils::rand_f32(),
+        }
+    }
+}
+
+/// Daily challenge run state (present only while today's challenge is being played)
+#[derive(Resource)]
+pub struct DailyChallenge {
+    /// UTC day number (days since 1970-01-01) seeding the layout and drops
+    pub day: u32,
+    /// Clear time in seconds, set when the level is cleared
+    pub cleared_time: Option<f32>,
+}
+
+/// Persisted daily challenge history (one attempt per UTC day)
+#[derive(Resource, Default, Clone, Copy, PartialEq, Debug)]
+pub struct DailyRecord {
+    pub last_day: u32,
+    /// Consecutive days played, ending at `last_day`
+    pub streak: u32,
+    pub best_streak: u32,
+    pub last_score: u32,
+}
+
+impl DailyRecord {
+    /// Load the record from persistent storage
+    pub fn load() -> Self {
+        let [last_day, streak, best_streak, last_score] = crate::storage::load_daily_record();
+        Self {
+            last_day,
+            streak,
+            best_streak,
+            last_score,
+        }
+    }
+
+    /// Save the record to persistent storage
+    pub fn save(&self) {
+        crate::storage::save_daily_record(&[
+            self.last_day,
+            self.streak,
+            self.best_streak,
+            self.last_score,
+        ]);
+    }
+
+    /// Whether today's single attempt has already been used
+    pub fn played(&self, day: u32) -> bool {
+        self.streak > 0 && self.last_day == day
+    }
+
+    /// Count an attempt for `day`, continuing the streak if the previous day was played
+    pub fn start_attempt(&mut self, day: u32) {
+        self.streak = if self.streak > 0 && self.last_day + 1 == day {
+            self.streak + 1
+        } else {
+            1
+        };
+        self.best_streak = self.best_streak.max(self.streak);
+        self.last_day = day;
+        self.last_score = 0;
+    }
+}
+
+/// Bonus word letters collected during the current run
+#[derive(Resource, Default)]
+pub struct BonusLetters {
+    pub collected: [bool; BONUS_WORD.len()],
+}
+
+impl BonusLetters {
+    /// HUD form of the word: collected letters shown, missing ones as `_`
+    pub fn display(&self) -> String {
+        BONUS_WORD
+            .chars()
+            .zip(self.collected)
+            .map(|(c, got)| if got { c } else { '_' })
+            .collect()
+    }
+}
+
+/// Audio volume settings (persisted)
+#[derive(Resource, Clone)]
+pub struct AudioSettings {
+    pub bgm_volume: f32,
+    pub sfx_volume: f32,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            bgm_volume: 0.4,
+            sfx_volume: 1.0,
+        }
+    }
+}
+
+impl AudioSettings {
+    /// Volume steps for settings UI (0%, 25%, 50%, 75%, 100%)
+    const STEPS: [f32; 5] = [0.0, 0.25, 0.5, 0.75, 1.0];
+
+    /// Load from persistent storage
+    pub fn load() -> Self {
+        let (bgm, sfx) = crate::storage::load_audio_settings();
+        Self {
+            bgm_volume: bgm,
+            sfx_volume: sfx,
+        }
+    }
+
+    /// Save to persistent storage
+    pub fn save(&self) {
+        crate::storage::save_audio_settings(self.bgm_volume, self.sfx_volume);
+    }
+
+    /// Get the current step index for a volume value
+    fn step_index(volume: f32) -> usize {
+        Self::STEPS
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                (volume - **a).abs().partial_cmp(&(volume - **b).abs()).unwrap()
+            })
+            .map(|(i, _)| i)
+            .unwrap_or(0)
+    }
+
+    /// Increase volume by one step, returns new value
+    pub fn step_up(volume: f32) -> f32 {
+        let idx = Self::step_index(volume);
+        Self::STEPS[(idx + 1).min(Self::STEPS.len() - 1)]
+    }
+
+    /// Decrease volume by one step, returns new value
+    pub fn step_down(volume: f32) -> f32 {
+        let idx = Self::step_index(volume);
+        Self::STEPS[idx.saturating_sub(1)]
+    }
+
+    /// Format volume as percentage string
+    pub fn volume_percent(volume: f32) -> u32 {
+        (volume * 100.0).round() as u32
+    }
+}
+
+/// Actions for one co-op / versus player (both players share one keyboard)
+pub struct CoopActions {
+    pub left: InputAction,
+    pub right: InputAction,
+    /// Launch a caught ball / fire lasers
+    pub launch: InputAction,
+}
+
+/// Co-op and versus actions per player
+pub const COOP_ACTIONS: [CoopActions; COOP_PLAYERS] = [
+    CoopActions {
+        left: InputAction::P1Left,
+        right: InputAction::P1Right,
+        launch: InputAction::P1Launch,
+    },
+    CoopActions {
+        left: InputAction::P2Left,
+        right: InputAction::P2Right,
+        launch: InputAction::P2Launch,
+    },
+];
+
+/// Shortcut actions for the practice tuning panel
+pub const PRACTICE_ACTIONS: [(InputAction, PracticeAction); 10] = [
+    (InputAction::PracticeLevelDown, PracticeAction::LevelDown),
+    (InputAction::PracticeLevelUp, PracticeAction::LevelUp),
+    (InputAction::PracticeSpeedDown, PracticeAction::SpeedDown),
+    (InputAction::PracticeSpeedUp, PracticeAction::SpeedUp),
+    (InputAction::PracticePrevPowerUp, PracticeAction::PrevPowerUp),
+    (InputAction::PracticeNextPowerUp, PracticeAction::NextPowerUp),
+    (InputAction::PracticeSpawnPowerUp, PracticeAction::SpawnPowerUp),
+    (InputAction::PracticeInvincible, PracticeAction::ToggleInvincible),
+    (InputAction::PracticeRestart, PracticeAction::Restart),
+    (InputAction::PracticeQuit, PracticeAction::Quit),
+];
+
+/// Rebindable keyboard / gamepad actions (touch and on-screen buttons are not remapped)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum InputAction {
+    MoveLeft,
+    MoveRight,
+    /// Launch a caught ball / fire lasers
+    Launch,
+    /// Pause during play (also resumes from the pause overlay)
+    Pause,
+    /// Start, continue and select on menus and result screens
+    Confirm,
+    MenuUp,
+    MenuDown,
+    TimeAttack,
+    Endless,
+    Daily,
+    Puzzle,
+    Coop,
+    Versus,
+    Practice,
+    Settings,
+    Editor,
+    /// Leave the settings screens and the pause overlay
+    Back,
+    // Co-op and versus players (see `COOP_ACTIONS`)
+    P1Left,
+    P1Right,
+    P1Launch,
+    P2Left,
+    P2Right,
+    P2Launch,
+    // Practice panel shortcuts (see `PRACTICE_ACTIONS`)
+    PracticeLevelDown,
+    PracticeLevelUp,
+    PracticeSpeedDown,
+    PracticeSpeedUp,
+    PracticePrevPowerUp,
+    PracticeNextPowerUp,
+    PracticeSpawnPowerUp,
+    PracticeInvincible,
+    PracticeRestart,
+    PracticeQuit,
+    /// Copy the daily challenge result on its result screen
+    Share,
+}
+
+/// Screen group an action is read on: actions sharing a group may not share a key
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum InputContext {
+    Play,
+    /// Co-op and versus play, where the paddles use `COOP_ACTIONS`
+    SplitPlay,
+    Menu,
+    Options,
+}
+
+impl InputAction {
+    pub const ALL: [InputAction; 34] = [
+        InputAction::MoveLeft,
+        InputAction::MoveRight,
+        InputAction::Launch,
+        InputAction::Pause,
+        InputAction::Confirm,
+        InputAction::MenuUp,
+        InputAction::MenuDown,
+        InputAction::TimeAttack,
+        InputAction::Endless,
+        InputAction::Daily,
+        InputAction::Puzzle,
+        InputAction::Coop,
+        InputAction::Versus,
+        InputAction::Practice,
+        InputAction::Settings,
+        InputAction::Editor,
+        InputAction::Back,
+        InputAction::P1Left,
+        InputAction::P1Right,
+        InputAction::P1Launch,
+        InputAction::P2Left,
+        InputAction::P2Right,
+        InputAction::P2Launch,
+        InputAction::PracticeLevelDown,
+        InputAction::PracticeLevelUp,
+        InputAction::PracticeSpeedDown,
+        InputAction::PracticeSpeedUp,
+        InputAction::PracticePrevPowerUp,
+        InputAction::PracticeNextPowerUp,
+        InputAction::PracticeSpawnPowerUp,
+        InputAction::PracticeInvincible,
+        InputAction::PracticeRestart,
+        InputAction::PracticeQuit,
+        InputAction::Share,
+    ];
+
+    /// Name shown on the key binding screen
+    pub fn label(self) -> &'static str {
+        match self {
+            InputAction::MoveLeft => "左へ移動",
+            InputAction::MoveRight => "右へ移動",
+            InputAction::Launch => "発射",
+            InputAction::Pause => "ポーズ",
+            InputAction::Back => "戻る",
+            InputAction::Confirm => "決定",
+            InputAction::MenuUp => "カーソル上",
+            InputAction::MenuDown => "カーソル下",
+            InputAction::TimeAttack => "タイムアタック",
+            InputAction::Endless => "エンドレス",
+            InputAction::Daily => "デイリー",
+            InputAction::Puzzle => "パズル",
+            InputAction::Coop => "2人協力",
+            InputAction::Versus => "対戦",
+            InputAction::Practice => "練習",
+            InputAction::Settings => "設定",
+            InputAction::Editor => "エディタ",
+            InputAction::P1Left => "1P 左へ移動",
+            InputAction::P1Right => "1P 右へ移動",
+            InputAction::P1Launch => "1P 発射",
+            InputAction::P2Left => "2P 左へ移動",
+            InputAction::P2Right => "2P 右へ移動",
+            InputAction::P2Launch => "2P 発射",
+            InputAction::PracticeLevelDown => "練習 レベル↓",
+            InputAction::PracticeLevelUp => "練習 レベル↑",
+            InputAction::PracticeSpeedDown => "練習 速度↓",
+            InputAction::PracticeSpeedUp => "練習 速度↑",
+            InputAction::PracticePrevPowerUp => "練習 前のアイテム",
+            InputAction::PracticeNextPowerUp => "練習 次のアイテム",
+            InputAction::PracticeSpawnPowerUp => "練習 アイテムを出す",
+            InputAction::PracticeInvincible => "練習 無敵",
+            InputAction::PracticeRestart => "練習 リスタート",
+            InputAction::PracticeQuit => "練習 終了",
+            InputAction::Share => "結果のコピー",
+        }
+    }
+
+    /// Screens the action is read on
+    pub fn contexts(self) -> &'static [InputContext] {
+        match self {
+            // Left/right also step the volume sliders
+            InputAction::MoveLeft | InputAction::MoveRight => &[InputContext::Play, InputContext::Options],
+            InputAction::Launch => &[InputContext::Play],
+            InputAction::Pause => &[InputContext::Play, InputContext::SplitPlay],
+            InputAction::Confirm => &[InputContext::Menu, InputContext::Options],
+            InputAction::Back | InputAction::MenuUp | InputAction::MenuDown => &[InputContext::Options],
+            InputAction::P1Left
+            | InputAction::P1Right
+            | InputAction::P1Launch
+            | InputAction::P2Left
+            | InputAction::P2Right
+            | InputAction::P2Launch => &[InputContext::SplitPlay],
+            InputAction::PracticeLevelDown
+            | InputAction::PracticeLevelUp
+            | InputAction::PracticeSpeedDown
+            | InputAction::PracticeSpeedUp
+            | InputAction::PracticePrevPowerUp
+            | InputAction::PracticeNextPowerUp
+            | InputAction::PracticeSpawnPowerUp
+            | InputAction::PracticeInvincible
+            | InputAction::PracticeRestart
+            | InputAction::PracticeQuit => &[InputContext::Play],
+            _ => &[InputContext::Menu],
+        }
+    }
+
+    /// Factory keys (primary, secondary)
+    pub fn default_keys(self) -> [Option<KeyCode>; 2] {
+        let (primary, secondary) = match self {
+            InputAction::MoveLeft => (KeyCode::ArrowLeft, Some(KeyCode::KeyA)),
+            InputAction::MoveRight => (KeyCode::ArrowRight, Some(KeyCode::KeyD)),
+            InputAction::Launch => (KeyCode::Space, None),
+            InputAction::Pause => (KeyCode::Escape, None),
+            InputAction::Back => (KeyCode::Escape, None),
+            InputAction::Confirm => (KeyCode::Space, Some(KeyCode::Enter)),
+            InputAction::MenuUp => (KeyCode::ArrowUp, Some(KeyCode::KeyW)),
+            InputAction::MenuDown => (KeyCode::ArrowDown, Some(KeyCode::KeyS)),
+            InputAction::TimeAttack => (KeyCode::KeyT, None),
+            InputAction::Endless => (KeyCode::KeyN, None),
+            InputAction::Daily => (KeyCode::KeyD, None),
+            InputAction::Puzzle => (KeyCode::KeyP, None),
+            InputAction::Coop => (KeyCode::Digit2, None),
+            InputAction::Versus => (KeyCode::KeyV, None),
+            InputAction::Practice => (KeyCode::KeyL, None),
+            InputAction::Settings => (KeyCode::KeyS, None),
+            InputAction::Editor => (KeyCode::KeyE, None),
+            InputAction::P1Left => (KeyCode::KeyA, None),
+            InputAction::P1Right => (KeyCode::KeyD, None),
+            InputAction::P1Launch => (KeyCode::KeyW, None),
+            InputAction::P2Left => (KeyCode::ArrowLeft, None),
+            InputAction::P2Right => (KeyCode::ArrowRight, None),
+            InputAction::P2Launch => (KeyCode::ArrowUp, None),
+            InputAction::PracticeLevelDown => (KeyCode::Comma, None),
+            InputAction::PracticeLevelUp => (KeyCode::Period, None),
+            InputAction::PracticeSpeedDown => (KeyCode::KeyZ, None),
+            InputAction::PracticeSpeedUp => (KeyCode::KeyX, None),
+            InputAction::PracticePrevPowerUp => (KeyCode::KeyC, None),
+            InputAction::PracticeNextPowerUp => (KeyCode::KeyV, None),
+            InputAction::PracticeSpawnPowerUp => (KeyCode::KeyF, None),
+            InputAction::PracticeInvincible => (KeyCode::KeyI, None),
+            InputAction::PracticeRestart => (KeyCode::KeyR, None),
+            InputAction::PracticeQuit => (KeyCode::KeyQ, None),
+            InputAction::Share => (KeyCode::KeyC, None),
+        };
+        [Some(primary), secondary]
+    }
+
+    /// Factory gamepad button (mode shortcuts have none: the menu is navigated with the D-pad;
+    /// co-op pads use the shared Launch / move buttons)
+    pub fn default_button(self) -> Option<GamepadButton> {
+        match self {
+            InputAction::MoveLeft => Some(GamepadButton::DPadLeft),
+            InputAction::MoveRight => Some(GamepadButton::DPadRight),
+            InputAction::Launch | InputAction::Confirm => Some(GamepadButton::South),
+            InputAction::Pause => Some(GamepadButton::Start),
+            InputAction::Back => Some(GamepadButton::East),
+            InputAction::MenuUp => Some(GamepadButton::DPadUp),
+            InputAction::MenuDown => Some(GamepadButton::DPadDown),
+            _ => None,
+        }
+    }
+
+    fn shares_context(self, other: InputAction) -> bool {
+        self.contexts().iter().any(|c| other.contexts().contains(c))
+    }
+}
+
+/// Keys that can be bound, with their on-screen names
+pub const BINDABLE_KEYS: &[(KeyCode, &str)] = &[
+    (KeyCode::ArrowLeft, "←"),
+    (KeyCode::ArrowRight, "→"),
+    (KeyCode::ArrowUp, "↑"),
+    (KeyCode::ArrowDown, "↓"),
+    (KeyCode::Space, "SPACE"),
+    (KeyCode::Enter, "ENTER"),
+    (KeyCode::Escape, "ESC"),
+    (KeyCode::Tab, "TAB"),
+    (KeyCode::ShiftLeft, "左SHIFT"),
+    (KeyCode::ShiftRight, "右SHIFT"),
+    (KeyCode::ControlLeft, "左CTRL"),
+    (KeyCode::ControlRight, "右CTRL"),
+    (KeyCode::KeyA, "A"),
+    (KeyCode::KeyB, "B"),
+    (KeyCode::KeyC, "C"),
+    (KeyCode::KeyD, "D"),
+    (KeyCode::KeyE, "E"),
+    (KeyCode::KeyF, "F"),
+    (KeyCode::KeyG, "G"),
+    (KeyCode::KeyH, "H"),
+    (KeyCode::KeyI, "I"),
+    (KeyCode::KeyJ, "J"),
+    (KeyCode::KeyK, "K"),
+    (KeyCode::KeyL, "L"),
+    (KeyCode::KeyM, "M"),
+    (KeyCode::KeyN, "N"),
+    (KeyCode::KeyO, "O"),
+    (KeyCode::KeyP, "P"),
+    (KeyCode::KeyQ, "Q"),
+    (KeyCode::KeyR, "R"),
+    (KeyCode::KeyS, "S"),
+    (KeyCode::KeyT, "T"),
+    (KeyCode::KeyU, "U"),
+    (KeyCode::KeyV, "V"),
+    (KeyCode::KeyW, "W"),
+    (KeyCode::KeyX, "X"),
+    (KeyCode::KeyY, "Y"),
+    (KeyCode::KeyZ, "Z"),
+    (KeyCode::Digit0, "0"),
+    (KeyCode::Digit1, "1"),
+    (KeyCode::Digit2, "2"),
+    (KeyCode::Digit3, "3"),
+    (KeyCode::Digit4, "4"),
+    (KeyCode::Digit5, "5"),
+    (KeyCode::Digit6, "6"),
+    (KeyCode::Digit7, "7"),
+    (KeyCode::Digit8, "8"),
+    (KeyCode::Digit9, "9"),
+    (KeyCode::Comma, ","),
+    (KeyCode::Period, "."),
+    (KeyCode::Slash, "/"),
+    (KeyCode::Semicolon, ";"),
+    (KeyCode::Minus, "-"),
+    (KeyCode::Equal, "="),
+];
+
+/// On-screen name of a key (None for keys that cannot be bound)
+pub fn key_label(key: KeyCode) -> Option<&'static str> {
+    BINDABLE_KEYS.iter().find(|(k, _)| *k == key).map(|(_, label)| *label)
+}
+
+/// Gamepad buttons that can be bound (Xbox-style names)
+pub const BINDABLE_BUTTONS: &[(GamepadButton, &str)] = &[
+    (GamepadButton::South, "Ⓐ"),
+    (GamepadButton::East, "Ⓑ"),
+    (GamepadButton::West, "Ⓧ"),
+    (GamepadButton::North, "Ⓨ"),
+    (GamepadButton::LeftTrigger, "LB"),
+    (GamepadButton::RightTrigger, "RB"),
+    (GamepadButton::LeftTrigger2, "LT"),
+    (GamepadButton::RightTrigger2, "RT"),
+    (GamepadButton::Select, "SELECT"),
+    (GamepadButton::Start, "START"),
+    (GamepadButton::LeftThumb, "L3"),
+    (GamepadButton::RightThumb, "R3"),
+    (GamepadButton::DPadUp, "十字↑"),
+    (GamepadButton::DPadDown, "十字↓"),
+    (GamepadButton::DPadLeft, "十字←"),
+    (GamepadButton::DPadRight, "十字→"),
+];
+
+/// On-screen name of a gamepad button (None for buttons that cannot be bound)
+pub fn button_label(button: GamepadButton) -> Option<&'static str> {
+    BINDABLE_BUTTONS.iter().find(|(b, _)| *b == button).map(|(_, label)| *label)
+}
+
+/// Bindings for every `InputAction`: a primary and an optional secondary key, plus an optional
+/// gamepad button (persisted)
+#[derive(Resource, Clone, PartialEq, Debug)]
+pub struct KeyBindings {
+    keys: [[Option<KeyCode>; 2]; InputAction::ALL.len()],
+    buttons: [Option<GamepadButton>; InputAction::ALL.len()],
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            keys: InputAction::ALL.map(InputAction::default_keys),
+            buttons: InputAction::ALL.map(InputAction::default_button),
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Load from persistent storage (factory keys when missing, unreadable or conflicting)
+    pub fn load() -> Self {
+        Self::from_names(&crate::storage::load_key_bindings()).unwrap_or_default()
+    }
+
+    /// Save to persistent storage
+    pub fn save(&self) {
+        crate::storage::save_key_bindings(&self.to_names());
+    }
+
+    /// Stored form per action: primary key, secondary key and gamepad button names (empty when unbound)
+    pub fn to_names(&self) -> Vec<Vec<String>> {
+        let name = |value: Option<String>| value.unwrap_or_default();
+        self.keys
+            .iter()
+            .zip(self.buttons)
+            .map(|(slots, button)| {
+                let mut names: Vec<String> = slots.iter().map(|key| name(key.map(|k| format!("{:?}", k)))).collect();
+                names.push(name(button.map(|b| format!("{:?}", b))));
+                names
+            })
+            .collect()
+    }
+
+    /// Rebuild from stored names; None unless every action is present and nothing conflicts.
+    /// Data without a gamepad field keeps the factory button.
+    pub fn from_names(names: &[Vec<String>]) -> Option<Self> {
+        // Lists saved before later actions were added leave those on their factory keys
+        if names.len() > InputAction::ALL.len() {
+            return None;
+        }
+        let mut bindings = Self::default();
+        for action in &InputAction::ALL[names.len()..] {
+            bindings.keys[*action as usize] = [None; 2];
+            bindings.buttons[*action as usize] = None;
+        }
+        for (i, stored) in names.iter().enumerate() {
+            for slot in 0..2 {
+                let name = stored.get(slot).map_or("", String::as_str);
+                bindings.keys[i][slot] = if name.is_empty() {
+                    None
+                } else {
+                    let key = BINDABLE_KEYS.iter().find(|(k, _)| format!("{:?}", k) == name)?;
+                    Some(key.0)
+                };
+            }
+            bindings.keys[i][0]?;
+            if let Some(name) = stored.get(2) {
+                bindings.buttons[i] = if name.is_empty() {
+                    None
+                } else {
+                    let button = BINDABLE_BUTTONS.iter().find(|(b, _)| format!("{:?}", b) == *name)?;
+                    Some(button.0)
+                };
+            }
+        }
+        let clean = InputAction::ALL.iter().all(|&action| {
+            bindings.keys(action).iter().flatten().all(|&key| bindings.conflict(action, key).is_none())
+                && bindings.button(action).is_none_or(|b| bindings.button_conflict(action, b).is_none())
+        });
+        if !clean {
+            return None;
+        }
+        // Actions added after the list was saved take the factory keys the stored ones left free
+        for &action in &InputAction::ALL[names.len()..] {
+            for key in action.default_keys().into_iter().flatten() {
+                if bindings.conflict(action, key).is_none() {
+                    let slot = usize::from(bindings.keys(action)[0].is_some());
+                    bindings.keys[action as usize][slot] = Some(key);
+                }
+            }
+            let button = action.default_button().filter(|&b| bindings.button_conflict(action, b).is_none());
+            bindings.buttons[action as usize] = button;
+        }
+        Some(bindings)
+    }
+
+    /// Keys bound to an action (primary, secondary)
+    pub fn keys(&self, action: InputAction) -> [Option<KeyCode>; 2] {
+        self.keys[action as usize]
+    }
+
+    /// Gamepad button bound to an action
+    pub fn button(&self, action: InputAction) -> Option<GamepadButton> {
+        self.buttons[action as usize]
+    }
+
+    pub fn pad_pressed(&self, gamepad: &Gamepad, action: InputAction) -> bool {
+        self.button(action).is_some_and(|button| gamepad.pressed(button))
+    }
+
+    pub fn pad_just_pressed(&self, gamepad: &Gamepad, action: InputAction) -> bool {
+        self.button(action).is_some_and(|button| gamepad.just_pressed(button))
+    }
+
+    pub fn pressed(&self, keyboard: &ButtonInput<KeyCode>, action: InputAction) -> bool {
+        self.keys(action).iter().flatten().any(|&key| keyboard.pressed(key))
+    }
+
+    pub fn just_pressed(&self, keyboard: &ButtonInput<KeyCode>, action: InputAction) -> bool {
+        self.keys(action).iter().flatten().any(|&key| keyboard.just_pressed(key))
+    }
+
+    /// Another action that already uses the key on a screen this action is read on
+    pub fn conflict(&self, action: InputAction, key: KeyCode) -> Option<InputAction> {
+        InputAction::ALL.into_iter().find(|&other| {
+            other != action && other.shares_context(action) && self.keys(other).contains(&Some(key))
+        })
+    }
+
+    /// Another action that already uses the gamepad button on a screen this action is read on
+    pub fn button_conflict(&self, action: InputAction, button: GamepadButton) -> Option<InputAction> {
+        InputAction::ALL.into_iter().find(|&other| {
+            other != action && other.shares_context(action) && self.button(other) == Some(button)
+        })
+    }
+
+    /// Bind a gamepad button to an action; on a conflict nothing changes
+    pub fn bind_button(&mut self, action: InputAction, button: GamepadButton) -> Result<(), InputAction> {
+        if let Some(other) = self.button_conflict(action, button) {
+            return Err(other);
+        }
+        self.buttons[action as usize] = Some(button);
+        Ok(())
+    }
+
+    /// Bind a key to one slot of an action; on a conflict nothing changes and the
+    /// action already using the key is returned
+    pub fn bind(&mut self, action: InputAction, slot: usize, key: KeyCode) -> Result<(), InputAction> {
+        if let Some(other) = self.conflict(action, key) {
+            return Err(other);
+        }
+        let slots = &mut self.keys[action as usize];
+        // Binding the key held by the other slot swaps the two
+        if slots[1 - slot] == Some(key) {
+            slots.swap(0, 1);
+        } else {
+            slots[slot] = Some(key);
+        }
+        Ok(())
+    }
+
+    /// Restore the factory keys and button of one action; fails like `bind` if another action
+    /// took one of them
+    pub fn reset(&mut self, action: InputAction) -> Result<(), InputAction> {
+        let defaults = action.default_keys();
+        let button = action.default_button();
+        let taken = defaults
+            .iter()
+            .flatten()
+            .find_map(|&key| self.conflict(action, key))
+            .or_else(|| button.and_then(|b| self.button_conflict(action, b)));
+        if let Some(other) = taken {
+            return Err(other);
+        }
+        self.keys[action as usize] = defaults;
+        self.buttons[action as usize] = button;
+        Ok(())
+    }
+
+    /// Name of the primary key, for on-screen hints
+    pub fn primary_label(&self, action: InputAction) -> &'static str {
+        self.keys(action)[0].and_then(key_label).unwrap_or("-")
+    }
+
+    /// Names of both keys, e.g. "← / A"
+    pub fn label(&self, action: InputAction) -> String {
+        self.keys(action)
+            .iter()
+            .flatten()
+            .filter_map(|&key| key_label(key))
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+}
+
+/// Analog stick and gamepad paddle feel (persisted)
+#[derive(Resource, Clone, Copy, PartialEq, Debug)]
+pub struct StickSettings {
+    /// Stick travel ignored around the center (0.0–`STICK_DEADZONE_MAX`)
+    pub deadzone: f32,
+    /// Seconds of held pad input before the paddle reaches full speed (0 = instant)
+    pub acceleration: f32,
+}
+
+impl Default for StickSettings {
+    fn default() -> Self {
+        Self {
+            deadzone: STICK_DEADZONE_DEFAULT,
+            acceleration: STICK_ACCEL_DEFAULT,
+        }
+    }
+}
+
+impl StickSettings {
+    /// Load from persistent storage
+    pub fn load() -> Self {
+        crate::storage::load_stick_settings()
+            .map(|(deadzone, acceleration)| Self {
+                deadzone: deadzone.clamp(0.0, STICK_DEADZONE_MAX),
+                acceleration: acceleration.clamp(0.0, STICK_ACCEL_MAX),
+            })
+            .unwrap_or_default()
+    }
+
+    /// Save to persistent storage
+    pub fn save(&self) {
+        crate::storage::save_stick_settings(self.deadzone, self.acceleration);
+    }
+
+    /// Step the deadzone by `steps` increments, staying in range
+    pub fn step_deadzone(&mut self, steps: f32) {
+        let value = ((self.deadzone + steps * STICK_DEADZONE_STEP) * 100.0).round() / 100.0;
+        self.deadzone = value.clamp(0.0, STICK_DEADZONE_MAX);
+    }
+
+    /// Step the acceleration time by `steps` increments, staying in range
+    pub fn step_acceleration(&mut self, steps: f32) {
+        let value = ((self.acceleration + steps * STICK_ACCEL_STEP) * 10.0).round() / 10.0;
+        self.acceleration = value.clamp(0.0, STICK_ACCEL_MAX);
+    }
+
+    /// Stick deflection with the deadzone removed and the rest rescaled to -1..1
+    pub fn stick_value(&self, raw: f32) -> f32 {
+        let magnitude = raw.abs();
+        if magnitude <= self.deadzone {
+            return 0.0;
+        }
+        raw.signum() * ((magnitude - self.deadzone) / (1.0 - self.deadzone)).min(1.0)
+    }
+
+    /// Speed factor after pad input has been held for `held` seconds
+    pub fn ramp(&self, held: f32) -> f32 {
+        if self.acceleration <= 0.0 {
+            1.0
+        } else {
+            (held / self.acceleration).min(1.0)
+        }
+    }
+}
+
+/// How the mouse steers the paddle
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum MouseMode {
+    /// Mouse only clicks buttons
+    Off,
+    /// The paddle follows the cursor X position
+    #[default]
+    Absolute,
+    /// The cursor is locked and mouse motion pushes the paddle
+    Relative,
+}
+
+impl MouseMode {
+    pub const ALL: [MouseMode; 3] = [MouseMode::Off, MouseMode::Absolute, MouseMode::Relative];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            MouseMode::Off => "オフ",
+            MouseMode::Absolute => "カーソル位置",
+            MouseMode::Relative => "相対移動",
+        }
+    }
+
+    /// Name used in storage
+    pub fn name(self) -> &'static str {
+        match self {
+            MouseMode::Off => "off",
+            MouseMode::Absolute => "absolute",
+            MouseMode::Relative => "relative",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|mode| mode.name() == name)
+    }
+}
+
+/// Mouse paddle control (persisted)
+#[derive(Resource, Clone, Copy, PartialEq, Debug)]
+pub struct MouseSettings {
+    pub mode: MouseMode,
+    /// Relative mode: paddle travel per pixel of mouse motion
+    pub sensitivity: f32,
 }
 
-impl Default for ComboTracker {
+impl Default for MouseSettings {
     fn default() -> Self {
         Self {
-            count: 0,
-            timer: Timer::from_seconds(crate::constants::COMBO_WINDOW, TimerMode::Once),
-            last_score_gained: 0,
+            mode: MouseMode::default(),
+            sensitivity: MOUSE_SENSITIVITY_DEFAULT,
         }
     }
 }
 
-/// Top-3 high score ranking (persisted across sessions)
-#[derive(Resource)]
-pub struct HighScores {
-    pub scores: [u32; 3], // Descending order
-}
-
-impl HighScores {
-    /// Load scores from persistent storage
+impl MouseSettings {
+    /// Load from persistent storage
     pub fn load() -> Self {
-        Self {
-            scores: crate::storage::load_scores(),
-        }
+        crate::storage::load_mouse_settings()
+            .and_then(|(mode, sensitivity)| {
+                Some(Self {
+                    mode: MouseMode::from_name(&mode)?,
+                    sensitivity: sensitivity.clamp(MOUSE_SENSITIVITY_MIN, MOUSE_SENSITIVITY_MAX),
+                })
+            })
+            .unwrap_or_default()
     }
 
-    /// Save scores to persistent storage
+    /// Save to persistent storage
     pub fn save(&self) {
-        crate::storage::save_scores(&self.scores);
+        crate::storage::save_mouse_settings(self.mode.name(), self.sensitivity);
     }
 
-    /// Best (1st place) score
-    pub fn best(&self) -> u32 {
-        self.scores[0]
+    /// Whether the mouse moves the paddle (and clicks launch)
+    pub fn enabled(&self) -> bool {
+        self.mode != MouseMode::Off
     }
 
-    /// Try to insert a score into the ranking.
-    /// Returns the rank index (0, 1, or 2) if the score qualifies, None otherwise.
-    /// Automatically saves after insertion.
-    pub fn try_insert(&mut self, score: u32) -> Option<usize> {
-        if score == 0 {
-            return None;
+    /// Cycle the mode by `steps`, wrapping around
+    pub fn step_mode(&mut self, steps: i32) {
+        let count = MouseMode::ALL.len() as i32;
+        let index = MouseMode::ALL.iter().position(|&mode| mode == self.mode).unwrap_or(0) as i32;
+        self.mode = MouseMode::ALL[(index + steps).rem_euclid(count) as usize];
+    }
+
+    /// Step the sensitivity by `steps` increments, staying in range
+    pub fn step_sensitivity(&mut self, steps: f32) {
+        let value = ((self.sensitivity + steps * MOUSE_SENSITIVITY_STEP) * 100.0).round() / 100.0;
+        self.sensitivity = value.clamp(MOUSE_SENSITIVITY_MIN, MOUSE_SENSITIVITY_MAX);
+    }
+}
+
+/// Color set for blocks and power-up capsules
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum BlockPalette {
+    #[default]
+    Standard,
+    /// Okabe-Ito colors for red-green (green-weak) color vision
+    Deuteranopia,
+    /// Blues and yellows for red-weak color vision, where reds read dark
+    Protanopia,
+    /// Reds and teals for blue-yellow color vision
+    Tritanopia,
+    /// Greys told apart by lightness alone
+    Monochrome,
+}
+
+impl BlockPalette {
+    pub const ALL: [BlockPalette; 5] = [
+        BlockPalette::Standard,
+        BlockPalette::Deuteranopia,
+        BlockPalette::Protanopia,
+        BlockPalette::Tritanopia,
+        BlockPalette::Monochrome,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            BlockPalette::Standard => "標準",
+            BlockPalette::Deuteranopia => "2型色覚",
+            BlockPalette::Protanopia => "1型色覚",
+            BlockPalette::Tritanopia => "3型色覚",
+            BlockPalette::Monochrome => "モノクロ",
         }
-        for i in 0..3 {
-            if score > self.scores[i] {
-                // Shift lower scores down
-                for j in (i + 1..3).rev() {
-                    self.scores[j] = self.scores[j - 1];
-                }
-                self.scores[i] = score;
-                self.save();
-                return Some(i);
-            }
+    }
+
+    /// Stable name used in storage
+    pub fn name(self) -> &'static str {
+        match self {
+            BlockPalette::Standard => "standard",
+            BlockPalette::Deuteranopia => "deuteranopia",
+            BlockPalette::Protanopia => "protanopia",
+            BlockPalette::Tritanopia => "tritanopia",
+            BlockPalette::Monochrome => "monochrome",
         }
-        None
     }
-}
 
-impl Default for HighScores {
-    fn default() -> Self {
-        Self { scores: [0, 0, 0] }
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|palette| palette.name() == name)
     }
-}
 
-/// Per-level statistics resource
-#[derive(Resource, Default)]
-pub struct LevelStats {
-    pub blocks_destroyed: u32,
-    pub max_combo: u32,
-    pub score_at_level_start: u32,
-    pub time_elapsed: f32,
+    /// Color of a block of `block_type` on grid row `row`
+    pub fn block_color(self, block_type: &BlockType, row: usize) -> Color {
+        let rows = match self {
+            BlockPalette::Standard => [
+                Color::srgb(0.92, 0.44, 0.44), // Coral
+                Color::srgb(0.95, 0.60, 0.35), // Orange
+                Color::srgb(0.95, 0.85, 0.40), // Yellow
+                Color::srgb(0.40, 0.80, 0.52), // Green
+                Color::srgb(0.44, 0.60, 0.92), // Blue
+            ],
+            BlockPalette::Deuteranopia => [
+                Color::srgb(0.90, 0.62, 0.00), // Orange
+                Color::srgb(0.34, 0.71, 0.91), // Sky blue
+                Color::srgb(0.94, 0.89, 0.26), // Yellow
+                Color::srgb(0.00, 0.62, 0.45), // Bluish green
+                Color::srgb(0.00, 0.45, 0.70), // Blue
+            ],
+            BlockPalette::Protanopia => [
+                Color::srgb(0.90, 0.70, 0.10), // Amber
+                Color::srgb(0.25, 0.45, 0.90), // Blue
+                Color::srgb(0.60, 0.80, 0.98), // Light blue
+                Color::srgb(0.60, 0.55, 0.25), // Olive
+                Color::srgb(0.40, 0.65, 0.95), // Sky blue
+            ],
+            BlockPalette::Tritanopia => [
+                Color::srgb(0.90, 0.30, 0.35), // Red
+                Color::srgb(0.20, 0.65, 0.65), // Teal
+                Color::srgb(0.95, 0.55, 0.65), // Pink
+                Color::srgb(0.45, 0.80, 0.85), // Cyan
+                Color::srgb(0.70, 0.35, 0.40), // Rose brown
+            ],
+            BlockPalette::Monochrome => [
+                Color::srgb(0.72, 0.72, 0.72),
+                Color::srgb(0.60, 0.60, 0.60),
+                Color::srgb(0.48, 0.48, 0.48),
+                Color::srgb(0.66, 0.66, 0.66),
+                Color::srgb(0.54, 0.54, 0.54),
+            ],
+        };
+
+        // The accessible palettes keep Explosive clear of every row color and Steel clear of
+        // every Durable step (see `palettes_keep_block_kinds_apart`)
+        match (self, block_type) {
+            (_, BlockType::Normal) => rows[row % rows.len()],
+            (_, BlockType::Durable { hits_remaining }) => self.durable_color(*hits_remaining),
+            (BlockPalette::Standard, BlockType::Steel) => Color::srgb(0.50, 0.50, 0.55), // Grey
+            (BlockPalette::Standard, BlockType::Explosive) => Color::srgb(0.90, 0.30, 0.30), // Red-purple
+            (BlockPalette::Protanopia, BlockType::Steel) => Color::srgb(0.35, 0.35, 0.35), // Charcoal
+            (BlockPalette::Monochrome, BlockType::Steel) => Color::srgb(0.22, 0.22, 0.22), // Charcoal
+            (_, BlockType::Steel) => Color::srgb(0.30, 0.30, 0.30), // Charcoal
+            (BlockPalette::Protanopia, BlockType::Explosive) => Color::srgb(1.0, 0.97, 0.70), // Cream
+            (_, BlockType::Explosive) => Color::WHITE,
+        }
+    }
+
+    /// Durable block color for its remaining hits (darkest at full strength)
+    pub fn durable_color(self, hits_remaining: u32) -> Color {
+        match (self, hits_remaining) {
+            (BlockPalette::Standard, 3) => Color::srgb(0.55, 0.15, 0.15), // Dark red
+            (BlockPalette::Standard, 2) => Color::srgb(0.80, 0.35, 0.15), // Dark orange
+            (BlockPalette::Standard, _) => Color::srgb(0.95, 0.60, 0.35), // Orange (about to break)
+            (BlockPalette::Protanopia, 3) => Color::srgb(0.20, 0.20, 0.55), // Indigo
+            (BlockPalette::Protanopia, 2) => Color::srgb(0.42, 0.42, 0.78),
+            (BlockPalette::Protanopia, _) => Color::srgb(0.70, 0.70, 0.95),
+            (BlockPalette::Monochrome, 3) => Color::srgb(0.40, 0.40, 0.40),
+            (BlockPalette::Monochrome, 2) => Color::srgb(0.62, 0.62, 0.62),
+            (BlockPalette::Monochrome, _) => Color::srgb(0.86, 0.86, 0.86),
+            (_, 3) => Color::srgb(0.58, 0.36, 0.00), // Dark amber
+            (_, 2) => Color::srgb(0.80, 0.58, 0.22),
+            (_, _) => Color::srgb(0.98, 0.85, 0.60), // Tan
+        }
+    }
+
+    /// Capsule color for a power-up. The accessible palettes color by good / hazard only and
+    /// always show capsule icons to tell the types apart (see `GameSettings::powerup_glyphs`);
+    /// the standard palette keeps the registry colors.
+    pub fn powerup_color(self, def: &PowerUpDef) -> Color {
+        match (self, def.hazard) {
+            (BlockPalette::Standard, _) => def.color,
+            (BlockPalette::Deuteranopia, false) => Color::srgb(0.34, 0.71, 0.91), // Sky blue
+            (BlockPalette::Deuteranopia, true) => Color::srgb(0.84, 0.37, 0.00), // Vermillion
+            (BlockPalette::Protanopia, false) => Color::srgb(0.30, 0.55, 0.95), // Blue
+            (BlockPalette::Protanopia, true) => Color::srgb(1.0, 0.90, 0.00), // Bright yellow
+            (BlockPalette::Tritanopia, false) => Color::srgb(0.20, 0.70, 0.70), // Teal
+            (BlockPalette::Tritanopia, true) => Color::srgb(0.95, 0.10, 0.45), // Magenta
+            (BlockPalette::Monochrome, false) => Color::srgb(0.90, 0.90, 0.90),
+            (BlockPalette::Monochrome, true) => Color::srgb(0.30, 0.30, 0.30),
+        }
+    }
 }
 
-/// Audio volume settings (persisted)
-#[derive(Resource, Clone)]
-pub struct AudioSettings {
-    pub bgm_volume: f32,
-    pub sfx_volume: f32,
+/// Gameplay, accessibility and visual options (persisted)
+#[derive(Resource, Clone, Copy, PartialEq, Debug)]
+pub struct GameSettings {
+    /// Screen shake strength (0 = off, 1 = default)
+    pub shake: f32,
+    /// Share of particles spawned (0..1)
+    pub particles: f32,
+    pub ball_trail: bool,
+    /// Factor on the paddle's keyboard / gamepad speed
+    pub paddle_speed: f32,
+    /// Black background, white walls and brighter blocks
+    pub high_contrast: bool,
+    pub palette: BlockPalette,
+    /// Steady dimming instead of blinking for expiry warnings and the NEW RECORD text
+    pub reduced_flashing: bool,
+    /// Letters on Steel / Explosive blocks, hit counts on Durable blocks and icons on power-ups
+    /// (power-up icons are also shown whenever the palette is not Standard)
+    pub glyphs: bool,
 }
 
-impl Default for AudioSettings {
+impl Default for GameSettings {
     fn default() -> Self {
         Self {
-            bgm_volume: 0.4,
-            sfx_volume: 1.0,
+            shake: 1.0,
+            particles: 1.0,
+            ball_trail: true,
+            paddle_speed: 1.0,
+            high_contrast: false,
+            palette: BlockPalette::Standard,
+            reduced_flashing: false,
+            glyphs: false,
         }
     }
 }
 
-impl AudioSettings {
-    /// Volume steps for settings UI (0%, 25%, 50%, 75%, 100%)
-    const STEPS: [f32; 5] = [0.0, 0.25, 0.5, 0.75, 1.0];
-
-    /// Load from persistent storage
+impl GameSettings {
+    /// Load from persistent storage (missing or unreadable values keep their defaults)
     pub fn load() -> Self {
-        let (bgm, sfx) = crate::storage::load_audio_settings();
-        Self {
-            bgm_volume: bgm,
-            sfx_volume: sfx,
+        let mut settings = Self::default();
+        for (name, value) in crate::storage::load_game_settings() {
+            settings.apply(&name, &value);
         }
+        settings
     }
 
     /// Save to persistent storage
     pub fn save(&self) {
-        crate::storage::save_audio_settings(self.bgm_volume, self.sfx_volume);
+        crate::storage::save_game_settings(&self.pairs());
     }
 
-    /// Get the current step index for a volume value
-    fn step_index(volume: f32) -> usize {
-        Self::STEPS
-            .iter()
-            .enumerate()
-            .min_by(|(_, a), (_, b)| {
-                (volume - **a).abs().partial_cmp(&(volume - **b).abs()).unwrap()
-            })
-            .map(|(i, _)| i)
-            .unwrap_or(0)
+    /// Stored `name=value` pairs
+    fn pairs(&self) -> Vec<(&'static str, String)> {
+        let flag = |on: bool| if on { "on" } else { "off" }.to_string();
+        vec![
+            ("shake", self.shake.to_string()),
+            ("particles", self.particles.to_string()),
+            ("trail", flag(self.ball_trail)),
+            ("paddle_speed", self.paddle_speed.to_string()),
+            ("high_contrast", flag(self.high_contrast)),
+            ("palette", self.palette.name().to_string()),
+            ("reduced_flashing", flag(self.reduced_flashing)),
+            ("glyphs", flag(self.glyphs)),
+        ]
     }
 
-    /// Increase volume by one step, returns new value
-    pub fn step_up(volume: f32) -> f32 {
-        let idx = Self::step_index(volume);
-        Self::STEPS[(idx + 1).min(Self::STEPS.len() - 1)]
+    /// Apply one stored pair, ignoring unknown names and bad values
+    fn apply(&mut self, name: &str, value: &str) {
+        let flag = || match value {
+            "on" => Some(true),
+            "off" => Some(false),
+            _ => None,
+        };
+        let number = || value.parse::<f32>().ok();
+        match name {
+            "shake" => self.shake = number().map_or(self.shake, |v| v.clamp(0.0, SHAKE_SCALE_MAX)),
+            "particles" => self.particles = number().map_or(self.particles, |v| v.clamp(0.0, 1.0)),
+            "trail" => self.ball_trail = flag().unwrap_or(self.ball_trail),
+            "paddle_speed" => {
+                self.paddle_speed = number()
+                    .map_or(self.paddle_speed, |v| v.clamp(PADDLE_SPEED_SCALE_MIN, PADDLE_SPEED_SCALE_MAX))
+            }
+            "high_contrast" => self.high_contrast = flag().unwrap_or(self.high_contrast),
+            "palette" => self.palette = BlockPalette::from_name(value).unwrap_or(self.palette),
+            "reduced_flashing" => self.reduced_flashing = flag().unwrap_or(self.reduced_flashing),
+            "glyphs" => self.glyphs = flag().unwrap_or(self.glyphs),
+            _ => {}
+        }
     }
 
-    /// Decrease volume by one step, returns new value
-    pub fn step_down(volume: f32) -> f32 {
-        let idx = Self::step_index(volume);
-        Self::STEPS[idx.saturating_sub(1)]
+    /// Step the shake strength by `steps` increments, staying in range
+    pub fn step_shake(&mut self, steps: f32) {
+        self.shake = (self.shake + steps * SHAKE_SCALE_STEP).clamp(0.0, SHAKE_SCALE_MAX);
     }
 
-    /// Format volume as percentage string
-    pub fn volume_percent(volume: f32) -> u32 {
-        (volume * 100.0).round() as u32
+    /// Step the particle density by `steps` increments, staying in range
+    pub fn step_particles(&mut self, steps: f32) {
+        self.particles = (self.particles + steps * PARTICLE_DENSITY_STEP).clamp(0.0, 1.0);
+    }
+
+    /// Step the paddle speed by `steps` increments, staying in range
+    pub fn step_paddle_speed(&mut self, steps: f32) {
+        let value = ((self.paddle_speed + steps * PADDLE_SPEED_SCALE_STEP) * 10.0).round() / 10.0;
+        self.paddle_speed = value.clamp(PADDLE_SPEED_SCALE_MIN, PADDLE_SPEED_SCALE_MAX);
+    }
+
+    /// Cycle the palette by `steps`, wrapping around
+    pub fn step_palette(&mut self, steps: i32) {
+        let count = BlockPalette::ALL.len() as i32;
+        let index = BlockPalette::ALL.iter().position(|&p| p == self.palette).unwrap_or(0) as i32;
+        self.palette = BlockPalette::ALL[(index + steps).rem_euclid(count) as usize];
+    }
+
+    /// Window background behind the playfield
+    pub fn background_color(&self) -> Color {
+        if self.high_contrast {
+            Color::BLACK
+        } else {
+            Color::srgb(0.07, 0.07, 0.16)
+        }
+    }
+
+    pub fn wall_color(&self) -> Color {
+        if self.high_contrast {
+            Color::srgb(0.92, 0.92, 0.95)
+        } else {
+            Color::srgb(0.22, 0.20, 0.32)
+        }
+    }
+
+    /// Block color with the palette and high-contrast mode applied
+    pub fn block_color(&self, block_type: &BlockType, row: usize) -> Color {
+        self.contrast(self.palette.block_color(block_type, row))
+    }
+
+    /// Power-up capsule color with the palette and high-contrast mode applied
+    pub fn powerup_color(&self, def: &PowerUpDef) -> Color {
+        self.contrast(self.palette.powerup_color(def))
+    }
+
+    fn contrast(&self, color: Color) -> Color {
+        if self.high_contrast {
+            color.lighter(HIGH_CONTRAST_LIGHTEN)
+        } else {
+            color
+        }
+    }
+
+    /// Whether power-up capsules show their icon: always on the accessible palettes, which
+    /// color capsules by good / hazard only
+    pub fn powerup_glyphs(&self) -> bool {
+        self.glyphs || self.palette != BlockPalette::Standard
+    }
+
+    /// Overlay glyph for a block, when glyphs are on and the type has one
+    pub fn block_glyph(&self, block_type: &BlockType) -> Option<String> {
+        if !self.glyphs {
+            return None;
+        }
+        match block_type {
+            BlockType::Normal => None,
+            BlockType::Durable { hits_remaining } => Some(hits_remaining.to_string()),
+            BlockType::Steel => Some("S".to_string()),
+            BlockType::Explosive => Some("E".to_string()),
+        }
     }
 }
 
@@ -183,18 +1795,80 @@ impl AudioSettings {
 pub struct TestPlayMode;
 
 /// Editor state resource
+///
+/// `grid` holds each block at its top-left (anchor) cell; `spans` holds the
+/// footprint of the block anchored at the same cell. Cells covered by a
+/// multi-cell block other than its anchor stay `None` in `grid`.
 #[derive(Resource)]
 pub struct EditorState {
     pub selected_tool: Option<BlockType>,
+    pub selected_span: BlockSpan,
     pub grid: [[Option<BlockType>; 10]; 7],
+    pub spans: [[BlockSpan; 10]; 7],
 }
 
 impl Default for EditorState {
     fn default() -> Self {
         Self {
             selected_tool: Some(BlockType::Normal),
+            selected_span: BlockSpan::SINGLE,
             grid: [[None; 10]; 7],
+            spans: [[BlockSpan::SINGLE; 10]; 7],
+        }
+    }
+}
+
+impl EditorState {
+    /// Find the anchor cell of the block covering (row, col), if any
+    pub fn anchor_at(&self, row: usize, col: usize) -> Option<(usize, usize)> {
+        for r in 0..=row.min(EDITOR_ROWS - 1) {
+            for c in 0..=col.min(EDITOR_COLS - 1) {
+                if self.grid[r][c].is_none() {
+                    continue;
+                }
+                let span = self.spans[r][c];
+                if row < r + span.rows && col < c + span.cols {
+                    return Some((r, c));
+                }
+            }
         }
+        None
+    }
+
+    /// Block type covering (row, col), including non-anchor cells of large blocks
+    pub fn block_at(&self, row: usize, col: usize) -> Option<BlockType> {
+        self.anchor_at(row, col).and_then(|(r, c)| self.grid[r][c])
+    }
+
+    /// Remove the block covering (row, col), if any
+    pub fn erase(&mut self, row: usize, col: usize) {
+        if let Some((r, c)) = self.anchor_at(row, col) {
+            self.grid[r][c] = None;
+            self.spans[r][c] = BlockSpan::SINGLE;
+        }
+    }
+
+    /// Place the selected tool with its footprint anchored at (row, col).
+    /// Blocks overlapping the new footprint are removed. Returns false if the
+    /// footprint does not fit inside the grid.
+    pub fn place(&mut self, row: usize, col: usize) -> bool {
+        let Some(block_type) = self.selected_tool else {
+            self.erase(row, col);
+            return true;
+        };
+        let span = self.selected_span;
+        if row + span.rows > EDITOR_ROWS || col + span.cols > EDITOR_COLS {
+            return false;
+        }
+
+        for r in row..row + span.rows {
+            for c in col..col + span.cols {
+                self.erase(r, c);
+            }
+        }
+        self.grid[row][col] = Some(block_type);
+        self.spans[row][col] = span;
+        true
     }
 }
 
@@ -208,12 +1882,24 @@ pub struct GameSounds {
     pub bgm: Option<Handle<AudioSource>>,
     pub countdown_beep: Option<Handle<AudioSource>>,
     pub countdown_go: Option<Handle<AudioSource>>,
+    pub powerup: Option<Handle<AudioSource>>,
+    pub hazard: Option<Handle<AudioSource>>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn hazard_ratio_rises_with_level() {
+        assert_eq!(Level { current: 1, ..default() }.hazard_ratio(), 0.0);
+        assert!(Level { current: 3, ..default() }.hazard_ratio() > Level { current: 2, ..default() }.hazard_ratio());
+        assert_eq!(
+            Level { current: 99, ..default() }.hazard_ratio(),
+            crate::constants::HAZARD_RATIO_MAX
+        );
+    }
+
     #[test]
     fn score_default_zero() {
         let score = Score::default();
@@ -228,19 +1914,19 @@ mod tests {
 
     #[test]
     fn speed_multiplier_level_1() {
-        let level = Level { current: 1 };
+        let level = Level { current: 1, ..default() };
         assert!((level.speed_multiplier() - 1.0).abs() < f32::EPSILON);
     }
 
     #[test]
     fn speed_multiplier_level_2() {
-        let level = Level { current: 2 };
+        let level = Level { current: 2, ..default() };
         assert!((level.speed_multiplier() - 1.1).abs() < 0.001);
     }
 
     #[test]
     fn speed_multiplier_level_10() {
-        let level = Level { current: 10 };
+        let level = Level { current: 10, ..default() };
         assert!((level.speed_multiplier() - 1.9).abs() < 0.001);
     }
 
@@ -264,7 +1950,10 @@ mod tests {
 
     #[test]
     fn high_scores_try_insert_no_rank() {
-        let mut hs = HighScores { scores: [300, 200, 100] };
+        let mut hs = HighScores {
+            scores: [300, 200, 100],
+            ..default()
+        };
         assert_eq!(hs.try_insert(50), None);
         assert_eq!(hs.scores, [300, 200, 100]);
     }
@@ -285,11 +1974,20 @@ mod tests {
         assert!((stats.time_elapsed - 0.0).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn speed_scale_multiplies_level_speed() {
+        let level = Level {
+            current: 2,
+            speed_scale: 1.5,
+        };
+        assert!((level.speed_multiplier() - 1.65).abs() < 0.001);
+    }
+
     #[test]
     fn speed_multiplier_monotonic() {
         for i in 1..20 {
-            let a = Level { current: i };
-            let b = Level { current: i + 1 };
+            let a = Level { current: i, ..default() };
+            let b = Level { current: i + 1, ..default() };
             assert!(b.speed_multiplier() > a.speed_multiplier());
         }
     }
@@ -318,6 +2016,58 @@ mod tests {
         assert!((AudioSettings::step_down(0.0) - 0.0).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn editor_place_multi_cell_block() {
+        let mut editor = EditorState {
+            selected_span: BlockSpan::new(2, 2),
+            ..Default::default()
+        };
+        assert!(editor.place(1, 1));
+
+        assert_eq!(editor.anchor_at(2, 2), Some((1, 1)));
+        assert_eq!(editor.block_at(2, 2), Some(BlockType::Normal));
+        assert_eq!(editor.grid[2][2], None, "Only the anchor cell stores the block");
+        assert_eq!(editor.block_at(3, 1), None);
+    }
+
+    #[test]
+    fn editor_place_rejects_out_of_bounds() {
+        let mut editor = EditorState {
+            selected_span: BlockSpan::new(3, 3),
+            ..Default::default()
+        };
+        assert!(!editor.place(5, 8));
+        assert!(editor.grid.iter().flatten().all(|c| c.is_none()));
+    }
+
+    #[test]
+    fn editor_place_replaces_overlapping_blocks() {
+        let mut editor = EditorState::default();
+        editor.place(0, 0);
+        editor.place(0, 2);
+        editor.selected_span = BlockSpan::new(2, 1);
+        editor.selected_tool = Some(BlockType::Steel);
+        editor.place(0, 1);
+
+        assert_eq!(editor.block_at(0, 0), Some(BlockType::Normal));
+        assert_eq!(editor.anchor_at(0, 2), Some((0, 1)));
+        assert_eq!(editor.block_at(0, 2), Some(BlockType::Steel));
+    }
+
+    #[test]
+    fn editor_erase_removes_whole_block() {
+        let mut editor = EditorState {
+            selected_span: BlockSpan::new(3, 1),
+            ..Default::default()
+        };
+        editor.place(4, 4);
+        editor.selected_tool = None;
+        editor.place(4, 6);
+
+        assert_eq!(editor.block_at(4, 4), None);
+        assert_eq!(editor.spans[4][4], BlockSpan::SINGLE);
+    }
+
     #[test]
     fn audio_settings_volume_percent() {
         assert_eq!(AudioSettings::volume_percent(0.0), 0);
@@ -325,4 +2075,295 @@ mod tests {
         assert_eq!(AudioSettings::volume_percent(0.5), 50);
         assert_eq!(AudioSettings::volume_percent(1.0), 100);
     }
+
+    #[test]
+    fn default_bindings_have_no_conflicts() {
+        let bindings = KeyBindings::default();
+        for action in InputAction::ALL {
+            for key in bindings.keys(action).into_iter().flatten() {
+                assert_eq!(bindings.conflict(action, key), None, "{:?}", action);
+            }
+        }
+    }
+
+    #[test]
+    fn binding_rejects_keys_used_on_the_same_screen() {
+        let mut bindings = KeyBindings::default();
+        // ESC pauses during play, so it cannot also move the paddle
+        assert_eq!(
+            bindings.bind(InputAction::MoveLeft, 0, KeyCode::Escape),
+            Err(InputAction::Pause)
+        );
+        assert_eq!(bindings.keys(InputAction::MoveLeft)[0], Some(KeyCode::ArrowLeft));
+
+        // T starts time attack on the menu only, so the paddle may use it
+        assert_eq!(bindings.bind(InputAction::MoveLeft, 0, KeyCode::KeyT), Ok(()));
+        assert_eq!(bindings.keys(InputAction::MoveLeft), [Some(KeyCode::KeyT), Some(KeyCode::KeyA)]);
+    }
+
+    #[test]
+    fn binding_own_other_slot_swaps_keys() {
+        let mut bindings = KeyBindings::default();
+        assert_eq!(bindings.bind(InputAction::MoveRight, 0, KeyCode::KeyD), Ok(()));
+        assert_eq!(
+            bindings.keys(InputAction::MoveRight),
+            [Some(KeyCode::KeyD), Some(KeyCode::ArrowRight)]
+        );
+        assert_eq!(bindings.label(InputAction::MoveRight), "D / →");
+    }
+
+    #[test]
+    fn coop_practice_and_share_keys_are_bindable_actions() {
+        let mut bindings = KeyBindings::default();
+        // Q quits practice, W launches for co-op player 1 and C copies the daily result
+        assert_eq!(
+            bindings.bind(InputAction::Launch, 0, KeyCode::KeyQ),
+            Err(InputAction::PracticeQuit)
+        );
+        assert_eq!(bindings.bind(InputAction::Pause, 1, KeyCode::KeyW), Err(InputAction::P1Launch));
+        assert_eq!(bindings.bind(InputAction::Confirm, 1, KeyCode::KeyC), Err(InputAction::Share));
+
+        // Launch is not read in co-op (each player has their own action), so W is free for it
+        assert_eq!(bindings.bind(InputAction::Launch, 0, KeyCode::KeyW), Ok(()));
+        // ...and the players' keys can move
+        assert_eq!(bindings.bind(InputAction::P1Launch, 0, KeyCode::KeyS), Ok(()));
+        assert_eq!(bindings.bind(InputAction::P2Left, 0, KeyCode::KeyS), Err(InputAction::P1Launch));
+        assert_eq!(bindings.bind(InputAction::PracticeQuit, 0, KeyCode::KeyK), Ok(()));
+        assert_eq!(bindings.bind(InputAction::Share, 0, KeyCode::KeyK), Ok(()));
+    }
+
+    #[test]
+    fn reset_restores_factory_keys_unless_taken() {
+        let mut bindings = KeyBindings::default();
+        bindings.bind(InputAction::Launch, 0, KeyCode::KeyK).unwrap();
+        assert_eq!(bindings.reset(InputAction::Launch), Ok(()));
+        assert_eq!(bindings.keys(InputAction::Launch)[0], Some(KeyCode::Space));
+
+        bindings.bind(InputAction::Settings, 0, KeyCode::KeyK).unwrap();
+        bindings.bind(InputAction::Daily, 1, KeyCode::KeyS).unwrap();
+        assert_eq!(bindings.reset(InputAction::Settings), Err(InputAction::Daily));
+    }
+
+    #[test]
+    fn stored_names_round_trip_and_reject_conflicts() {
+        let mut bindings = KeyBindings::default();
+        bindings.bind(InputAction::Pause, 1, KeyCode::KeyK).unwrap();
+        let names = bindings.to_names();
+        assert_eq!(KeyBindings::from_names(&names), Some(bindings));
+
+        let mut clash = names.clone();
+        clash[InputAction::Launch as usize][0] = "Escape".to_string();
+        assert_eq!(KeyBindings::from_names(&clash), None);
+        assert_eq!(KeyBindings::from_names(&[names.clone(), names].concat()), None);
+    }
+
+    #[test]
+    fn stored_names_from_older_versions_fill_in_later_actions() {
+        let mut bindings = KeyBindings::default();
+        bindings.bind(InputAction::Launch, 0, KeyCode::KeyK).unwrap();
+        // Later actions are stored after the older ones, so an older list is a prefix
+        let names = bindings.to_names();
+        let loaded = KeyBindings::from_names(&names[..InputAction::Back as usize]).unwrap();
+        assert_eq!(loaded.keys(InputAction::Launch)[0], Some(KeyCode::KeyK));
+        assert_eq!(loaded.keys(InputAction::Back)[0], Some(KeyCode::Escape));
+        assert_eq!(loaded.keys(InputAction::PracticeQuit)[0], Some(KeyCode::KeyQ));
+
+        // A key the player already used keeps its action; the new action starts unbound
+        let mut names = KeyBindings::default().to_names();
+        names[InputAction::MoveLeft as usize][1] = "KeyZ".to_string();
+        let loaded = KeyBindings::from_names(&names[..=InputAction::Back as usize]).unwrap();
+        assert_eq!(loaded.keys(InputAction::MoveLeft)[1], Some(KeyCode::KeyZ));
+        assert_eq!(loaded.keys(InputAction::PracticeSpeedDown), [None, None]);
+        assert_eq!(loaded.keys(InputAction::PracticeSpeedUp)[0], Some(KeyCode::KeyX));
+    }
+
+    #[test]
+    fn gamepad_buttons_follow_the_same_conflict_rules() {
+        let mut bindings = KeyBindings::default();
+        // Ⓐ launches during play and confirms on menus: different screens
+        assert_eq!(bindings.button(InputAction::Launch), bindings.button(InputAction::Confirm));
+        assert_eq!(
+            bindings.bind_button(InputAction::Launch, GamepadButton::Start),
+            Err(InputAction::Pause)
+        );
+        assert_eq!(bindings.bind_button(InputAction::Launch, GamepadButton::RightTrigger), Ok(()));
+        assert_eq!(bindings.button(InputAction::Launch), Some(GamepadButton::RightTrigger));
+    }
+
+    #[test]
+    fn stored_names_without_gamepad_field_keep_factory_buttons() {
+        let names: Vec<Vec<String>> = KeyBindings::default()
+            .to_names()
+            .into_iter()
+            .map(|slots| slots[..2].to_vec())
+            .collect();
+        assert_eq!(KeyBindings::from_names(&names), Some(KeyBindings::default()));
+    }
+
+    #[test]
+    fn mouse_settings_cycle_modes_and_clamp_sensitivity() {
+        let mut mouse = MouseSettings::default();
+        mouse.step_mode(1);
+        assert_eq!(mouse.mode, MouseMode::Relative);
+        mouse.step_mode(1);
+        assert_eq!(mouse.mode, MouseMode::Off);
+        assert!(!mouse.enabled());
+        mouse.step_mode(-1);
+        assert_eq!(mouse.mode, MouseMode::Relative);
+
+        for _ in 0..20 {
+            mouse.step_sensitivity(1.0);
+        }
+        assert_eq!(mouse.sensitivity, MOUSE_SENSITIVITY_MAX);
+        for _ in 0..20 {
+            mouse.step_sensitivity(-1.0);
+        }
+        assert_eq!(mouse.sensitivity, MOUSE_SENSITIVITY_MIN);
+        assert_eq!(MouseMode::from_name(MouseMode::Relative.name()), Some(MouseMode::Relative));
+    }
+
+    #[test]
+    fn game_settings_round_trip_and_clamp() {
+        let mut settings = GameSettings::default();
+        settings.step_shake(-10.0);
+        settings.step_particles(-1.0);
+        settings.step_paddle_speed(3.0);
+        settings.step_palette(1);
+        settings.ball_trail = false;
+        settings.reduced_flashing = true;
+        settings.glyphs = true;
+        assert_eq!(settings.shake, 0.0);
+        assert_eq!(settings.particles, 0.75);
+        assert_eq!(settings.paddle_speed, 1.3);
+        assert_eq!(settings.palette, BlockPalette::Deuteranopia);
+
+        let mut loaded = GameSettings::default();
+        for (name, value) in settings.pairs() {
+            loaded.apply(name, &value);
+        }
+        assert_eq!(loaded, settings);
+
+        // Unknown names and bad values are ignored, out-of-range values clamped
+        loaded.apply("volume", "3");
+        loaded.apply("trail", "maybe");
+        loaded.apply("paddle_speed", "9");
+        assert!(!loaded.ball_trail);
+        assert_eq!(loaded.paddle_speed, PADDLE_SPEED_SCALE_MAX);
+    }
+
+    #[test]
+    fn palettes_keep_block_kinds_apart() {
+        // Perceptual (Oklab) distance two block kinds must keep on an accessible palette
+        const MIN_DISTANCE: f32 = 0.15;
+        let distance = |a: Color, b: Color| {
+            let (a, b) = (Oklaba::from(a), Oklaba::from(b));
+            Vec3::new(a.lightness - b.lightness, a.a - b.a, a.b - b.b).length()
+        };
+
+        for palette in BlockPalette::ALL {
+            let steel = palette.block_color(&BlockType::Steel, 0);
+            let explosive = palette.block_color(&BlockType::Explosive, 0);
+            let durable: Vec<Color> = (1..=3).map(|hits| palette.durable_color(hits)).collect();
+            assert_ne!(steel, explosive);
+            assert_ne!(durable[0], durable[1]);
+            assert_ne!(durable[1], durable[2]);
+
+            let registry = PowerUpRegistry::default();
+            let good = palette.powerup_color(registry.get(PowerUpType::MultiBall));
+            let hazard = palette.powerup_color(registry.get(PowerUpType::FastBall));
+            assert_ne!(good, hazard, "{palette:?} keeps hazards apart");
+
+            if palette == BlockPalette::Standard {
+                continue;
+            }
+            for row in 0..crate::constants::BLOCK_ROWS {
+                let normal = palette.block_color(&BlockType::Normal, row);
+                assert!(
+                    distance(explosive, normal) >= MIN_DISTANCE,
+                    "{palette:?}: Explosive too close to row {row}"
+                );
+            }
+            for (i, &step) in durable.iter().enumerate() {
+                assert!(distance(steel, step) >= MIN_DISTANCE, "{palette:?}: Steel too close to Durable {}", i + 1);
+                for &other in &durable[i + 1..] {
+                    assert!(distance(step, other) >= MIN_DISTANCE, "{palette:?}: Durable steps too close");
+                }
+            }
+        }
+        assert_eq!(BlockPalette::from_name("tritanopia"), Some(BlockPalette::Tritanopia));
+    }
+
+    #[test]
+    fn block_glyphs_follow_setting() {
+        let mut settings = GameSettings::default();
+        assert_eq!(settings.block_glyph(&BlockType::Steel), None, "Glyphs start off");
+
+        assert!(!settings.powerup_glyphs());
+        settings.palette = BlockPalette::Protanopia;
+        assert!(settings.powerup_glyphs(), "Accessible palettes always label capsules");
+        assert_eq!(settings.block_glyph(&BlockType::Steel), None, "Blocks still follow the setting");
+
+        settings.glyphs = true;
+        assert_eq!(settings.block_glyph(&BlockType::Normal), None);
+        assert_eq!(settings.block_glyph(&BlockType::Steel).as_deref(), Some("S"));
+        assert_eq!(settings.block_glyph(&BlockType::Explosive).as_deref(), Some("E"));
+        assert_eq!(settings.block_glyph(&BlockType::Durable { hits_remaining: 2 }).as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn seeded_drop_rolls_stay_below_one() {
+        // The first step lands on 0x7FFFFFFF, which used to round up to exactly 1.0
+        let mut rng = DropRng::seeded(2_378_021_662);
+        assert!(rng.roll() < 1.0);
+        for _ in 0..1000 {
+            assert!((0.0..1.0).contains(&rng.roll()));
+        }
+    }
+
+    #[test]
+    fn speed_ramp_rules_and_cap() {
+        let level = Level { current: 6, speed_scale: 1.0 };
+        let mut ramp = SpeedRamp::default();
+        for _ in 0..SPEED_RAMP_PADDLE_HITS - 1 {
+            ramp.paddle_hit(&level, 0);
+        }
+        assert_eq!(ramp.factor(0), 1.0);
+        ramp.paddle_hit(&level, 0);
+        assert!((ramp.factor(0) - (1.0 + SPEED_RAMP_STEP)).abs() < 1e-6);
+
+        // Top wall and top rows count once per level, and only for their own field
+        ramp.top_wall_hit(&level, 0);
+        ramp.top_wall_hit(&level, 0);
+        ramp.top_row_broken(&level, 1);
+        assert!((ramp.factor(0) - (1.0 + SPEED_RAMP_STEP + SPEED_RAMP_TOP_WALL)).abs() < 1e-6);
+        assert!((ramp.factor(1) - (1.0 + SPEED_RAMP_TOP_ROWS)).abs() < 1e-6);
+
+        let before = ramp.factor(0);
+        assert_eq!(ramp.take_change(0), Some(before));
+        assert_eq!(ramp.take_change(0), None);
+
+        for _ in 0..SPEED_RAMP_PADDLE_HITS * 20 {
+            ramp.paddle_hit(&level, 0);
+        }
+        assert_eq!(ramp.factor(0), 1.0 + level.speed_ramp_max());
+        let capped = crate::constants::BALL_SPEED * level.speed_multiplier() * (1.0 + level.speed_ramp_max());
+        assert!((ramp.speed(&level, 0) - capped).abs() < 0.01);
+    }
+
+    #[test]
+    fn speed_ramp_cap_follows_the_level() {
+        use crate::constants::{SPEED_RAMP_MAX, SPEED_RAMP_MAX_BASE};
+        let first = Level::default();
+        let later = Level { current: 20, speed_scale: 1.0 };
+        assert_eq!(first.speed_ramp_max(), SPEED_RAMP_MAX_BASE);
+        assert_eq!(later.speed_ramp_max(), SPEED_RAMP_MAX);
+
+        let mut ramp = SpeedRamp::default();
+        for _ in 0..SPEED_RAMP_PADDLE_HITS * 20 {
+            ramp.paddle_hit(&first, 0);
+            ramp.paddle_hit(&later, 1);
+        }
+        assert!((ramp.factor(0) - (1.0 + SPEED_RAMP_MAX_BASE)).abs() < 1e-6);
+        assert!((ramp.factor(1) - (1.0 + SPEED_RAMP_MAX)).abs() < 1e-6);
+    }
 }
@@ -11,6 +11,7 @@ pub enum GameState {
     GameOver,
     LevelClear,
     Settings,
+    Controls,
     Editor,
     TestPlay,
 }
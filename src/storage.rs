@@ -1,13 +1,58 @@
-pub fn save_scores(scores: &[u32; 3]) {
+use crate::constants::PUZZLE_STAGE_COUNT;
+
+/// Save a top-3 ranking under the given table key (one of the `*_SCORES_KEY` constants below)
+pub fn save_scores(key: &str, scores: &[u32; 3]) {
     let csv = format!("{},{},{}", scores[0], scores[1], scores[2]);
-    save_string(SCORES_KEY, &csv);
+    save_string(key, &csv);
 }
 
-pub fn load_scores() -> [u32; 3] {
-    let csv = load_string(SCORES_KEY);
+pub fn load_scores(key: &str) -> [u32; 3] {
+    let csv = load_string(key);
     parse_scores(&csv)
 }
 
+/// Save the daily challenge record: last played day, streak, best streak, last score
+pub fn save_daily_record(values: &[u32; 4]) {
+    let csv = values.map(|v| v.to_string()).join(",");
+    save_string(DAILY_KEY, &csv);
+}
+
+pub fn load_daily_record() -> [u32; 4] {
+    let csv = load_string(DAILY_KEY);
+    parse_fields(&csv)
+}
+
+/// Save the best stars earned on each puzzle stage
+pub fn save_puzzle_stars(stars: &[u32; PUZZLE_STAGE_COUNT]) {
+    let csv = stars.map(|v| v.to_string()).join(",");
+    save_string(PUZZLE_KEY, &csv);
+}
+
+pub fn load_puzzle_stars() -> [u32; PUZZLE_STAGE_COUNT] {
+    let csv = load_string(PUZZLE_KEY);
+    parse_fields(&csv)
+}
+
+/// Save key bindings: `primary|secondary|gamepad` names per action (empty for an unbound slot)
+pub fn save_key_bindings(names: &[Vec<String>]) {
+    let data = names.iter().map(|slots| slots.join("|")).collect::<Vec<_>>().join(",");
+    save_string(KEYS_KEY, &data);
+}
+
+pub fn load_key_bindings() -> Vec<Vec<String>> {
+    let data = load_string(KEYS_KEY);
+    parse_key_bindings(&data)
+}
+
+fn parse_key_bindings(csv: &str) -> Vec<Vec<String>> {
+    if csv.trim().is_empty() {
+        return Vec::new();
+    }
+    csv.split(',')
+        .map(|slots| slots.trim().split('|').map(str::to_string).collect())
+        .collect()
+}
+
 pub fn save_audio_settings(bgm_volume: f32, sfx_volume: f32) {
     let data = format!("{},{}", bgm_volume, sfx_volume);
     save_string(AUDIO_KEY, &data);
@@ -18,6 +63,50 @@ pub fn load_audio_settings() -> (f32, f32) {
     parse_audio_settings(&data)
 }
 
+/// Save the stick deadzone and the pad acceleration time
+pub fn save_stick_settings(deadzone: f32, acceleration: f32) {
+    let data = format!("{},{}", deadzone, acceleration);
+    save_string(STICK_KEY, &data);
+}
+
+/// Stored stick settings (None when missing or unreadable)
+pub fn load_stick_settings() -> Option<(f32, f32)> {
+    let data = load_string(STICK_KEY);
+    let mut values = data.split(',').map(|s| s.trim().parse::<f32>().ok());
+    Some((values.next()??, values.next()??))
+}
+
+/// Save the mouse control mode and the relative-motion sensitivity
+pub fn save_mouse_settings(mode: &str, sensitivity: f32) {
+    save_string(MOUSE_KEY, &format!("{},{}", mode, sensitivity));
+}
+
+/// Stored mouse settings (None when missing or unreadable)
+pub fn load_mouse_settings() -> Option<(String, f32)> {
+    let data = load_string(MOUSE_KEY);
+    let (mode, sensitivity) = data.split_once(',')?;
+    Some((mode.trim().to_string(), sensitivity.trim().parse().ok()?))
+}
+
+/// Save the gameplay / accessibility / visual options as `name=value` pairs
+pub fn save_game_settings(pairs: &[(&str, String)]) {
+    let data = pairs.iter().map(|(name, value)| format!("{name}={value}")).collect::<Vec<_>>().join(",");
+    save_string(OPTIONS_KEY, &data);
+}
+
+/// Stored option pairs (unknown names are kept; the caller ignores what it does not know)
+pub fn load_game_settings() -> Vec<(String, String)> {
+    let data = load_string(OPTIONS_KEY);
+    parse_name_values(&data)
+}
+
+fn parse_name_values(csv: &str) -> Vec<(String, String)> {
+    csv.split(',')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .collect()
+}
+
 fn parse_audio_settings(csv: &str) -> (f32, f32) {
     let parts: Vec<f32> = csv
         .split(',')
@@ -29,19 +118,33 @@ fn parse_audio_settings(csv: &str) -> (f32, f32) {
 }
 
 fn parse_scores(csv: &str) -> [u32; 3] {
-    let parts: Vec<u32> = csv
-        .split(',')
-        .filter_map(|s| s.trim().parse().ok())
-        .collect();
-    [
-        parts.first().copied().unwrap_or(0),
-        parts.get(1).copied().unwrap_or(0),
-        parts.get(2).copied().unwrap_or(0),
-    ]
+    parse_fields(csv)
+}
+
+/// Parse up to N comma-separated numbers, skipping invalid entries and zero-filling the rest
+fn parse_fields<const N: usize>(csv: &str) -> [u32; N] {
+    let mut fields = [0; N];
+    for (field, value) in fields
+        .iter_mut()
+        .zip(csv.split(',').filter_map(|s| s.trim().parse().ok()))
+    {
+        *field = value;
+    }
+    fields
 }
 
-const SCORES_KEY: &str = "breakout_scores";
+pub const SCORES_KEY: &str = "breakout_scores";
+pub const TIME_ATTACK_SCORES_KEY: &str = "breakout_scores_time_attack";
+pub const ENDLESS_SCORES_KEY: &str = "breakout_scores_endless";
+pub const COOP_SCORES_KEY: &str = "breakout_scores_coop";
 const AUDIO_KEY: &str = "breakout_audio";
+const DAILY_KEY: &str = "breakout_daily";
+const PUZZLE_KEY: &str = "breakout_puzzle";
+const KEYS_KEY: &str = "breakout_keys";
+const STICK_KEY: &str = "breakout_stick";
+const MOUSE_KEY: &str = "breakout_mouse";
+const OPTIONS_KEY: &str = "breakout_options";
+const DAILY_SHARE_KEY: &str = "breakout_daily_share";
 
 // --- WASM implementation ---
 #[cfg(target_arch = "wasm32")]
@@ -83,6 +186,16 @@ fn load_string(key: &str) -> String {
         .unwrap_or_default()
 }
 
+/// Write the daily result text next to the saved data (native builds have no clipboard);
+/// returns the file it went to
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save_daily_share(text: &str) -> Option<std::path::PathBuf> {
+    let path = storage_path(DAILY_SHARE_KEY)?;
+    std::fs::create_dir_all(path.parent()?).ok()?;
+    std::fs::write(&path, text).ok()?;
+    Some(path)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -108,6 +221,31 @@ mod tests {
         assert_eq!(parse_scores("abc,def,ghi"), [0, 0, 0]);
     }
 
+    #[test]
+    fn parse_daily_record_fields() {
+        assert_eq!(parse_fields::<4>("20744,3,5,1200"), [20744, 3, 5, 1200]);
+        assert_eq!(parse_fields::<4>("20744"), [20744, 0, 0, 0]);
+        assert_eq!(parse_fields::<4>(""), [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn parse_key_binding_slots() {
+        let slots = parse_key_bindings("ArrowLeft|KeyA|DPadLeft,Space||");
+        assert_eq!(slots.len(), 2);
+        assert_eq!(slots[0], ["ArrowLeft", "KeyA", "DPadLeft"]);
+        assert_eq!(slots[1], ["Space", "", ""]);
+        assert!(parse_key_bindings("").is_empty());
+    }
+
+    #[test]
+    fn parse_option_pairs() {
+        let pairs = parse_name_values("shake=0.5, trail=off,broken,palette=colorblind");
+        assert_eq!(pairs.len(), 3);
+        assert_eq!(pairs[0], ("shake".to_string(), "0.5".to_string()));
+        assert_eq!(pairs[2], ("palette".to_string(), "colorblind".to_string()));
+        assert!(parse_name_values("").is_empty());
+    }
+
     #[test]
     fn parse_audio_valid() {
         let (bgm, sfx) = parse_audio_settings("0.5,0.75");
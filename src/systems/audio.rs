@@ -12,6 +12,8 @@ pub enum CollisionEvent {
     Block,
     GameOver,
     LevelClear,
+    PowerUp,
+    Hazard,
 }
 
 /// Play sounds based on collision events
@@ -27,6 +29,8 @@ pub fn play_collision_sounds(
             CollisionEvent::Block => sounds.break_block.clone(),
             CollisionEvent::GameOver => sounds.game_over.clone(),
             CollisionEvent::LevelClear => sounds.level_up.clone(),
+            CollisionEvent::PowerUp => sounds.powerup.clone(),
+            CollisionEvent::Hazard => sounds.hazard.clone(),
         };
 
         if let Some(source) = sound {
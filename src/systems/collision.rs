@@ -5,79 +5,174 @@ use crate::constants::*;
 use crate::resources::*;
 use crate::states::GameState;
 use crate::systems::audio::CollisionEvent;
-use crate::systems::setup::durable_color;
-use crate::utils::{aabb_collision, rand_f32, simple_rand};
-
-/// Handle ball-paddle collision (multi-ball support)
+use crate::systems::effects::particle_count;
+use crate::systems::pickup::maybe_spawn_pickup;
+use crate::systems::powerup::fireball_fields;
+use crate::systems::setup::{durable_color, spawn_field_ball, spawn_kind_ball};
+use crate::utils::{aabb_collision, point_rect_distance, simple_rand};
+
+/// Handle ball-paddle collision (multi-ball and co-op paddles; catch power-up holds the ball).
+/// The bouncing paddle's player is credited with the blocks the ball breaks next.
+#[allow(clippy::type_complexity)]
 pub fn ball_paddle_collision(
-    mut ball_query: Query<(&Transform, &mut Velocity, &Collider), With<Ball>>,
-    paddle_query: Query<(&Transform, &Collider), With<Paddle>>,
+    mut commands: Commands,
+    mut ball_query: Query<
+        (Entity, &Transform, &mut Velocity, &mut BallIdle, &Collider, &PlayfieldId),
+        (With<Ball>, Without<CaughtBall>),
+    >,
+    paddle_query: Query<
+        (Entity, &Transform, &Collider, &Player, &PlayfieldId, &PaddleVelocity, Option<&PowerUpEffects>),
+        With<Paddle>,
+    >,
     mut collision_events: EventWriter<CollisionEvent>,
+    mut ramp: ResMut<SpeedRamp>,
+    level: Res<Level>,
 ) {
-    let Ok((paddle_transform, paddle_collider)) = paddle_query.get_single() else {
-        return;
-    };
+    for (ball_entity, ball_transform, mut ball_velocity, mut idle, ball_collider, ball_field) in &mut ball_query {
+        for (paddle_entity, paddle_transform, paddle_collider, player, paddle_field, paddle_velocity, effects) in
+            &paddle_query
+        {
+            // Only bounce off a paddle in the same field, and only if the ball is moving downward
+            if ball_field != paddle_field
+                || ball_velocity.0.y >= 0.0
+                || !aabb_collision(
+                    ball_transform.translation.truncate(),
+                    ball_collider.size,
+                    paddle_transform.translation.truncate(),
+                    paddle_collider.size,
+                )
+            {
+                continue;
+            }
 
-    for (ball_transform, mut ball_velocity, ball_collider) in &mut ball_query {
-        if aabb_collision(
-            ball_transform.translation.truncate(),
-            ball_collider.size,
-            paddle_transform.translation.truncate(),
-            paddle_collider.size,
-        ) {
-            // Only bounce if ball is moving downward
-            if ball_velocity.0.y < 0.0 {
-                // Reflect Y direction
-                ball_velocity.0.y = ball_velocity.0.y.abs();
+            commands.entity(ball_entity).insert(*player);
+            collision_events.send(CollisionEvent::Paddle);
+            idle.reset();
+            ramp.paddle_hit(&level, ball_field.0);
+
+            let is_catch = effects.is_some_and(|effects| {
+                effects.effects.iter().any(|e| e.effect_type == PowerUpType::Catch)
+            });
+            if is_catch {
+                // Hold the ball at its contact offset until released
+                commands.entity(ball_entity).insert(CaughtBall {
+                    paddle: paddle_entity,
+                    offset: ball_transform.translation.x - paddle_transform.translation.x,
+                    hold: Timer::from_seconds(CATCH_HOLD_TIME, TimerMode::Once),
+                });
+                break;
+            }
 
-                // Adjust X based on hit position on paddle
-                let hit_pos = ball_transform.translation.x - paddle_transform.translation.x;
-                let paddle_width = paddle_collider.size.x;
-                let normalized = hit_pos / (paddle_width / 2.0);
-                let speed = ball_velocity.0.length();
-                ball_velocity.0.x = normalized * speed * 0.8;
+            // Reflect Y direction
+            ball_velocity.0.y = ball_velocity.0.y.abs();
 
-                // Normalize to maintain current speed (preserves slow ball effect)
-                ball_velocity.0 = ball_velocity.0.normalize() * speed;
+            // Adjust X based on hit position on paddle, plus english from the paddle's motion
+            let hit_pos = ball_transform.translation.x - paddle_transform.translation.x;
+            let paddle_width = paddle_collider.size.x;
+            let normalized = hit_pos / (paddle_width / 2.0);
+            let speed = ball_velocity.0.length();
+            let english = paddle_velocity.0.clamp(-PADDLE_SPEED, PADDLE_SPEED) * PADDLE_ENGLISH;
+            ball_velocity.0.x = normalized * speed * 0.8 + english;
 
-                collision_events.send(CollisionEvent::Paddle);
-            }
+            // Normalize to maintain current speed (preserves slow ball effect)
+            ball_velocity.0 = clamp_vertical_angle(ball_velocity.0.normalize() * speed);
+            break;
         }
     }
 }
 
+/// Keep a velocity at least `BALL_MIN_VERTICAL_ANGLE` away from horizontal, at the same speed,
+/// so a ball can never crawl sideways between the walls
+pub fn clamp_vertical_angle(velocity: Vec2) -> Vec2 {
+    let speed = velocity.length();
+    let (min_sin, min_cos) = BALL_MIN_VERTICAL_ANGLE.to_radians().sin_cos();
+    if speed == 0.0 || velocity.y.abs() >= speed * min_sin {
+        return velocity;
+    }
+    let sign_y = if velocity.y < 0.0 { -1.0 } else { 1.0 };
+    Vec2::new(velocity.x.signum() * min_cos, sign_y * min_sin) * speed
+}
+
 /// Handle ball-wall collision (multi-ball support)
-/// Uses position check for bottom to prevent tunneling at high speeds
+/// Uses position checks for the barrier and bottom to prevent tunneling at high speeds.
+/// Losing every ball spends a spare life (back to countdown) or ends the game;
+/// in versus each field serves again on its own until that player runs out.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn ball_wall_collision(
     mut commands: Commands,
-    mut ball_query: Query<(Entity, &Transform, &mut Velocity, &Collider), With<Ball>>,
-    wall_query: Query<(&Transform, &Collider, &Wall)>,
+    mut ball_query: Query<(Entity, &mut Transform, &mut Velocity, &mut BallIdle, &Collider, &PlayfieldId), With<Ball>>,
+    wall_query: Query<(&Transform, &Collider, &Wall, &PlayfieldId), Without<Ball>>,
+    barrier_query: Query<(Entity, &Transform, &Collider, &PlayfieldId), (With<Barrier>, Without<Ball>)>,
+    field_entities: Query<
+        Entity,
+        Or<(With<Paddle>, With<PowerUp>, With<Pickup>, With<Laser>, With<Fog>, With<Barrier>)>,
+    >,
+    mut lives: ResMut<Lives>,
+    mut versus: Option<ResMut<VersusMode>>,
+    puzzle: Option<Res<PuzzleMode>>,
+    practice: Option<Res<PracticeMode>>,
+    level: Res<Level>,
+    mut ramp: ResMut<SpeedRamp>,
     mut next_state: ResMut<NextState<GameState>>,
     mut collision_events: EventWriter<CollisionEvent>,
 ) {
-    let total_balls = ball_query.iter().count();
-    let mut balls_lost = 0;
+    let mut total_balls = [0usize; VERSUS_FIELDS];
+    for (_, _, _, _, _, field) in &ball_query {
+        total_balls[field.0] += 1;
+    }
+    let mut balls_lost = [0usize; VERSUS_FIELDS];
     let bottom_limit = -WINDOW_HEIGHT / 2.0;
+    let mut barriers_used: Vec<Entity> = Vec::new();
+
+    for (ball_entity, mut ball_transform, mut ball_velocity, mut idle, ball_collider, ball_field) in &mut ball_query {
+        // Barrier: position-based so fast balls cannot slip through the thin bar
+        let barrier = barrier_query.iter().find(|(_, _, _, barrier_field)| *barrier_field == ball_field);
+        if let Some((barrier_entity, barrier_transform, barrier_collider, _)) = barrier {
+            let barrier_top = barrier_transform.translation.y + barrier_collider.size.y / 2.0;
+            let ball_bottom = ball_transform.translation.y - ball_collider.size.y / 2.0;
+            if ball_velocity.0.y < 0.0 && ball_bottom < barrier_top {
+                ball_velocity.0.y = ball_velocity.0.y.abs();
+                ball_transform.translation.y = barrier_top + ball_collider.size.y / 2.0;
+                if !barriers_used.contains(&barrier_entity) {
+                    barriers_used.push(barrier_entity);
+                }
+                collision_events.send(CollisionEvent::Wall);
+                continue;
+            }
+        }
 
-    for (ball_entity, ball_transform, mut ball_velocity, ball_collider) in &mut ball_query {
         // Position-based bottom check (no tunneling possible)
         if ball_transform.translation.y < bottom_limit {
+            // Practice invincibility: the bottom edge bounces like a wall
+            if practice.as_ref().is_some_and(|practice| practice.invincible) {
+                ball_velocity.0.y = ball_velocity.0.y.abs();
+                ball_transform.translation.y = bottom_limit;
+                collision_events.send(CollisionEvent::Wall);
+                continue;
+            }
             commands.entity(ball_entity).despawn();
-            balls_lost += 1;
+            balls_lost[ball_field.0] += 1;
             continue;
         }
 
-        for (wall_transform, wall_collider, wall_type) in &wall_query {
+        for (wall_transform, wall_collider, wall_type, wall_field) in &wall_query {
+            if wall_field != ball_field {
+                continue;
+            }
             if aabb_collision(
                 ball_transform.translation.truncate(),
                 ball_collider.size,
                 wall_transform.translation.truncate(),
                 wall_collider.size,
             ) {
+                if !matches!(wall_type, Wall::Bottom) {
+                    idle.record(ball_transform.translation.truncate());
+                }
                 match wall_type {
                     Wall::Top => {
                         ball_velocity.0.y = -ball_velocity.0.y.abs();
                         collision_events.send(CollisionEvent::Wall);
+                        ramp.top_wall_hit(&level, ball_field.0);
                     }
                     Wall::Left => {
                         ball_velocity.0.x = ball_velocity.0.x.abs();
@@ -95,9 +190,51 @@ pub fn ball_wall_collision(
         }
     }
 
-    if balls_lost > 0 && balls_lost >= total_balls {
-        collision_events.send(CollisionEvent::GameOver);
-        next_state.set(GameState::GameOver);
+    // Each barrier is single-use
+    for barrier_entity in barriers_used {
+        commands.entity(barrier_entity).despawn();
+    }
+
+    for field in 0..VERSUS_FIELDS {
+        if balls_lost[field] == 0 || balls_lost[field] < total_balls[field] {
+            continue;
+        }
+
+        if let Some(versus) = versus.as_mut() {
+            let side = &mut versus.fields[field];
+            side.lives = side.lives.saturating_sub(1);
+            if side.lives > 0 {
+                spawn_field_ball(&mut commands, PlayfieldId(field), ramp.speed(&level, field), BallKind::Normal);
+            } else {
+                versus.decide(1 - field);
+                collision_events.send(CollisionEvent::GameOver);
+                next_state.set(GameState::GameOver);
+            }
+            continue;
+        }
+
+        // Puzzle stages have no lives: the next serve waits on the paddle until the shots run out
+        if let Some(puzzle) = &puzzle {
+            if puzzle.shots_left == 0 {
+                collision_events.send(CollisionEvent::GameOver);
+                next_state.set(GameState::GameOver);
+            }
+            continue;
+        }
+
+        if lives.spare > 0 || practice.is_some() {
+            // Spend a life (practice has unlimited lives): clear the paddle and field items, then count down again
+            if practice.is_none() {
+                lives.spare -= 1;
+            }
+            for entity in &field_entities {
+                commands.entity(entity).despawn_recursive();
+            }
+            next_state.set(GameState::Countdown);
+        } else {
+            collision_events.send(CollisionEvent::GameOver);
+            next_state.set(GameState::GameOver);
+        }
     }
 }
 
@@ -122,34 +259,85 @@ fn apply_block_score(
     }
 }
 
-/// Handle ball-block collision (multi-ball support + power-up drops + special block types)
+/// Handle ball-block collision (multi-ball support + power-up drops + special block types).
+/// Balls only hit blocks in their own field; versus hits also feed that player's combo.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn ball_block_collision(
     mut commands: Commands,
-    mut ball_query: Query<(Entity, &Transform, &mut Velocity, &Collider), With<Ball>>,
+    mut ball_query: Query<
+        (
+            Entity,
+            &Transform,
+            &mut Velocity,
+            &mut BallIdle,
+            &mut BallKind,
+            &mut BlockContacts,
+            &Collider,
+            Option<&Player>,
+            &PlayfieldId,
+        ),
+        With<Ball>,
+    >,
     mut block_query: Query<(Entity, &Transform, &Collider, &mut Sprite, &mut Block)>,
+    block_fields: Query<&PlayfieldId, With<Block>>,
     mut score: ResMut<Score>,
     mut combo: ResMut<ComboTracker>,
     mut collision_events: EventWriter<CollisionEvent>,
     mut screen_shake: ResMut<crate::resources::ScreenShake>,
     mut level_stats: ResMut<LevelStats>,
-    paddle_effects_query: Query<&PowerUpEffects, With<Paddle>>,
+    paddle_effects_query: Query<(&PowerUpEffects, &PlayfieldId), With<Paddle>>,
+    registry: Res<PowerUpRegistry>,
+    level: Res<Level>,
+    mut drop_rng: ResMut<DropRng>,
+    mut versus: Option<ResMut<VersusMode>>,
+    mut ramp: ResMut<SpeedRamp>,
+    settings: Res<GameSettings>,
 ) {
-    // Check if fireball is active on any paddle
-    let is_fireball = paddle_effects_query.iter().any(|effects| {
-        effects.effects.iter().any(|e| e.effect_type == PowerUpType::FireBall)
-    });
+    // Fields where a paddle has the fireball active
+    let fireball_fields = fireball_fields(&paddle_effects_query);
 
     // Track which blocks have been destroyed this frame to avoid double-processing
     let mut destroyed_blocks = Vec::new();
 
-    for (_, ball_transform, mut ball_velocity, ball_collider) in &mut ball_query {
+    for (_, ball_transform, mut ball_velocity, mut idle, mut kind, mut contacts, ball_collider, player, ball_field) in
+        &mut ball_query
+    {
+        // Versus fields keep their own score and combo
+        let (score, combo) = match versus.as_deref_mut() {
+            Some(versus) => {
+                let side = &mut versus.fields[ball_field.0];
+                (&mut side.score, &mut side.combo)
+            }
+            None => (&mut *score, &mut *combo),
+        };
+        let score_before = score.value;
+        let combo_before = combo.count;
+        let is_fireball = fireball_fields.contains(ball_field);
         let mut hit_block = false;
         let mut pending_explosions: Vec<Vec2> = Vec::new();
 
+        // A ghost ball leaves the block it passed through once it no longer overlaps it
+        if let Some(passing) = contacts.passing {
+            let inside = block_query.get(passing).is_ok_and(|(_, block_transform, block_collider, _, _)| {
+                aabb_collision(
+                    ball_transform.translation.truncate(),
+                    ball_collider.size,
+                    block_transform.translation.truncate(),
+                    block_collider.size,
+                )
+            });
+            if !inside {
+                contacts.passing = None;
+            }
+        }
+
         for (block_entity, block_transform, block_collider, mut block_sprite, mut block) in
             &mut block_query
         {
-            if destroyed_blocks.contains(&block_entity) {
+            if destroyed_blocks.contains(&block_entity)
+                || block_fields.get(block_entity) != Ok(ball_field)
+                || contacts.passing == Some(block_entity)
+            {
                 continue;
             }
 
@@ -162,8 +350,18 @@ pub fn ball_block_collision(
                 let block_pos = block_transform.translation.truncate();
                 let ball_pos = ball_transform.translation.truncate();
                 let diff = ball_pos - block_pos;
-                let x_overlap = (BLOCK_WIDTH + BALL_SIZE) / 2.0 - diff.x.abs();
-                let y_overlap = (BLOCK_HEIGHT + BALL_SIZE) / 2.0 - diff.y.abs();
+                let x_overlap = (block_collider.size.x + ball_collider.size.x) / 2.0 - diff.x.abs();
+                let y_overlap = (block_collider.size.y + ball_collider.size.y) / 2.0 - diff.y.abs();
+
+                let breakable = !matches!(block.block_type, BlockType::Steel);
+                if breakable {
+                    contacts.count += 1;
+                    // Ghost balls slip through every other breakable block untouched
+                    if *kind == BallKind::Ghost && contacts.count.is_multiple_of(2) {
+                        contacts.passing = Some(block_entity);
+                        continue;
+                    }
+                }
 
                 // Fireball skips reflection for non-Steel blocks
                 let skip_reflection = is_fireball && !matches!(block.block_type, BlockType::Steel);
@@ -198,59 +396,57 @@ pub fn ball_block_collision(
                     }
                 }
 
-                match block.block_type {
-                    BlockType::Normal => {
-                        let block_color = block_sprite.color;
-                        commands.entity(block_entity).despawn();
-                        destroyed_blocks.push(block_entity);
-                        spawn_particles(&mut commands, block_pos, block_color);
-                        screen_shake.trauma = (screen_shake.trauma + SHAKE_TRAUMA).min(1.0);
-
-                        apply_block_score(&mut score, &mut combo, &mut level_stats, 0);
-                        collision_events.send(CollisionEvent::Block);
+                damage_block(
+                    &mut commands,
+                    block_entity,
+                    block_pos,
+                    *ball_field,
+                    &mut block_sprite,
+                    &mut block,
+                    is_fireball || *kind == BallKind::Heavy,
+                    &registry,
+                    &level,
+                    &mut drop_rng,
+                    &mut destroyed_blocks,
+                    &mut pending_explosions,
+                    score,
+                    combo,
+                    &mut collision_events,
+                    &mut screen_shake,
+                    settings.particles,
+                    &mut level_stats,
+                );
 
-                        maybe_spawn_powerup(&mut commands, block_pos);
+                hit_block = true;
+                if breakable {
+                    idle.reset();
+                } else {
+                    idle.record(ball_transform.translation.truncate());
+                }
+                match *kind {
+                    BallKind::Bouncy => {
+                        let cap = ramp.speed(&level, ball_field.0) * BOUNCY_BALL_MAX;
+                        ball_velocity.0 = (ball_velocity.0 * BOUNCY_BALL_SPEEDUP).clamp_length_max(cap);
                     }
-                    BlockType::Durable { hits_remaining } => {
-                        if is_fireball || hits_remaining <= 1 {
-                            let block_color = block_sprite.color;
-                            commands.entity(block_entity).despawn();
-                            destroyed_blocks.push(block_entity);
-                            spawn_particles(&mut commands, block_pos, block_color);
-                            screen_shake.trauma =
-                                (screen_shake.trauma + SHAKE_TRAUMA).min(1.0);
-
-                            apply_block_score(&mut score, &mut combo, &mut level_stats, DURABLE_SCORE_BONUS);
-                            collision_events.send(CollisionEvent::Block);
-
-                            maybe_spawn_powerup(&mut commands, block_pos);
-                        } else {
-                            // Reduce hits and change color
-                            block.block_type = BlockType::Durable {
-                                hits_remaining: hits_remaining - 1,
-                            };
-                            block_sprite.color = durable_color(hits_remaining - 1);
-                            collision_events.send(CollisionEvent::Wall); // lighter hit sound
+                    BallKind::Splitter => {
+                        let twin_velocity = Vec2::from_angle(SPLITTER_ANGLE.to_radians()).rotate(ball_velocity.0);
+                        let twin = spawn_kind_ball(
+                            &mut commands,
+                            *ball_field,
+                            ball_transform.translation.truncate(),
+                            clamp_vertical_angle(twin_velocity),
+                            BallKind::Normal,
+                        );
+                        if let Some(player) = player {
+                            commands.entity(twin).insert(*player);
                         }
+                        *kind = BallKind::Normal;
                     }
-                    BlockType::Steel => {
-                        // Already handled above (reflection only)
-                    }
-                    BlockType::Explosive => {
-                        let block_color = block_sprite.color;
-                        commands.entity(block_entity).despawn();
-                        destroyed_blocks.push(block_entity);
-                        spawn_particles(&mut commands, block_pos, block_color);
-                        screen_shake.trauma = (screen_shake.trauma + SHAKE_TRAUMA * 1.5).min(1.0);
-
-                        apply_block_score(&mut score, &mut combo, &mut level_stats, 0);
-                        collision_events.send(CollisionEvent::Block);
-
-                        pending_explosions.push(block_pos);
-                    }
+                    _ => {}
+                }
+                if block_pos.y >= SPEED_RAMP_TOP_ROWS_Y && destroyed_blocks.contains(&block_entity) {
+                    ramp.top_row_broken(&level, ball_field.0);
                 }
-
-                hit_block = true;
                 // Fireball penetrates non-Steel blocks (no break)
                 if !is_fireball || matches!(block.block_type, BlockType::Steel) {
                     break;
@@ -264,32 +460,233 @@ pub fn ball_block_collision(
             process_explosions(
                 &mut commands,
                 &mut block_query,
+                &block_fields,
+                *ball_field,
                 &mut destroyed_blocks,
                 &mut explosion_queue,
-                &mut score,
-                &mut combo,
+                score,
+                combo,
                 &mut collision_events,
                 &mut screen_shake,
+                settings.particles,
                 &mut level_stats,
             );
         }
 
+        credit_player(&mut level_stats, player, score.value - score_before);
+        if let Some(versus) = versus.as_mut() {
+            versus.send_garbage(ball_field.0, combo_before);
+        }
+
         if hit_block && !is_fireball {
             continue;
         }
     }
 }
 
-/// Process explosion chain reactions
+/// Add score gained by a ball or laser to its player's share (player 1 when unowned)
+fn credit_player(level_stats: &mut LevelStats, player: Option<&Player>, gained: u32) {
+    let index = player.map_or(0, |p| p.0);
+    level_stats.player_scores[index] += gained;
+}
+
+/// Apply one hit to a breakable block: score, durability, and explosion queueing.
+/// `instant_kill` destroys Durable blocks regardless of remaining hits. Steel is ignored.
+/// Drops are rolled from the registry's table for this level and block kind.
+#[allow(clippy::too_many_arguments)]
+fn damage_block(
+    commands: &mut Commands,
+    block_entity: Entity,
+    block_pos: Vec2,
+    field: PlayfieldId,
+    block_sprite: &mut Sprite,
+    block: &mut Block,
+    instant_kill: bool,
+    registry: &PowerUpRegistry,
+    level: &Level,
+    drop_rng: &mut DropRng,
+    destroyed_blocks: &mut Vec<Entity>,
+    pending_explosions: &mut Vec<Vec2>,
+    score: &mut Score,
+    combo: &mut ComboTracker,
+    collision_events: &mut EventWriter<CollisionEvent>,
+    screen_shake: &mut ResMut<ScreenShake>,
+    particle_density: f32,
+    level_stats: &mut ResMut<LevelStats>,
+) {
+    match block.block_type {
+        BlockType::Normal => {
+            let block_color = block_sprite.color;
+            commands.entity(block_entity).despawn_recursive();
+            destroyed_blocks.push(block_entity);
+            spawn_particles(commands, block_pos, block_color, particle_density);
+            screen_shake.trauma = (screen_shake.trauma + SHAKE_TRAUMA).min(1.0);
+
+            apply_block_score(score, combo, level_stats, 0);
+            collision_events.send(CollisionEvent::Block);
+
+            if !maybe_spawn_powerup(commands, registry, level, drop_rng, block.block_type, block_pos, field) {
+                maybe_spawn_pickup(commands, drop_rng, block_pos, field);
+            }
+        }
+        BlockType::Durable { hits_remaining } => {
+            if instant_kill || hits_remaining <= 1 {
+                let block_color = block_sprite.color;
+                commands.entity(block_entity).despawn_recursive();
+                destroyed_blocks.push(block_entity);
+                spawn_particles(commands, block_pos, block_color, particle_density);
+                screen_shake.trauma = (screen_shake.trauma + SHAKE_TRAUMA).min(1.0);
+
+                apply_block_score(score, combo, level_stats, DURABLE_SCORE_BONUS);
+                collision_events.send(CollisionEvent::Block);
+
+                if !maybe_spawn_powerup(commands, registry, level, drop_rng, block.block_type, block_pos, field) {
+                    maybe_spawn_pickup(commands, drop_rng, block_pos, field);
+                }
+            } else {
+                // Reduce hits and change color
+                block.block_type = BlockType::Durable {
+                    hits_remaining: hits_remaining - 1,
+                };
+                block_sprite.color = durable_color(hits_remaining - 1);
+                collision_events.send(CollisionEvent::Wall); // lighter hit sound
+            }
+        }
+        BlockType::Steel => {
+            // Indestructible; callers handle reflection/stopping
+        }
+        BlockType::Explosive => {
+            let block_color = block_sprite.color;
+            commands.entity(block_entity).despawn_recursive();
+            destroyed_blocks.push(block_entity);
+            spawn_particles(commands, block_pos, block_color, particle_density);
+            screen_shake.trauma = (screen_shake.trauma + SHAKE_TRAUMA * 1.5).min(1.0);
+
+            apply_block_score(score, combo, level_stats, 0);
+            collision_events.send(CollisionEvent::Block);
+
+            pending_explosions.push(block_pos);
+        }
+    }
+}
+
+/// Handle laser-block collision: each laser hits one block, stopping on Steel
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn laser_block_collision(
+    mut commands: Commands,
+    laser_query: Query<(Entity, &Transform, &Collider, Option<&Player>, &PlayfieldId), With<Laser>>,
+    mut block_query: Query<(Entity, &Transform, &Collider, &mut Sprite, &mut Block)>,
+    block_fields: Query<&PlayfieldId, With<Block>>,
+    mut score: ResMut<Score>,
+    mut combo: ResMut<ComboTracker>,
+    mut collision_events: EventWriter<CollisionEvent>,
+    mut screen_shake: ResMut<ScreenShake>,
+    mut level_stats: ResMut<LevelStats>,
+    registry: Res<PowerUpRegistry>,
+    level: Res<Level>,
+    mut drop_rng: ResMut<DropRng>,
+    mut versus: Option<ResMut<VersusMode>>,
+    settings: Res<GameSettings>,
+) {
+    let mut destroyed_blocks = Vec::new();
+
+    for (laser_entity, laser_transform, laser_collider, player, laser_field) in &laser_query {
+        // Versus fields keep their own score and combo
+        let (score, combo) = match versus.as_deref_mut() {
+            Some(versus) => {
+                let side = &mut versus.fields[laser_field.0];
+                (&mut side.score, &mut side.combo)
+            }
+            None => (&mut *score, &mut *combo),
+        };
+        let score_before = score.value;
+        let combo_before = combo.count;
+        let mut pending_explosions: Vec<Vec2> = Vec::new();
+
+        for (block_entity, block_transform, block_collider, mut block_sprite, mut block) in
+            &mut block_query
+        {
+            if destroyed_blocks.contains(&block_entity)
+                || block_fields.get(block_entity) != Ok(laser_field)
+            {
+                continue;
+            }
+
+            if aabb_collision(
+                laser_transform.translation.truncate(),
+                laser_collider.size,
+                block_transform.translation.truncate(),
+                block_collider.size,
+            ) {
+                commands.entity(laser_entity).despawn();
+
+                if matches!(block.block_type, BlockType::Steel) {
+                    collision_events.send(CollisionEvent::Wall);
+                } else {
+                    damage_block(
+                        &mut commands,
+                        block_entity,
+                        block_transform.translation.truncate(),
+                        *laser_field,
+                        &mut block_sprite,
+                        &mut block,
+                        false,
+                        &registry,
+                        &level,
+                        &mut drop_rng,
+                        &mut destroyed_blocks,
+                        &mut pending_explosions,
+                        score,
+                        combo,
+                        &mut collision_events,
+                        &mut screen_shake,
+                        settings.particles,
+                        &mut level_stats,
+                    );
+                }
+                break;
+            }
+        }
+
+        for explosion_pos in pending_explosions {
+            let mut explosion_queue = vec![explosion_pos];
+            process_explosions(
+                &mut commands,
+                &mut block_query,
+                &block_fields,
+                *laser_field,
+                &mut destroyed_blocks,
+                &mut explosion_queue,
+                score,
+                combo,
+                &mut collision_events,
+                &mut screen_shake,
+                settings.particles,
+                &mut level_stats,
+            );
+        }
+
+        credit_player(&mut level_stats, player, score.value - score_before);
+        if let Some(versus) = versus.as_mut() {
+            versus.send_garbage(laser_field.0, combo_before);
+        }
+    }
+}
+
+/// Process explosion chain reactions (within one field)
+#[allow(clippy::too_many_arguments)]
 fn process_explosions(
     commands: &mut Commands,
     block_query: &mut Query<(Entity, &Transform, &Collider, &mut Sprite, &mut Block)>,
+    block_fields: &Query<&PlayfieldId, With<Block>>,
+    field: PlayfieldId,
     destroyed_blocks: &mut Vec<Entity>,
     explosion_queue: &mut Vec<Vec2>,
-    score: &mut ResMut<Score>,
-    combo: &mut ResMut<ComboTracker>,
+    score: &mut Score,
+    combo: &mut ComboTracker,
     collision_events: &mut EventWriter<CollisionEvent>,
     screen_shake: &mut ResMut<ScreenShake>,
+    particle_density: f32,
     level_stats: &mut ResMut<LevelStats>,
 ) {
     let mut queue_idx = 0;
@@ -297,13 +694,13 @@ fn process_explosions(
         let explosion_pos = explosion_queue[queue_idx];
         queue_idx += 1;
 
-        // Find blocks within explosion radius
+        // Find blocks whose nearest edge is within explosion radius
         let mut to_destroy = Vec::new();
-        for (entity, transform, _, _, block) in block_query.iter() {
-            if destroyed_blocks.contains(&entity) {
+        for (entity, transform, collider, _, block) in block_query.iter() {
+            if destroyed_blocks.contains(&entity) || block_fields.get(entity) != Ok(&field) {
                 continue;
             }
-            let dist = transform.translation.truncate().distance(explosion_pos);
+            let dist = point_rect_distance(explosion_pos, transform.translation.truncate(), collider.size);
             if dist <= EXPLOSIVE_RADIUS {
                 match block.block_type {
                     BlockType::Steel => {} // Steel is immune to explosions
@@ -329,9 +726,9 @@ fn process_explosions(
                 }
             };
 
-            commands.entity(entity).despawn();
+            commands.entity(entity).despawn_recursive();
             destroyed_blocks.push(entity);
-            spawn_particles(commands, pos, block_color);
+            spawn_particles(commands, pos, block_color, particle_density);
 
             apply_block_score(score, combo, level_stats, 0);
             collision_events.send(CollisionEvent::Block);
@@ -345,58 +742,73 @@ fn process_explosions(
     }
 }
 
-/// Roll once for both drop chance and power-up type to avoid LCG correlation
-fn maybe_spawn_powerup(commands: &mut Commands, position: Vec2) {
-    let roll = rand_f32();
-    if roll < POWERUP_DROP_CHANCE {
-        // Remap [0, POWERUP_DROP_CHANCE) → [0, 1) for type selection
-        let type_roll = roll / POWERUP_DROP_CHANCE;
-        spawn_powerup(commands, position, type_roll);
+/// Roll once for both drop chance and power-up type to avoid LCG correlation.
+/// Returns whether a power-up was spawned.
+fn maybe_spawn_powerup(
+    commands: &mut Commands,
+    registry: &PowerUpRegistry,
+    level: &Level,
+    drop_rng: &mut DropRng,
+    block_type: BlockType,
+    position: Vec2,
+    field: PlayfieldId,
+) -> bool {
+    let table = registry.drop_table(level, block_type);
+    let roll = drop_rng.roll();
+    if roll >= table.chance {
+        return false;
     }
+    // Remap [0, chance) → [0, 1) for type selection
+    let Some(power_type) = table.pick(roll / table.chance) else {
+        return false;
+    };
+    let power_up = spawn_powerup(commands, registry, position, power_type);
+    commands.entity(power_up).insert(field);
+    true
 }
 
-/// Spawn a power-up at the given position using a pre-computed type roll in [0, 1)
-fn spawn_powerup(commands: &mut Commands, position: Vec2, type_roll: f32) {
-    let power_type = if type_roll < 0.25 {
-        PowerUpType::WidePaddle
-    } else if type_roll < 0.50 {
-        PowerUpType::MultiBall
-    } else if type_roll < 0.75 {
-        PowerUpType::SlowBall
+/// Spawn a falling power-up item styled from its registry entry
+pub fn spawn_powerup(
+    commands: &mut Commands,
+    registry: &PowerUpRegistry,
+    position: Vec2,
+    power_type: PowerUpType,
+) -> Entity {
+    let def = registry.get(power_type);
+
+    // Hazards fall as diamonds so they read differently from square capsules
+    let rotation = if def.hazard {
+        Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)
     } else {
-        PowerUpType::FireBall
-    };
-
-    let color = match power_type {
-        PowerUpType::WidePaddle => Color::srgb(0.95, 0.40, 0.80),  // Magenta
-        PowerUpType::MultiBall => Color::srgb(0.40, 0.90, 0.95),   // Cyan
-        PowerUpType::SlowBall => Color::srgb(0.60, 0.95, 0.40),    // Lime
-        PowerUpType::FireBall => Color::srgb(1.0, 0.35, 0.15),     // Orange-red
+        Quat::IDENTITY
     };
 
-    commands.spawn((
-        Sprite {
-            color,
-            custom_size: Some(Vec2::splat(POWERUP_SIZE)),
-            ..default()
-        },
-        Transform::from_xyz(position.x, position.y, 0.5),
-        PowerUp { power_type },
-        Velocity(Vec2::new(0.0, -POWERUP_FALL_SPEED)),
-        Collider {
-            size: Vec2::splat(POWERUP_SIZE),
-        },
-    ));
+    commands
+        .spawn((
+            Sprite {
+                color: def.color,
+                custom_size: Some(Vec2::splat(POWERUP_SIZE)),
+                ..default()
+            },
+            Transform::from_xyz(position.x, position.y, 0.5).with_rotation(rotation),
+            PowerUp { power_type },
+            Velocity(Vec2::new(0.0, -POWERUP_FALL_SPEED)),
+            Collider {
+                size: Vec2::splat(POWERUP_SIZE),
+            },
+        ))
+        .id()
 }
 
-/// Spawn particle effects at the given position with the given color
-fn spawn_particles(commands: &mut Commands, position: Vec2, color: Color) {
+/// Spawn particle effects at the given position with the given color, thinned to the density setting
+fn spawn_particles(commands: &mut Commands, position: Vec2, color: Color, density: f32) {
     use std::f32::consts::TAU;
     use crate::components::Particle;
 
-    for i in 0..PARTICLE_COUNT {
+    let count = particle_count(PARTICLE_COUNT, density);
+    for i in 0..count {
         // Spread particles evenly around a circle with some randomness
-        let base_angle = (i as f32 / PARTICLE_COUNT as f32) * TAU;
+        let base_angle = (i as f32 / count as f32) * TAU;
         let angle = base_angle + simple_rand(i as u32) * 0.5;
         let speed = PARTICLE_SPEED * (0.5 + simple_rand(i as u32 + 100) * 0.5);
         let velocity = Vec2::new(angle.cos() * speed, angle.sin() * speed);
@@ -488,7 +900,7 @@ mod tests {
     fn simple_rand_range() {
         for seed in 0..1000 {
             let val = simple_rand(seed);
-            assert!(val >= 0.0 && val < 1.0, "seed {seed} produced {val}");
+            assert!((0.0..1.0).contains(&val), "seed {seed} produced {val}");
         }
     }
 
@@ -528,6 +940,29 @@ mod tests {
         assert!(ball_vel.0.y > 0.0, "Ball should bounce upward");
     }
 
+    #[test]
+    fn ball_ignores_paddle_in_other_field() {
+        let mut app = test_app();
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        app.world_mut().entity_mut(paddle).insert(PlayfieldId(1));
+        spawn_test_ball(
+            app.world_mut(),
+            Vec2::new(0.0, ball_y_overlapping_paddle()),
+            Vec2::new(0.0, -BALL_SPEED),
+        );
+
+        app.add_systems(Update, ball_paddle_collision);
+        app.update();
+
+        let ball_vel = app
+            .world_mut()
+            .query::<&Velocity>()
+            .iter(app.world())
+            .next()
+            .unwrap();
+        assert!(ball_vel.0.y < 0.0, "Paddles only catch balls of their own field");
+    }
+
     #[test]
     fn ball_no_bounce_when_moving_up() {
         let mut app = test_app();
@@ -575,6 +1010,61 @@ mod tests {
         assert!(ball_vel.0.x > 0.0, "Hit right side → positive x velocity");
     }
 
+    #[test]
+    fn moving_paddle_adds_english() {
+        let mut app = test_app();
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        app.world_mut()
+            .entity_mut(paddle)
+            .insert(PaddleVelocity(PADDLE_SPEED));
+        spawn_test_ball(
+            app.world_mut(),
+            Vec2::new(0.0, ball_y_overlapping_paddle()),
+            Vec2::new(0.0, -BALL_SPEED),
+        );
+
+        app.add_systems(Update, ball_paddle_collision);
+        app.update();
+
+        let ball_vel = app.world_mut().query::<&Velocity>().single(app.world()).0;
+        assert!(ball_vel.x > 0.0, "Center hit on a paddle moving right → ball drifts right");
+        assert!((ball_vel.length() - BALL_SPEED).abs() < 0.01, "English keeps the speed");
+    }
+
+    #[test]
+    fn paddle_bounce_never_goes_near_horizontal() {
+        let mut app = test_app();
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        app.world_mut()
+            .entity_mut(paddle)
+            .insert(PaddleVelocity(-10.0 * PADDLE_SPEED));
+        // Edge hit on a paddle rushing the other way would otherwise be almost flat
+        spawn_test_ball(
+            app.world_mut(),
+            Vec2::new(-PADDLE_WIDTH / 2.0, ball_y_overlapping_paddle()),
+            Vec2::new(0.0, -BALL_SPEED),
+        );
+
+        app.add_systems(Update, ball_paddle_collision);
+        app.update();
+
+        let ball_vel = app.world_mut().query::<&Velocity>().single(app.world()).0;
+        let angle = ball_vel.y.atan2(ball_vel.x.abs()).to_degrees();
+        assert!(angle >= BALL_MIN_VERTICAL_ANGLE - 0.01, "angle {angle} too flat");
+        assert!(ball_vel.x < 0.0 && ball_vel.y > 0.0);
+    }
+
+    #[test]
+    fn vertical_angle_clamp_keeps_speed_and_direction() {
+        let flat = clamp_vertical_angle(Vec2::new(-300.0, -1.0));
+        assert!((flat.length() - Vec2::new(-300.0, -1.0).length()).abs() < 0.01);
+        assert!(flat.x < 0.0 && flat.y < 0.0);
+        assert!((flat.y.abs() / flat.length() - BALL_MIN_VERTICAL_ANGLE.to_radians().sin()).abs() < 1e-4);
+
+        let steep = Vec2::new(100.0, 300.0);
+        assert_eq!(clamp_vertical_angle(steep), steep);
+    }
+
     #[test]
     fn ball_speed_preserved_after_paddle() {
         let mut app = test_app();
@@ -602,6 +1092,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn catch_paddle_holds_ball_at_offset() {
+        let mut app = test_app();
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        app.world_mut().entity_mut(paddle).insert(PowerUpEffects {
+            effects: vec![ActiveEffect {
+                effect_type: PowerUpType::Catch,
+                timer: Timer::from_seconds(CATCH_DURATION, TimerMode::Once),
+            }],
+        });
+        let ball = spawn_test_ball(
+            app.world_mut(),
+            Vec2::new(-20.0, ball_y_overlapping_paddle()),
+            Vec2::new(0.0, -BALL_SPEED),
+        );
+
+        app.add_systems(Update, ball_paddle_collision);
+        app.update();
+
+        let caught = app.world().entity(ball).get::<CaughtBall>();
+        assert!(caught.is_some(), "Ball should be caught by the paddle");
+        assert_eq!(caught.unwrap().offset, -20.0);
+    }
+
     // --- ball_wall_collision ---
 
     #[test]
@@ -770,6 +1284,168 @@ mod tests {
         );
     }
 
+    #[test]
+    fn versus_ball_loss_costs_a_serve_in_its_field_only() {
+        let mut app = test_app();
+        app.insert_resource(VersusMode::default());
+        let lost = spawn_test_ball(
+            app.world_mut(),
+            Vec2::new(0.0, -WINDOW_HEIGHT / 2.0 - 10.0),
+            Vec2::new(0.0, -BALL_SPEED),
+        );
+        // The other field still has its ball in play
+        let other = spawn_test_ball(app.world_mut(), Vec2::ZERO, Vec2::new(0.0, BALL_SPEED));
+        app.world_mut().entity_mut(other).insert(PlayfieldId(1));
+
+        app.add_systems(Update, ball_wall_collision);
+        app.update();
+
+        assert!(app.world().get_entity(lost).is_err());
+        let versus = app.world().resource::<VersusMode>();
+        assert_eq!(versus.fields[0].lives, VERSUS_LIVES - 1);
+        assert_eq!(versus.fields[1].lives, VERSUS_LIVES);
+        assert_eq!(versus.winner, None);
+        let served = app
+            .world_mut()
+            .query::<&PlayfieldId>()
+            .iter(app.world())
+            .filter(|field| field.0 == 0)
+            .count();
+        assert_eq!(served, 1, "A new ball is served in the losing field");
+        assert!(!matches!(
+            app.world().resource::<NextState<GameState>>(),
+            NextState::Pending(GameState::Countdown | GameState::GameOver)
+        ));
+    }
+
+    #[test]
+    fn puzzle_ball_loss_ends_stage_only_when_out_of_shots() {
+        for (shots_left, game_over) in [(1, false), (0, true)] {
+            let mut app = test_app();
+            app.insert_resource(PuzzleMode {
+                shots_left,
+                ..default()
+            });
+            spawn_test_ball(
+                app.world_mut(),
+                Vec2::new(0.0, -WINDOW_HEIGHT / 2.0 - 10.0),
+                Vec2::new(0.0, -BALL_SPEED),
+            );
+
+            app.add_systems(Update, ball_wall_collision);
+            app.update();
+
+            let state = app.world().resource::<NextState<GameState>>();
+            assert_eq!(matches!(state, NextState::Pending(GameState::GameOver)), game_over);
+            assert!(!matches!(state, NextState::Pending(GameState::Countdown)), "No lives are spent");
+            assert_eq!(app.world().resource::<Lives>().spare, Lives::default().spare);
+        }
+    }
+
+    #[test]
+    fn versus_last_life_decides_match() {
+        let mut app = test_app();
+        let mut versus = VersusMode::default();
+        versus.fields[1].lives = 1;
+        app.insert_resource(versus);
+        let ball = spawn_test_ball(
+            app.world_mut(),
+            Vec2::new(VERSUS_FIELD_SPACING, -WINDOW_HEIGHT / 2.0 - 10.0),
+            Vec2::new(0.0, -BALL_SPEED),
+        );
+        app.world_mut().entity_mut(ball).insert(PlayfieldId(1));
+
+        app.add_systems(Update, ball_wall_collision);
+        app.update();
+
+        assert_eq!(app.world().resource::<VersusMode>().winner, Some(0));
+        assert!(matches!(
+            app.world().resource::<NextState<GameState>>(),
+            NextState::Pending(GameState::GameOver)
+        ));
+    }
+
+    fn spawn_test_barrier(world: &mut World) -> Entity {
+        world
+            .spawn((
+                Transform::from_xyz(0.0, BARRIER_Y, 0.0),
+                Barrier {
+                    timer: Timer::from_seconds(BARRIER_DURATION, TimerMode::Once),
+                },
+                Collider {
+                    size: Vec2::new(WINDOW_WIDTH, BARRIER_HEIGHT),
+                },
+            ))
+            .id()
+    }
+
+    #[test]
+    fn barrier_bounces_ball_once_and_disappears() {
+        let mut app = test_app();
+        let barrier = spawn_test_barrier(app.world_mut());
+        // Ball already past the bar this frame (fast ball)
+        let ball = spawn_test_ball(
+            app.world_mut(),
+            Vec2::new(0.0, BARRIER_Y - 2.0),
+            Vec2::new(0.0, -BALL_SPEED),
+        );
+
+        app.add_systems(Update, ball_wall_collision);
+        app.update();
+
+        let vel = app.world().entity(ball).get::<Velocity>().unwrap();
+        assert!(vel.0.y > 0.0, "Barrier should bounce the ball up");
+        assert!(app.world().get_entity(barrier).is_err(), "Barrier is single-use");
+    }
+
+    #[test]
+    fn barrier_only_bounces_balls_in_its_field() {
+        let mut app = test_app();
+        let barrier = spawn_test_barrier(app.world_mut());
+        app.world_mut().entity_mut(barrier).insert(PlayfieldId(1));
+        let ball = spawn_test_ball(
+            app.world_mut(),
+            Vec2::new(0.0, BARRIER_Y - 2.0),
+            Vec2::new(0.0, -BALL_SPEED),
+        );
+
+        app.add_systems(Update, ball_wall_collision);
+        app.update();
+
+        let vel = app.world().entity(ball).get::<Velocity>().unwrap();
+        assert!(vel.0.y < 0.0, "Another field's barrier lets the ball through");
+        assert!(app.world().get_entity(barrier).is_ok());
+    }
+
+    #[test]
+    fn spare_life_restarts_countdown_instead_of_game_over() {
+        let mut app = test_app();
+        app.world_mut().resource_mut::<Lives>().spare = 1;
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        spawn_test_ball(
+            app.world_mut(),
+            Vec2::new(0.0, -WINDOW_HEIGHT / 2.0 - 10.0),
+            Vec2::new(0.0, -BALL_SPEED),
+        );
+
+        app.add_systems(
+            Update,
+            ball_wall_collision.before(collect_collision_events),
+        );
+        app.add_systems(Update, collect_collision_events);
+        app.init_resource::<CollectedEvents>();
+        app.update();
+
+        let events = app.world().resource::<CollectedEvents>();
+        assert!(!events.events.contains(&CollisionEvent::GameOver));
+        assert_eq!(app.world().resource::<Lives>().spare, 0);
+        assert!(app.world().get_entity(paddle).is_err(), "Paddle respawns after the countdown");
+        assert!(matches!(
+            app.world().resource::<NextState<GameState>>(),
+            NextState::Pending(GameState::Countdown)
+        ));
+    }
+
     // --- ball_block_collision ---
 
     #[test]
@@ -970,6 +1646,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn destroyed_block_takes_its_glyph_overlay_along() {
+        let mut app = test_app();
+        let block = spawn_test_block(app.world_mut(), Vec2::new(0.0, 100.0));
+        app.world_mut()
+            .spawn((Text2d::new("S"), GlyphOverlay))
+            .set_parent(block);
+        spawn_test_ball(app.world_mut(), Vec2::new(0.0, 100.0), Vec2::new(0.0, BALL_SPEED));
+
+        app.add_systems(Update, ball_block_collision);
+        app.update();
+
+        let overlays = app.world_mut().query::<&GlyphOverlay>().iter(app.world()).count();
+        assert_eq!(overlays, 0, "No orphaned glyph label is left behind");
+    }
+
+    #[test]
+    fn versus_block_hit_scores_for_its_field() {
+        let mut app = test_app();
+        app.insert_resource(VersusMode::default());
+        let block = spawn_test_block(app.world_mut(), Vec2::new(0.0, 100.0));
+        app.world_mut().entity_mut(block).insert(PlayfieldId(1));
+        let ball = spawn_test_ball(app.world_mut(), Vec2::new(0.0, 100.0), Vec2::new(0.0, BALL_SPEED));
+        app.world_mut().entity_mut(ball).insert(PlayfieldId(1));
+
+        app.add_systems(Update, ball_block_collision);
+        app.update();
+
+        let versus = app.world().resource::<VersusMode>();
+        assert_eq!(versus.fields[1].score.value, SCORE_PER_BLOCK);
+        assert_eq!(versus.fields[1].combo.count, 1);
+        assert_eq!(versus.fields[0].score.value, 0);
+        assert_eq!(app.world().resource::<Score>().value, 0, "Shared score stays untouched");
+    }
+
     #[test]
     fn explosive_block_chain_destroys_nearby() {
         let mut app = test_app();
@@ -1001,6 +1712,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn wide_block_hit_near_end_reflects_vertically() {
+        let mut app = test_app();
+        // 3-cell wide block; ball comes up from below near its right end
+        let size = Vec2::new(3.0 * BLOCK_WIDTH + 2.0 * BLOCK_GAP, BLOCK_HEIGHT);
+        app.world_mut().spawn((
+            Sprite::default(),
+            Transform::from_xyz(0.0, 100.0, 0.0),
+            Block {
+                block_type: BlockType::Normal,
+            },
+            Collider { size },
+        ));
+        spawn_test_ball(
+            app.world_mut(),
+            Vec2::new(size.x / 2.0 - 10.0, 100.0 - BLOCK_HEIGHT / 2.0 - BALL_SIZE / 2.0 + 2.0),
+            Vec2::new(100.0, BALL_SPEED),
+        );
+
+        app.add_systems(Update, ball_block_collision);
+        app.update();
+
+        let ball_vel = app
+            .world_mut()
+            .query_filtered::<&Velocity, With<Ball>>()
+            .iter(app.world())
+            .next()
+            .unwrap();
+        assert!(ball_vel.0.y < 0.0, "Should bounce off the bottom face, vy={}", ball_vel.0.y);
+        assert!(ball_vel.0.x > 0.0, "x velocity should be untouched, vx={}", ball_vel.0.x);
+    }
+
+    #[test]
+    fn explosion_reaches_large_block_by_edge() {
+        let mut app = test_app();
+        spawn_test_block_typed(app.world_mut(), Vec2::new(0.0, 100.0), BlockType::Explosive);
+        // Tall block whose center is beyond the radius but whose edge is inside it
+        let size = Vec2::new(BLOCK_WIDTH, 3.0 * BLOCK_HEIGHT + 2.0 * BLOCK_GAP);
+        app.world_mut().spawn((
+            Sprite::default(),
+            Transform::from_xyz(0.0, 100.0 - EXPLOSIVE_RADIUS - size.y / 2.0 + 10.0, 0.0),
+            Block {
+                block_type: BlockType::Normal,
+            },
+            Collider { size },
+        ));
+        spawn_test_ball(
+            app.world_mut(),
+            Vec2::new(0.0, 100.0),
+            Vec2::new(0.0, BALL_SPEED),
+        );
+
+        app.add_systems(Update, ball_block_collision);
+        app.update();
+
+        let block_count = app
+            .world_mut()
+            .query::<&Block>()
+            .iter(app.world())
+            .count();
+        assert_eq!(block_count, 0, "Large block within radius of its edge should explode");
+    }
+
     #[test]
     fn combo_scoring_increments() {
         let mut app = test_app();
@@ -1219,74 +1993,308 @@ mod tests {
         );
     }
 
-    // --- spawn_powerup type mapping ---
+    // --- ball kind tests ---
 
     #[test]
-    fn spawn_powerup_selects_wide_paddle() {
+    fn heavy_ball_destroys_durable_instantly() {
         let mut app = test_app();
-        app.add_systems(Update, |mut commands: Commands| {
-            spawn_powerup(&mut commands, Vec2::ZERO, 0.1); // < 0.25
-        });
+        spawn_test_block_typed(app.world_mut(), Vec2::new(0.0, 100.0), BlockType::Durable { hits_remaining: 3 });
+        let ball = spawn_test_ball(app.world_mut(), Vec2::new(0.0, 85.0), Vec2::new(0.0, BALL_SPEED));
+        app.world_mut().entity_mut(ball).insert(BallKind::Heavy);
+
+        app.add_systems(Update, ball_block_collision);
+        app.update();
+
+        let block_count = app.world_mut().query::<&Block>().iter(app.world()).count();
+        assert_eq!(block_count, 0, "Heavy ball breaks a durable block in one hit");
+        assert!(app.world().get::<Velocity>(ball).unwrap().0.y < 0.0, "Heavy ball still bounces");
+    }
+
+    #[test]
+    fn ghost_ball_passes_through_every_other_block() {
+        let mut app = test_app();
+        let first = spawn_test_block_typed(app.world_mut(), Vec2::new(0.0, 100.0), BlockType::Durable { hits_remaining: 3 });
+        let ball = spawn_test_ball(app.world_mut(), Vec2::new(0.0, 85.0), Vec2::new(0.0, BALL_SPEED));
+        app.world_mut().entity_mut(ball).insert(BallKind::Ghost);
+        app.add_systems(Update, ball_block_collision);
+
+        // First contact is solid
         app.update();
+        assert!(app.world().get::<Velocity>(ball).unwrap().0.y < 0.0);
+        assert_eq!(
+            app.world().get::<Block>(first).unwrap().block_type,
+            BlockType::Durable { hits_remaining: 2 }
+        );
+
+        // Second contact passes through without damage, and stays ignored while overlapping
+        app.world_mut().get_mut::<Velocity>(ball).unwrap().0 = Vec2::new(0.0, BALL_SPEED);
+        app.update();
+        app.update();
+        assert!(app.world().get::<Velocity>(ball).unwrap().0.y > 0.0, "Ghost ball keeps going");
+        assert_eq!(
+            app.world().get::<Block>(first).unwrap().block_type,
+            BlockType::Durable { hits_remaining: 2 }
+        );
+        assert_eq!(app.world().get::<BlockContacts>(ball).unwrap().passing, Some(first));
+    }
 
-        let powerup = app
+    #[test]
+    fn bouncy_ball_speeds_up_on_block_hit_up_to_cap() {
+        let mut app = test_app();
+        spawn_test_block_typed(app.world_mut(), Vec2::new(0.0, 100.0), BlockType::Steel);
+        let ball = spawn_test_ball(app.world_mut(), Vec2::new(0.0, 85.0), Vec2::new(0.0, BALL_SPEED));
+        app.world_mut().entity_mut(ball).insert(BallKind::Bouncy);
+        app.add_systems(Update, ball_block_collision);
+
+        app.update();
+        let speed = app.world().get::<Velocity>(ball).unwrap().0.length();
+        assert!((speed - BALL_SPEED * BOUNCY_BALL_SPEEDUP).abs() < 0.01);
+
+        app.world_mut().get_mut::<Velocity>(ball).unwrap().0 = Vec2::new(0.0, BALL_SPEED * BOUNCY_BALL_MAX);
+        app.update();
+        let speed = app.world().get::<Velocity>(ball).unwrap().0.length();
+        assert!((speed - BALL_SPEED * BOUNCY_BALL_MAX).abs() < 0.01, "Capped speed");
+    }
+
+    #[test]
+    fn splitter_ball_splits_on_first_hit_only() {
+        let mut app = test_app();
+        spawn_test_block_typed(app.world_mut(), Vec2::new(0.0, 100.0), BlockType::Steel);
+        let ball = spawn_test_ball(app.world_mut(), Vec2::new(0.0, 85.0), Vec2::new(0.0, BALL_SPEED));
+        app.world_mut().entity_mut(ball).insert((BallKind::Splitter, Player(1)));
+        app.add_systems(Update, ball_block_collision);
+
+        app.update();
+        let mut balls = app.world_mut().query::<(Entity, &BallKind, &Player, &Velocity)>();
+        let balls: Vec<_> = balls.iter(app.world()).map(|(e, k, p, v)| (e, *k, *p, v.0)).collect();
+        assert_eq!(balls.len(), 2, "Split into two balls");
+        assert!(balls.iter().all(|(_, kind, player, velocity)| {
+            *kind == BallKind::Normal && player.0 == 1 && velocity.y < 0.0
+        }));
+        let (_, _, _, twin_velocity) = balls.iter().find(|(e, ..)| *e != ball).unwrap();
+        assert!(twin_velocity.x.abs() > 1.0, "Twin heads off at an angle");
+
+        // The original is Normal now: no more splitting
+        app.world_mut().get_mut::<Velocity>(ball).unwrap().0 = Vec2::new(0.0, BALL_SPEED);
+        app.update();
+        let count = app.world_mut().query::<&Ball>().iter(app.world()).count();
+        assert_eq!(count, 2);
+    }
+
+    // --- laser tests ---
+
+    fn spawn_test_laser(world: &mut World, pos: Vec2) -> Entity {
+        world
+            .spawn((
+                Transform::from_xyz(pos.x, pos.y, 0.0),
+                Laser,
+                Velocity(Vec2::new(0.0, LASER_SPEED)),
+                Collider {
+                    size: Vec2::new(LASER_WIDTH, LASER_HEIGHT),
+                },
+            ))
+            .id()
+    }
+
+    #[test]
+    fn laser_destroys_normal_block_with_score() {
+        let mut app = test_app();
+        spawn_test_block(app.world_mut(), Vec2::new(0.0, 100.0));
+        let laser = spawn_test_laser(app.world_mut(), Vec2::new(0.0, 100.0));
+
+        app.add_systems(Update, laser_block_collision);
+        app.update();
+
+        let block_count = app
             .world_mut()
-            .query::<&PowerUp>()
+            .query::<&Block>()
             .iter(app.world())
-            .next()
-            .unwrap();
-        assert_eq!(powerup.power_type, PowerUpType::WidePaddle);
+            .count();
+        assert_eq!(block_count, 0, "Laser should destroy the block");
+        assert!(app.world().get_entity(laser).is_err(), "Laser should be consumed");
+        assert_eq!(app.world().resource::<Score>().value, SCORE_PER_BLOCK);
     }
 
     #[test]
-    fn spawn_powerup_selects_multi_ball() {
+    fn laser_decrements_durable_block() {
         let mut app = test_app();
-        app.add_systems(Update, |mut commands: Commands| {
-            spawn_powerup(&mut commands, Vec2::ZERO, 0.3); // 0.25..0.50
-        });
+        spawn_test_block_typed(
+            app.world_mut(),
+            Vec2::new(0.0, 100.0),
+            BlockType::Durable { hits_remaining: 2 },
+        );
+        spawn_test_laser(app.world_mut(), Vec2::new(0.0, 100.0));
+
+        app.add_systems(Update, laser_block_collision);
         app.update();
 
-        let powerup = app
+        let block = app
             .world_mut()
-            .query::<&PowerUp>()
+            .query::<&Block>()
             .iter(app.world())
             .next()
             .unwrap();
-        assert_eq!(powerup.power_type, PowerUpType::MultiBall);
+        assert_eq!(block.block_type, BlockType::Durable { hits_remaining: 1 });
     }
 
     #[test]
-    fn spawn_powerup_selects_slow_ball() {
+    fn laser_stops_on_steel() {
         let mut app = test_app();
-        app.add_systems(Update, |mut commands: Commands| {
-            spawn_powerup(&mut commands, Vec2::ZERO, 0.6); // 0.50..0.75
-        });
+        spawn_test_block_typed(app.world_mut(), Vec2::new(0.0, 100.0), BlockType::Steel);
+        let laser = spawn_test_laser(app.world_mut(), Vec2::new(0.0, 100.0));
+
+        app.add_systems(
+            Update,
+            laser_block_collision.before(collect_collision_events),
+        );
+        app.add_systems(Update, collect_collision_events);
+        app.init_resource::<CollectedEvents>();
         app.update();
 
-        let powerup = app
+        let block_count = app
             .world_mut()
-            .query::<&PowerUp>()
+            .query::<&Block>()
             .iter(app.world())
-            .next()
-            .unwrap();
-        assert_eq!(powerup.power_type, PowerUpType::SlowBall);
+            .count();
+        assert_eq!(block_count, 1, "Steel block should survive a laser");
+        assert!(app.world().get_entity(laser).is_err(), "Laser should stop on steel");
+
+        let events = app.world().resource::<CollectedEvents>();
+        assert!(events.events.contains(&CollisionEvent::Wall));
+        assert!(!events.events.contains(&CollisionEvent::Block));
+    }
+
+    #[test]
+    fn laser_triggers_explosion_chain() {
+        let mut app = test_app();
+        spawn_test_block_typed(app.world_mut(), Vec2::new(0.0, 100.0), BlockType::Explosive);
+        spawn_test_block(app.world_mut(), Vec2::new(50.0, 100.0));
+        spawn_test_block(app.world_mut(), Vec2::new(300.0, 100.0));
+        spawn_test_laser(app.world_mut(), Vec2::new(0.0, 100.0));
+
+        app.add_systems(Update, laser_block_collision);
+        app.update();
+
+        let block_count = app
+            .world_mut()
+            .query::<&Block>()
+            .iter(app.world())
+            .count();
+        assert_eq!(block_count, 1, "Only the far block should survive the explosion");
+    }
+
+    // --- drop table type mapping ---
+
+    fn pick_level_one(roll: f32) -> PowerUpType {
+        PowerUpRegistry::default()
+            .drop_table(&Level { current: 1, ..default() }, BlockType::Normal)
+            .pick(roll)
+            .unwrap()
+    }
+
+    #[test]
+    fn drop_table_selects_wide_paddle() {
+        assert_eq!(pick_level_one(0.1), PowerUpType::WidePaddle); // < 0.125
+    }
+
+    #[test]
+    fn drop_table_selects_multi_ball() {
+        assert_eq!(pick_level_one(0.2), PowerUpType::MultiBall); // 0.125..0.25
+    }
+
+    #[test]
+    fn drop_table_selects_slow_ball() {
+        assert_eq!(pick_level_one(0.3), PowerUpType::SlowBall); // 0.25..0.357
+    }
+
+    #[test]
+    fn drop_table_selects_fire_ball() {
+        assert_eq!(pick_level_one(0.4), PowerUpType::FireBall); // 0.357..0.464
+    }
+
+    #[test]
+    fn drop_table_selects_laser() {
+        assert_eq!(pick_level_one(0.5), PowerUpType::Laser); // 0.464..0.571
+    }
+
+    #[test]
+    fn drop_table_selects_catch() {
+        assert_eq!(pick_level_one(0.6), PowerUpType::Catch); // 0.571..0.679
+    }
+
+    #[test]
+    fn drop_table_selects_barrier_magnet_and_extra_life() {
+        assert_eq!(pick_level_one(0.7), PowerUpType::Barrier); // 0.679..0.768
+        assert_eq!(pick_level_one(0.8), PowerUpType::Magnet); // 0.768..0.857
+        assert_eq!(pick_level_one(0.87), PowerUpType::ExtraLife); // 0.857..0.893
+    }
+
+    #[test]
+    fn drop_table_selects_ball_kinds() {
+        assert_eq!(pick_level_one(0.9), PowerUpType::HeavyBall); // 0.893..0.920
+        assert_eq!(pick_level_one(0.93), PowerUpType::BouncyBall); // 0.920..0.946
+        assert_eq!(pick_level_one(0.96), PowerUpType::SplitBall); // 0.946..0.973
+        assert_eq!(pick_level_one(0.99), PowerUpType::GhostBall); // >= 0.973
     }
 
     #[test]
-    fn spawn_powerup_selects_fire_ball() {
+    fn drop_table_hazard_share_follows_level() {
+        let registry = PowerUpRegistry::default();
+        let table = registry.drop_table(&Level { current: 7, ..default() }, BlockType::Normal);
+        let hazard_weight: f32 = table
+            .entries
+            .iter()
+            .filter(|(t, _)| registry.get(*t).hazard)
+            .map(|(_, w)| w)
+            .sum();
+        assert!((hazard_weight - 0.3).abs() < 1e-4);
+
+        // Hazards share their band equally
+        let hazards: Vec<PowerUpType> = [0.75, 0.82, 0.9, 0.97]
+            .iter()
+            .map(|r| table.pick(*r).unwrap())
+            .collect();
+        assert_eq!(
+            hazards,
+            vec![
+                PowerUpType::ShrinkPaddle,
+                PowerUpType::FastBall,
+                PowerUpType::ReverseControls,
+                PowerUpType::Fog,
+            ]
+        );
+    }
+
+    #[test]
+    fn drop_table_chance_depends_on_block_type() {
+        let registry = PowerUpRegistry::default();
+        let level = Level { current: 1, ..default() };
+        let normal = registry.drop_table(&level, BlockType::Normal).chance;
+        let durable = registry
+            .drop_table(&level, BlockType::Durable { hits_remaining: 2 })
+            .chance;
+        assert_eq!(normal, POWERUP_DROP_CHANCE);
+        assert!(durable > normal);
+        assert_eq!(registry.drop_table(&level, BlockType::Steel).chance, 0.0);
+    }
+
+    #[test]
+    fn spawn_powerup_uses_registry_style() {
         let mut app = test_app();
-        app.add_systems(Update, |mut commands: Commands| {
-            spawn_powerup(&mut commands, Vec2::ZERO, 0.8); // >= 0.75
+        app.add_systems(Update, |mut commands: Commands, registry: Res<PowerUpRegistry>| {
+            spawn_powerup(&mut commands, &registry, Vec2::ZERO, PowerUpType::Fog);
         });
         app.update();
 
-        let powerup = app
+        let (powerup, sprite, transform) = app
             .world_mut()
-            .query::<&PowerUp>()
+            .query::<(&PowerUp, &Sprite, &Transform)>()
             .iter(app.world())
             .next()
             .unwrap();
-        assert_eq!(powerup.power_type, PowerUpType::FireBall);
+        assert_eq!(powerup.power_type, PowerUpType::Fog);
+        assert_eq!(sprite.color, PowerUpRegistry::default().get(PowerUpType::Fog).color);
+        assert_ne!(transform.rotation, Quat::IDENTITY, "Hazards fall as diamonds");
     }
 
     #[test]
@@ -1294,9 +2302,18 @@ mod tests {
         // Regression test: consecutive calls must not always produce the same type.
         // Spawn many powerups and verify at least 2 distinct types appear.
         let mut app = test_app();
-        app.add_systems(Update, |mut commands: Commands| {
+        app.add_systems(Update, |mut commands: Commands, registry: Res<PowerUpRegistry>| {
+            let mut drop_rng = DropRng::default();
             for _ in 0..200 {
-                maybe_spawn_powerup(&mut commands, Vec2::ZERO);
+                maybe_spawn_powerup(
+                    &mut commands,
+                    &registry,
+                    &Level { current: 1, ..default() },
+                    &mut drop_rng,
+                    BlockType::Normal,
+                    Vec2::ZERO,
+                    PlayfieldId(0),
+                );
             }
         });
         app.update();
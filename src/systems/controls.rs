@@ -0,0 +1,510 @@
+use bevy::prelude::*;
+use bevy::text::FontSmoothing;
+
+use crate::components::*;
+use crate::constants::*;
+use crate::resources::*;
+use crate::states::GameState;
+use crate::systems::option_list::visible_start;
+
+/// Shown while the screen waits for a new key
+const WAITING_MESSAGE: &str = "新しいキーを押してください（BACKSPACE で取り消し）";
+
+/// Shown while the screen waits for a new gamepad button
+const WAITING_BUTTON_MESSAGE: &str = "新しいボタンを押してください（BACKSPACE で取り消し）";
+
+/// Slot index of the gamepad button column
+const PAD_SLOT: usize = 2;
+
+/// Key or button name shown in one slot of an action row
+fn slot_label(bindings: &KeyBindings, action: InputAction, slot: usize) -> &'static str {
+    if slot == PAD_SLOT {
+        return bindings.button(action).and_then(button_label).unwrap_or("-");
+    }
+    bindings.keys(action)[slot].and_then(key_label).unwrap_or("-")
+}
+
+fn waiting_message(slot: usize) -> String {
+    if slot == PAD_SLOT { WAITING_BUTTON_MESSAGE } else { WAITING_MESSAGE }.to_string()
+}
+
+fn conflict_message(name: Option<&str>, other: InputAction) -> String {
+    format!("{} は「{}」で使用中です", name.unwrap_or("?"), other.label())
+}
+
+/// Setup the key binding screen: one row per action with two key slots and a gamepad slot
+pub fn setup_controls(mut commands: Commands, asset_server: Res<AssetServer>, bindings: Res<KeyBindings>) {
+    let warm_white = Color::srgb(1.0, 0.96, 0.88);
+    let cream = Color::srgb(0.95, 0.85, 0.65);
+    let lavender = Color::srgb(0.55, 0.50, 0.65);
+    let gold = Color::srgb(1.0, 0.85, 0.20);
+    let font_handle: Handle<Font> = asset_server.load(GAME_FONT_PATH);
+    let font = |size: f32| TextFont {
+        font: font_handle.clone(),
+        font_size: size,
+        font_smoothing: FontSmoothing::None,
+    };
+
+    commands.init_resource::<ControlsSelection>();
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.12, 0.88)),
+            ControlsUI,
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn((
+                Text::new("キー設定"),
+                font(40.0),
+                TextColor(warm_white),
+                TextLayout::new_with_justify(JustifyText::Center),
+                Node {
+                    margin: UiRect::bottom(Val::Px(8.0)),
+                    ..default()
+                },
+            ));
+
+            // Action rows: [cursor] [name] [primary] [secondary] [gamepad], scrolling with ▲ / ▼ hints
+            let len = InputAction::ALL.len();
+            parent.spawn((Button, Text::new("▲"), font(16.0), TextColor(Color::NONE), ControlsScroll(-1)));
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        height: Val::Px(CONTROLS_VISIBLE_ROWS.min(len) as f32 * CONTROLS_ROW_HEIGHT),
+                        overflow: Overflow::scroll_y(),
+                        ..default()
+                    },
+                    ScrollPosition::default(),
+                    ControlsList,
+                ))
+                .with_children(|list| {
+                    for (row, action) in InputAction::ALL.into_iter().enumerate() {
+                        list.spawn(Node {
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(6.0),
+                            height: Val::Px(CONTROLS_ROW_HEIGHT),
+                            flex_shrink: 0.0,
+                            ..default()
+                        })
+                        .with_children(|line| {
+                            line.spawn((
+                                Text::new(">"),
+                                font(16.0),
+                                TextColor(if row == 0 { gold } else { Color::NONE }),
+                                ControlsCursor(row),
+                            ));
+                            line.spawn((
+                                Text::new(action.label()),
+                                font(16.0),
+                                TextColor(cream),
+                                Node {
+                                    min_width: Val::Px(160.0),
+                                    ..default()
+                                },
+                            ));
+                            for slot in 0..=PAD_SLOT {
+                                let selected = row == 0 && slot == 0;
+                                line.spawn((
+                                    Button,
+                                    Node {
+                                        min_width: Val::Px(if slot == PAD_SLOT { 80.0 } else { 110.0 }),
+                                        padding: UiRect::axes(Val::Px(8.0), Val::Px(3.0)),
+                                        justify_content: JustifyContent::Center,
+                                        ..default()
+                                    },
+                                    BackgroundColor(slot_background(selected)),
+                                    ControlsSlot { row, slot },
+                                ))
+                                .with_children(|btn| {
+                                    btn.spawn((
+                                        Text::new(slot_label(&bindings, action, slot)),
+                                        font(16.0),
+                                        TextColor(cream),
+                                    ));
+                                });
+                            }
+                        });
+                    }
+                });
+            parent.spawn((
+                Button,
+                Text::new("▼"),
+                font(16.0),
+                TextColor(if len > CONTROLS_VISIBLE_ROWS { lavender } else { Color::NONE }),
+                ControlsScroll(1),
+            ));
+
+            // Status / conflict message
+            parent.spawn((
+                Text::new(""),
+                font(16.0),
+                TextColor(Color::srgb(0.92, 0.44, 0.44)),
+                TextLayout::new_with_justify(JustifyText::Center),
+                ControlsMessage,
+            ));
+
+            // Reset and back buttons
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(16.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    for (label, is_reset) in [("[ 初期設定に戻す ]", true), ("[ 戻る ]", false)] {
+                        let mut btn = row.spawn((
+                            Button,
+                            Node {
+                                padding: UiRect::axes(Val::Px(16.0), Val::Px(6.0)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::NONE),
+                        ));
+                        if is_reset {
+                            btn.insert(ControlsResetButton);
+                        } else {
+                            btn.insert(ControlsBackButton);
+                        }
+                        btn.with_children(|btn| {
+                            btn.spawn((Text::new(label), font(16.0), TextColor(lavender)));
+                        });
+                    }
+                });
+
+            // Instructions
+            parent.spawn((
+                Text::new(
+                    "↑ ↓ ← → / 十字キー で選択  ENTER / Ⓐ で変更\n\
+                     BACKSPACE で 1 項目を初期設定に  ESC / Ⓑ で戻る",
+                ),
+                font(14.0),
+                TextColor(lavender),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+        });
+}
+
+fn slot_background(selected: bool) -> Color {
+    if selected {
+        Color::srgba(1.0, 0.85, 0.20, 0.25)
+    } else {
+        Color::srgba(1.0, 1.0, 1.0, 0.06)
+    }
+}
+
+/// Cleanup the key binding screen
+pub fn cleanup_controls(mut commands: Commands, query: Query<Entity, With<ControlsUI>>) {
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands.remove_resource::<ControlsSelection>();
+}
+
+/// Handle input on the key binding screen.
+/// Arrow keys, ENTER, ESC and BACKSPACE always work here alongside the bound keys and
+/// buttons, so a bad mapping can never lock the player out of this screen.
+#[allow(clippy::too_many_arguments)]
+pub fn controls_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut bindings: ResMut<KeyBindings>,
+    mut selection: ResMut<ControlsSelection>,
+    mut next_state: ResMut<NextState<GameState>>,
+    slot_btns: Query<(&Interaction, &ControlsSlot), Changed<Interaction>>,
+    scroll_btns: Query<(&Interaction, &ControlsScroll), Changed<Interaction>>,
+    reset_btn: Query<&Interaction, (With<ControlsResetButton>, Changed<Interaction>)>,
+    back_btn: Query<&Interaction, (With<ControlsBackButton>, Changed<Interaction>)>,
+    mut message: Query<&mut Text, With<ControlsMessage>>,
+) {
+    let bound = |action| {
+        bindings.just_pressed(&keyboard, action)
+            || gamepads.iter().any(|pad| bindings.pad_just_pressed(pad, action))
+    };
+    let scroll = |step| scroll_btns.iter().any(|(i, s)| *i == Interaction::Pressed && s.0 == step);
+    let up = keyboard.just_pressed(KeyCode::ArrowUp) || bound(InputAction::MenuUp) || scroll(-1);
+    let down = keyboard.just_pressed(KeyCode::ArrowDown) || bound(InputAction::MenuDown) || scroll(1);
+    let left = keyboard.just_pressed(KeyCode::ArrowLeft) || bound(InputAction::MoveLeft);
+    let right = keyboard.just_pressed(KeyCode::ArrowRight) || bound(InputAction::MoveRight);
+    let confirm = keyboard.just_pressed(KeyCode::Enter) || bound(InputAction::Confirm);
+    let back = keyboard.just_pressed(KeyCode::Escape) || bound(InputAction::Back);
+
+    let mut note: Option<String> = None;
+
+    if let Some((_, slot)) = slot_btns.iter().find(|(i, _)| **i == Interaction::Pressed) {
+        // Tapping a slot selects it and waits for a key
+        selection.row = slot.row;
+        selection.slot = slot.slot;
+        selection.waiting = true;
+        note = Some(waiting_message(slot.slot));
+    } else if back_btn.iter().any(|i| *i == Interaction::Pressed) {
+        next_state.set(GameState::Settings);
+        return;
+    } else if reset_btn.iter().any(|i| *i == Interaction::Pressed) {
+        *bindings = KeyBindings::default();
+        selection.waiting = false;
+        note = Some("すべて初期設定に戻しました".to_string());
+    } else if selection.waiting {
+        let action = InputAction::ALL[selection.row];
+        if keyboard.just_pressed(KeyCode::Backspace) {
+            selection.waiting = false;
+            note = Some(String::new());
+        } else if selection.slot == PAD_SLOT {
+            let button = gamepads
+                .iter()
+                .find_map(|pad| pad.get_just_pressed().find(|button| button_label(**button).is_some()).copied());
+            if let Some(button) = button {
+                match bindings.bind_button(action, button) {
+                    Ok(()) => {
+                        selection.waiting = false;
+                        note = Some(String::new());
+                    }
+                    Err(other) => note = Some(conflict_message(button_label(button), other)),
+                }
+            }
+        } else if let Some(&key) = keyboard.get_just_pressed().find(|key| key_label(**key).is_some()) {
+            match bindings.bind(action, selection.slot, key) {
+                Ok(()) => {
+                    selection.waiting = false;
+                    note = Some(String::new());
+                }
+                // Stay waiting so another key can be tried
+                Err(other) => note = Some(conflict_message(key_label(key), other)),
+            }
+        }
+    } else {
+        let last_row = InputAction::ALL.len() - 1;
+        if up {
+            selection.row = selection.row.saturating_sub(1);
+        }
+        if down {
+            selection.row = (selection.row + 1).min(last_row);
+        }
+        if left {
+            selection.slot = selection.slot.saturating_sub(1);
+        }
+        if right {
+            selection.slot = (selection.slot + 1).min(PAD_SLOT);
+        }
+        let action = InputAction::ALL[selection.row];
+        if keyboard.just_pressed(KeyCode::Backspace) {
+            note = Some(match bindings.reset(action) {
+                Ok(()) => format!("「{}」を初期設定に戻しました", action.label()),
+                Err(other) => {
+                    format!("初期設定のキーは「{}」で使用中です", other.label())
+                }
+            });
+        } else if confirm {
+            selection.waiting = true;
+            note = Some(waiting_message(selection.slot));
+        } else if back {
+            next_state.set(GameState::Settings);
+            return;
+        }
+    }
+
+    if let Some(note) = note {
+        for mut text in &mut message {
+            **text = note.clone();
+        }
+    }
+}
+
+/// Refresh cursors and key slots after the selection or a binding changed, scrolling the list
+/// to keep the selected row in view
+pub fn update_controls_screen(
+    bindings: Res<KeyBindings>,
+    selection: Res<ControlsSelection>,
+    mut lists: Query<&mut ScrollPosition, With<ControlsList>>,
+    mut cursors: Query<(&ControlsCursor, &mut TextColor), Without<ControlsScroll>>,
+    mut hints: Query<(&ControlsScroll, &mut TextColor), Without<ControlsCursor>>,
+    mut slots: Query<(&ControlsSlot, &Children, &mut BackgroundColor)>,
+    mut texts: Query<&mut Text>,
+) {
+    if !bindings.is_changed() && !selection.is_changed() {
+        return;
+    }
+    let gold = Color::srgb(1.0, 0.85, 0.20);
+    let lavender = Color::srgb(0.55, 0.50, 0.65);
+    let len = InputAction::ALL.len();
+    for mut scroll in &mut lists {
+        let current = (scroll.offset_y / CONTROLS_ROW_HEIGHT).round() as usize;
+        let first = visible_start(current, selection.row, len, CONTROLS_VISIBLE_ROWS);
+        scroll.offset_y = first as f32 * CONTROLS_ROW_HEIGHT;
+        for (hint, mut color) in &mut hints {
+            let more = if hint.0 < 0 { first > 0 } else { first + CONTROLS_VISIBLE_ROWS < len };
+            color.0 = if more { lavender } else { Color::NONE };
+        }
+    }
+    for (cursor, mut color) in &mut cursors {
+        *color = TextColor(if cursor.0 == selection.row { gold } else { Color::NONE });
+    }
+    for (slot, children, mut background) in &mut slots {
+        let selected = slot.row == selection.row && slot.slot == selection.slot;
+        *background = BackgroundColor(slot_background(selected));
+        let label = if selected && selection.waiting {
+            "…"
+        } else {
+            slot_label(&bindings, InputAction::ALL[slot.row], slot.slot)
+        };
+        for &child in children {
+            if let Ok(mut text) = texts.get_mut(child) {
+                **text = label.to_string();
+            }
+        }
+    }
+}
+
+/// Persist the bindings whenever they change
+pub fn persist_key_bindings(bindings: Res<KeyBindings>) {
+    bindings.save();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    fn controls_app() -> App {
+        let mut app = test_app();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<ControlsSelection>();
+        app.world_mut().spawn((Text::new(""), ControlsMessage));
+        app.add_systems(Update, controls_input);
+        app
+    }
+
+    fn press(app: &mut App, key: KeyCode) {
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release_all();
+        keyboard.clear();
+        keyboard.press(key);
+        app.update();
+    }
+
+    fn message(app: &mut App) -> String {
+        let mut query = app.world_mut().query_filtered::<&Text, With<ControlsMessage>>();
+        query.single(app.world()).0.clone()
+    }
+
+    #[test]
+    fn confirm_then_key_rebinds_selected_slot() {
+        let mut app = controls_app();
+        app.world_mut().resource_mut::<ControlsSelection>().row = InputAction::Launch as usize;
+
+        press(&mut app, KeyCode::Enter);
+        assert!(app.world().resource::<ControlsSelection>().waiting);
+
+        press(&mut app, KeyCode::KeyK);
+        assert!(!app.world().resource::<ControlsSelection>().waiting);
+        assert_eq!(
+            app.world().resource::<KeyBindings>().keys(InputAction::Launch)[0],
+            Some(KeyCode::KeyK)
+        );
+    }
+
+    #[test]
+    fn conflicting_key_is_refused_and_keeps_waiting() {
+        let mut app = controls_app();
+        app.world_mut().resource_mut::<ControlsSelection>().waiting = true;
+
+        // Row 0 is MoveLeft; ESC already pauses during play
+        press(&mut app, KeyCode::Escape);
+
+        assert!(app.world().resource::<ControlsSelection>().waiting);
+        assert_eq!(message(&mut app), "ESC は「ポーズ」で使用中です");
+        assert_eq!(app.world().resource::<KeyBindings>().keys(InputAction::MoveLeft), InputAction::MoveLeft.default_keys());
+        assert!(!matches!(
+            app.world().resource::<NextState<GameState>>(),
+            NextState::Pending(GameState::Settings)
+        ));
+    }
+
+    #[test]
+    fn backspace_cancels_then_restores_factory_keys() {
+        let mut app = controls_app();
+        app.world_mut()
+            .resource_mut::<KeyBindings>()
+            .bind(InputAction::MoveLeft, 0, KeyCode::KeyJ)
+            .unwrap();
+        app.world_mut().resource_mut::<ControlsSelection>().waiting = true;
+
+        press(&mut app, KeyCode::Backspace);
+        assert!(!app.world().resource::<ControlsSelection>().waiting);
+        assert_eq!(app.world().resource::<KeyBindings>().keys(InputAction::MoveLeft)[0], Some(KeyCode::KeyJ));
+
+        press(&mut app, KeyCode::Backspace);
+        assert_eq!(app.world().resource::<KeyBindings>().keys(InputAction::MoveLeft), InputAction::MoveLeft.default_keys());
+    }
+
+    #[test]
+    fn gamepad_slot_binds_the_pressed_button() {
+        let mut app = controls_app();
+        let pad = app.world_mut().spawn(Gamepad::default()).id();
+        {
+            let mut selection = app.world_mut().resource_mut::<ControlsSelection>();
+            selection.row = InputAction::Launch as usize;
+            selection.slot = PAD_SLOT;
+            selection.waiting = true;
+        }
+
+        // Keyboard keys are ignored while a button is awaited
+        press(&mut app, KeyCode::KeyK);
+        assert!(app.world().resource::<ControlsSelection>().waiting);
+
+        app.world_mut()
+            .get_mut::<Gamepad>(pad)
+            .unwrap()
+            .digital_mut()
+            .press(GamepadButton::West);
+        app.update();
+
+        assert!(!app.world().resource::<ControlsSelection>().waiting);
+        let bindings = app.world().resource::<KeyBindings>();
+        assert_eq!(bindings.button(InputAction::Launch), Some(GamepadButton::West));
+        assert_eq!(bindings.keys(InputAction::Launch), InputAction::Launch.default_keys());
+    }
+
+    #[test]
+    fn list_scrolls_to_keep_the_selected_row_in_view() {
+        let mut app = controls_app();
+        app.add_systems(Update, update_controls_screen);
+        let list = app.world_mut().spawn((ScrollPosition::default(), ControlsList)).id();
+        let up = app.world_mut().spawn((TextColor(Color::NONE), ControlsScroll(-1))).id();
+        let last = InputAction::ALL.len() - 1;
+        app.world_mut().resource_mut::<ControlsSelection>().row = last;
+        app.update();
+
+        let first = last + 1 - CONTROLS_VISIBLE_ROWS;
+        let offset = app.world().get::<ScrollPosition>(list).unwrap().offset_y;
+        assert_eq!(offset, first as f32 * CONTROLS_ROW_HEIGHT);
+        assert_ne!(app.world().get::<TextColor>(up).unwrap().0, Color::NONE, "▲ shows rows above");
+
+        // The ▲ hint moves the selection up without scrolling while the row stays visible
+        app.world_mut().entity_mut(up).insert((Button, Interaction::Pressed));
+        app.update();
+        assert_eq!(app.world().resource::<ControlsSelection>().row, last - 1);
+        assert_eq!(app.world().get::<ScrollPosition>(list).unwrap().offset_y, offset);
+    }
+
+    #[test]
+    fn escape_returns_to_settings() {
+        let mut app = controls_app();
+        press(&mut app, KeyCode::Escape);
+        assert!(matches!(
+            app.world().resource::<NextState<GameState>>(),
+            NextState::Pending(GameState::Settings)
+        ));
+    }
+}
@@ -0,0 +1,369 @@
+use bevy::prelude::*;
+use bevy::text::FontSmoothing;
+
+use crate::components::*;
+use crate::constants::*;
+use crate::resources::*;
+use crate::systems::setup::{grid_x, grid_y, level_rand, seeded_block_type, spawn_block};
+
+/// Current UTC day number (days since 1970-01-01)
+pub fn today_utc() -> u32 {
+    use bevy::utils::SystemTime;
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|elapsed| (elapsed.as_secs() / 86_400) as u32)
+        .unwrap_or(0)
+}
+
+/// Layout seed for a day (spread so neighbouring days look unrelated)
+pub fn daily_seed(day: u32) -> u32 {
+    day.wrapping_mul(2_654_435_761)
+}
+
+/// Drop RNG for a day's challenge, independent of the layout rolls
+pub fn daily_drop_rng(day: u32) -> DropRng {
+    DropRng::seeded(daily_seed(day) ^ 0x5BD1_E995)
+}
+
+/// Day number as a YYYY-MM-DD date
+pub fn date_string(day: u32) -> String {
+    // Civil-from-days conversion (proleptic Gregorian calendar)
+    let z = day as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + if m <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}", y, m, d)
+}
+
+/// Block for one daily cell: mirrored left/right, with some gaps (None)
+pub fn daily_block_type(day: u32, row: u32, col: u32) -> Option<BlockType> {
+    let col = col.min(BLOCK_COLS as u32 - 1 - col);
+    let seed = daily_seed(day);
+    if level_rand(seed, row + 200, col) < DAILY_GAP_CHANCE {
+        return None;
+    }
+    Some(seeded_block_type(DAILY_LEVEL, seed, row, col))
+}
+
+/// Spawn the daily challenge layout for a day
+pub fn spawn_daily_level(commands: &mut Commands, day: u32) {
+    for row in 0..DAILY_ROWS {
+        for col in 0..BLOCK_COLS {
+            if let Some(block_type) = daily_block_type(day, row as u32, col as u32) {
+                spawn_block(commands, grid_x(col), grid_y(row), block_type, row);
+            }
+        }
+    }
+}
+
+/// Breakable blocks in a day's layout (for the result's progress grid)
+pub fn daily_breakable_count(day: u32) -> u32 {
+    let mut count = 0;
+    for row in 0..DAILY_ROWS as u32 {
+        for col in 0..BLOCK_COLS as u32 {
+            if daily_block_type(day, row, col).is_some_and(|b| b != BlockType::Steel) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+/// Shareable result: title line, a 10-cell progress grid and score/time/streak
+pub fn daily_share_text(
+    day: u32,
+    blocks_destroyed: u32,
+    cleared_time: Option<f32>,
+    elapsed: f32,
+    score: u32,
+    streak: u32,
+) -> String {
+    let total = daily_breakable_count(day).max(1);
+    let filled = if cleared_time.is_some() {
+        10
+    } else {
+        (blocks_destroyed.min(total) * 10 / total) as usize
+    };
+    let grid = "🟩".repeat(filled) + &"⬛".repeat(10 - filled);
+    let mark = if cleared_time.is_some() { "✅" } else { "❌" };
+    let secs = cleared_time.unwrap_or(elapsed) as u32;
+    format!(
+        "ブロック崩し デイリー {}\n{}\n{} ⏱{}:{:02} ⭐{} 🔥{}",
+        date_string(day),
+        grid,
+        mark,
+        secs / 60,
+        secs % 60,
+        score,
+        streak
+    )
+}
+
+/// Remember the clear time when the daily level is cleared
+pub fn mark_daily_cleared(mut daily: ResMut<DailyChallenge>, level_stats: Res<LevelStats>) {
+    daily.cleared_time = Some(level_stats.time_elapsed);
+}
+
+/// Setup the daily result screen (replaces the ranking; the daily has no retry)
+pub fn setup_daily_result(
+    mut commands: Commands,
+    score: Res<Score>,
+    level_stats: Res<LevelStats>,
+    daily: Res<DailyChallenge>,
+    mut record: ResMut<DailyRecord>,
+    asset_server: Res<AssetServer>,
+    bindings: Res<KeyBindings>,
+) {
+    let soft_green = Color::srgb(0.40, 0.80, 0.52);
+    let salmon = Color::srgb(0.92, 0.44, 0.44);
+    let cream = Color::srgb(0.95, 0.85, 0.65);
+    let lavender = Color::srgb(0.55, 0.50, 0.65);
+    let font_handle: Handle<Font> = asset_server.load(GAME_FONT_PATH);
+
+    record.last_score = score.value;
+    record.save();
+
+    let secs = daily.cleared_time.unwrap_or(level_stats.time_elapsed) as u32;
+    let (title, title_color) = if daily.cleared_time.is_some() {
+        ("デイリー クリア！", soft_green)
+    } else {
+        ("デイリー 失敗", salmon)
+    };
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.12, 0.88)),
+            GameOverUI,
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn((
+                Text::new(title),
+                TextFont {
+                    font: font_handle.clone(),
+                    font_size: 48.0,
+                    font_smoothing: FontSmoothing::None,
+                },
+                TextColor(title_color),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+
+            // Result
+            parent.spawn((
+                Text::new(format!(
+                    "{}\nスコア {}\nタイム {}:{:02}\n連続 {} 日（最高 {} 日）",
+                    date_string(daily.day),
+                    score.value,
+                    secs / 60,
+                    secs % 60,
+                    record.streak,
+                    record.best_streak
+                )),
+                TextFont {
+                    font: font_handle.clone(),
+                    font_size: 24.0,
+                    font_smoothing: FontSmoothing::None,
+                },
+                TextColor(cream),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+
+            // Share button
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(16.0), Val::Px(8.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.06)),
+                    ShareButton,
+                ))
+                .with_children(|btn| {
+                    btn.spawn((
+                        Text::new("[ 結果をコピー ]"),
+                        TextFont {
+                            font: font_handle.clone(),
+                            font_size: 16.0,
+                            font_smoothing: FontSmoothing::None,
+                        },
+                        TextColor(cream),
+                    ));
+                });
+
+            // Share feedback text (initially invisible)
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font: font_handle.clone(),
+                    font_size: 16.0,
+                    font_smoothing: FontSmoothing::None,
+                },
+                TextColor(Color::srgb(0.40, 1.0, 0.50)),
+                ShareFeedback {
+                    timer: Timer::from_seconds(2.0, TimerMode::Once),
+                },
+            ));
+
+            parent.spawn((
+                Text::new(format!(
+                    "{}  結果をコピー\n{} / タップ でメニューへ（挑戦は 1 日 1 回）",
+                    bindings.primary_label(InputAction::Share),
+                    bindings.primary_label(InputAction::Confirm)
+                )),
+                TextFont {
+                    font: font_handle,
+                    font_size: 16.0,
+                    font_smoothing: FontSmoothing::None,
+                },
+                TextColor(lavender),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+        });
+}
+
+/// Copy the daily result on the share key or the share button (native builds save it to a file
+/// and show where)
+#[allow(clippy::too_many_arguments)]
+pub fn daily_share_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    share_btn: Query<&Interaction, (With<ShareButton>, Changed<Interaction>)>,
+    mut feedback: Query<(&mut Text, &mut ShareFeedback)>,
+    score: Res<Score>,
+    level_stats: Res<LevelStats>,
+    daily: Res<DailyChallenge>,
+    record: Res<DailyRecord>,
+) {
+    let pressed = share_btn.iter().any(|i| *i == Interaction::Pressed);
+    if !pressed && !bindings.just_pressed(&keyboard, InputAction::Share) {
+        return;
+    }
+
+    let text = daily_share_text(
+        daily.day,
+        level_stats.blocks_destroyed,
+        daily.cleared_time,
+        level_stats.time_elapsed,
+        score.value,
+        record.streak,
+    );
+
+    #[cfg(target_arch = "wasm32")]
+    let message = {
+        crate::systems::editor::copy_text_to_clipboard(&text);
+        "結果をコピーしました！".to_string()
+    };
+
+    #[cfg(not(target_arch = "wasm32"))]
+    let message = {
+        match crate::storage::save_daily_share(&text) {
+            Some(path) => format!("結果を {} に保存しました", path.display()),
+            None => "結果を保存できませんでした".to_string(),
+        }
+    };
+
+    if let Ok((mut text, mut fb)) = feedback.get_single_mut() {
+        **text = message;
+        fb.timer.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    #[test]
+    fn date_string_converts_day_numbers() {
+        assert_eq!(date_string(0), "1970-01-01");
+        assert_eq!(date_string(19_782), "2024-02-29");
+        assert_eq!(date_string(20_744), "2026-10-18");
+    }
+
+    #[test]
+    fn daily_layout_is_deterministic_and_mirrored() {
+        let day = 20_744;
+        for row in 0..DAILY_ROWS as u32 {
+            for col in 0..BLOCK_COLS as u32 {
+                let cell = daily_block_type(day, row, col);
+                assert_eq!(cell, daily_block_type(day, row, col));
+                assert_eq!(cell, daily_block_type(day, row, BLOCK_COLS as u32 - 1 - col));
+            }
+        }
+        let differs = (0..DAILY_ROWS as u32)
+            .flat_map(|row| (0..BLOCK_COLS as u32).map(move |col| (row, col)))
+            .any(|(row, col)| daily_block_type(day, row, col) != daily_block_type(day + 1, row, col));
+        assert!(differs, "Consecutive days get different layouts");
+    }
+
+    #[test]
+    fn daily_drops_repeat_for_the_same_day() {
+        let mut a = daily_drop_rng(20_744);
+        let mut b = daily_drop_rng(20_744);
+        for _ in 0..20 {
+            let roll = a.roll();
+            assert!((0.0..1.0).contains(&roll));
+            assert_eq!(roll, b.roll());
+        }
+    }
+
+    #[test]
+    fn streak_continues_only_on_consecutive_days() {
+        let mut record = DailyRecord::default();
+        assert!(!record.played(100));
+
+        record.start_attempt(100);
+        assert!(record.played(100));
+        assert_eq!(record.streak, 1);
+
+        record.start_attempt(101);
+        assert_eq!(record.streak, 2);
+
+        record.start_attempt(105);
+        assert_eq!(record.streak, 1);
+        assert_eq!(record.best_streak, 2);
+    }
+
+    #[test]
+    fn share_text_shows_progress_grid() {
+        let total = daily_breakable_count(20_744);
+        let half = daily_share_text(20_744, total / 2, None, 75.0, 1234, 3);
+        assert!(half.starts_with("ブロック崩し デイリー 2026-10-18\n"));
+        assert_eq!(half.matches('🟩').count(), 5);
+        assert!(half.ends_with("❌ ⏱1:15 ⭐1234 🔥3"));
+
+        let cleared = daily_share_text(20_744, total, Some(62.0), 90.0, 5000, 1);
+        assert_eq!(cleared.matches('🟩').count(), 10);
+        assert!(cleared.ends_with("✅ ⏱1:02 ⭐5000 🔥1"));
+    }
+
+    #[test]
+    fn level_clear_records_clear_time() {
+        let mut app = test_app();
+        app.insert_resource(DailyChallenge {
+            day: 20_744,
+            cleared_time: None,
+        });
+        app.world_mut().resource_mut::<LevelStats>().time_elapsed = 48.0;
+
+        app.add_systems(Update, mark_daily_cleared);
+        app.update();
+
+        assert_eq!(app.world().resource::<DailyChallenge>().cleared_time, Some(48.0));
+    }
+}
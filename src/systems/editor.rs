@@ -5,23 +5,76 @@ use crate::components::*;
 use crate::constants::*;
 use crate::resources::*;
 use crate::states::GameState;
-use crate::systems::setup::{block_type_color, grid_x, grid_y, spawn_block};
+use crate::systems::effects::glyph_color;
+use crate::systems::setup::spawn_grid_block;
 
 /// Color for an empty editor grid cell
 const EMPTY_CELL_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.08);
 /// Color for the selected tool highlight border
 const TOOL_SELECTED_COLOR: Color = Color::srgb(1.0, 0.85, 0.20);
+/// Block sizes offered in the size palette
+const EDITOR_SPANS: [BlockSpan; 6] = [
+    BlockSpan::new(1, 1),
+    BlockSpan::new(2, 1),
+    BlockSpan::new(3, 1),
+    BlockSpan::new(1, 2),
+    BlockSpan::new(2, 2),
+    BlockSpan::new(3, 3),
+];
+
+/// Serialized stage: the anchor grid plus footprints of multi-cell blocks.
+/// Stages without large blocks are shared as the bare grid for compatibility.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct StageData {
+    grid: [[Option<BlockType>; 10]; 7],
+    #[serde(default)]
+    spans: Vec<StageSpan>,
+}
+
+/// Footprint of the multi-cell block anchored at (row, col)
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+struct StageSpan {
+    row: usize,
+    col: usize,
+    cols: usize,
+    rows: usize,
+}
+
+/// Get the display color for a block type in the editor with the player's palette (use row=0 for Normal)
+fn editor_block_color(settings: &GameSettings, bt: &BlockType) -> Color {
+    settings.block_color(bt, 0)
+}
+
+/// Get the display color for a grid cell (covered cells of large blocks included)
+fn editor_cell_color(settings: &GameSettings, editor: &EditorState, row: usize, col: usize) -> Color {
+    match editor.block_at(row, col) {
+        Some(ref bt) => editor_block_color(settings, bt),
+        None => EMPTY_CELL_COLOR,
+    }
+}
+
+/// Glyph text and its color for a block type, when glyphs are on
+fn editor_block_glyph(settings: &GameSettings, bt: &BlockType) -> Option<(String, Color)> {
+    settings
+        .block_glyph(bt)
+        .map(|glyph| (glyph, glyph_color(editor_block_color(settings, bt))))
+}
 
-/// Get the display color for a block type in the editor (use row=0 for Normal)
-fn editor_block_color(bt: &BlockType) -> Color {
-    block_type_color(bt, 0)
+/// Glyph text and its color for a grid cell (empty when the cell has none)
+fn editor_cell_glyph(settings: &GameSettings, editor: &EditorState, row: usize, col: usize) -> (String, Color) {
+    editor
+        .block_at(row, col)
+        .and_then(|bt| editor_block_glyph(settings, &bt))
+        .unwrap_or((String::new(), Color::NONE))
 }
 
 /// Setup the editor UI
+#[allow(clippy::type_complexity)]
 pub fn setup_editor(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     editor: Res<EditorState>,
+    settings: Res<GameSettings>,
 ) {
     let warm_white = Color::srgb(1.0, 0.96, 0.88);
     let cream = Color::srgb(0.95, 0.85, 0.65);
@@ -84,15 +137,18 @@ pub fn setup_editor(
                             TextColor(lavender),
                         ));
 
-                        let tools: Vec<(Option<BlockType>, &str, &str, Color)> = vec![
-                            (Some(BlockType::Normal), "N", "通常", editor_block_color(&BlockType::Normal)),
-                            (Some(BlockType::Durable { hits_remaining: 2 }), "D", "耐久", editor_block_color(&BlockType::Durable { hits_remaining: 2 })),
-                            (Some(BlockType::Steel), "S", "鉄", editor_block_color(&BlockType::Steel)),
-                            (Some(BlockType::Explosive), "E", "爆発", editor_block_color(&BlockType::Explosive)),
-                            (None, "×", "消去", Color::srgb(0.3, 0.3, 0.3)),
+                        let block_tool = |bt: BlockType, icon, desc| {
+                            (Some(bt), icon, desc, editor_block_color(&settings, &bt), editor_block_glyph(&settings, &bt))
+                        };
+                        let tools: Vec<(Option<BlockType>, &str, &str, Color, Option<(String, Color)>)> = vec![
+                            block_tool(BlockType::Normal, "N", "通常"),
+                            block_tool(BlockType::Durable { hits_remaining: 2 }, "D", "耐久"),
+                            block_tool(BlockType::Steel, "S", "鉄"),
+                            block_tool(BlockType::Explosive, "E", "爆発"),
+                            (None, "×", "消去", Color::srgb(0.3, 0.3, 0.3), None),
                         ];
 
-                        for (tool_type, icon, desc, color) in tools {
+                        for (tool_type, icon, desc, color, glyph) in tools {
                             let is_selected = editor.selected_tool == tool_type;
                             palette
                                 .spawn((
@@ -110,15 +166,30 @@ pub fn setup_editor(
                                     ToolButton(tool_type),
                                 ))
                                 .with_children(|btn| {
-                                    // Color swatch
+                                    // Color swatch (with the block's glyph when glyphs are on)
                                     btn.spawn((
                                         Node {
                                             width: Val::Px(28.0),
                                             height: Val::Px(20.0),
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
                                             ..default()
                                         },
                                         BackgroundColor(color),
-                                    ));
+                                    ))
+                                    .with_children(|swatch| {
+                                        if let Some((glyph, glyph_color)) = glyph {
+                                            swatch.spawn((
+                                                Text::new(glyph),
+                                                TextFont {
+                                                    font: font.clone(),
+                                                    font_size: 14.0,
+                                                    font_smoothing: FontSmoothing::None,
+                                                },
+                                                TextColor(glyph_color),
+                                            ));
+                                        }
+                                    });
                                     // Description label
                                     btn.spawn((
                                         Text::new(format!("{} {}", icon, desc)),
@@ -131,6 +202,59 @@ pub fn setup_editor(
                                     ));
                                 });
                         }
+
+                        // Size palette label
+                        palette.spawn((
+                            Text::new("SIZE"),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: 16.0,
+                                font_smoothing: FontSmoothing::None,
+                            },
+                            TextColor(lavender),
+                            Node {
+                                margin: UiRect::top(Val::Px(6.0)),
+                                ..default()
+                            },
+                        ));
+
+                        // Size buttons, two per row
+                        for pair in EDITOR_SPANS.chunks(2) {
+                            palette
+                                .spawn(Node {
+                                    flex_direction: FlexDirection::Row,
+                                    column_gap: Val::Px(6.0),
+                                    ..default()
+                                })
+                                .with_children(|size_row| {
+                                    for &span in pair {
+                                        let is_selected = editor.selected_span == span;
+                                        size_row
+                                            .spawn((
+                                                Button,
+                                                Node {
+                                                    padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+                                                    border: UiRect::all(Val::Px(2.0)),
+                                                    ..default()
+                                                },
+                                                BackgroundColor(Color::NONE),
+                                                BorderColor(if is_selected { TOOL_SELECTED_COLOR } else { Color::NONE }),
+                                                SizeButton(span),
+                                            ))
+                                            .with_children(|btn| {
+                                                btn.spawn((
+                                                    Text::new(format!("{}x{}", span.cols, span.rows)),
+                                                    TextFont {
+                                                        font: font.clone(),
+                                                        font_size: 16.0,
+                                                        font_smoothing: FontSmoothing::None,
+                                                    },
+                                                    TextColor(cream),
+                                                ));
+                                            });
+                                    }
+                                });
+                        }
                     });
 
                 // Grid container
@@ -150,21 +274,34 @@ pub fn setup_editor(
                                 })
                                 .with_children(|row_node| {
                                     for col in 0..EDITOR_COLS {
-                                        let cell_color = match editor.grid[row][col] {
-                                            Some(ref bt) => editor_block_color(bt),
-                                            None => EMPTY_CELL_COLOR,
-                                        };
-
-                                        row_node.spawn((
-                                            Button,
-                                            Node {
-                                                width: Val::Px(EDITOR_CELL_SIZE),
-                                                height: Val::Px(36.0),
-                                                ..default()
-                                            },
-                                            BackgroundColor(cell_color),
-                                            GridCell { row, col },
-                                        ));
+                                        let cell_color = editor_cell_color(&settings, &editor, row, col);
+                                        let (glyph, glyph_color) = editor_cell_glyph(&settings, &editor, row, col);
+
+                                        row_node
+                                            .spawn((
+                                                Button,
+                                                Node {
+                                                    width: Val::Px(EDITOR_CELL_SIZE),
+                                                    height: Val::Px(36.0),
+                                                    justify_content: JustifyContent::Center,
+                                                    align_items: AlignItems::Center,
+                                                    ..default()
+                                                },
+                                                BackgroundColor(cell_color),
+                                                GridCell { row, col },
+                                            ))
+                                            .with_children(|cell| {
+                                                cell.spawn((
+                                                    Text::new(glyph),
+                                                    TextFont {
+                                                        font: font.clone(),
+                                                        font_size: 16.0,
+                                                        font_smoothing: FontSmoothing::None,
+                                                    },
+                                                    TextColor(glyph_color),
+                                                    GridCellGlyph { row, col },
+                                                ));
+                                            });
                                     }
                                 });
                         }
@@ -235,18 +372,30 @@ fn spawn_editor_button(
         });
 }
 
-/// Handle grid cell clicks to place/remove blocks
+/// Handle grid cell clicks to place/remove blocks (anchored at the clicked cell)
 pub fn editor_grid_input(
     mut editor: ResMut<EditorState>,
-    mut cells: Query<(&Interaction, &GridCell, &mut BackgroundColor), Changed<Interaction>>,
+    settings: Res<GameSettings>,
+    pressed: Query<(&Interaction, &GridCell), Changed<Interaction>>,
+    mut cells: Query<(&GridCell, &mut BackgroundColor)>,
+    mut glyphs: Query<(&GridCellGlyph, &mut Text, &mut TextColor)>,
 ) {
-    for (interaction, cell, mut bg) in &mut cells {
+    let mut changed = false;
+    for (interaction, cell) in &pressed {
         if *interaction == Interaction::Pressed {
-            editor.grid[cell.row][cell.col] = editor.selected_tool;
-            *bg = BackgroundColor(match editor.selected_tool {
-                Some(ref bt) => editor_block_color(bt),
-                None => EMPTY_CELL_COLOR,
-            });
+            changed |= editor.place(cell.row, cell.col);
+        }
+    }
+
+    // A large block touches several cells, so repaint the whole grid
+    if changed {
+        for (cell, mut bg) in &mut cells {
+            *bg = BackgroundColor(editor_cell_color(&settings, &editor, cell.row, cell.col));
+        }
+        for (cell, mut text, mut color) in &mut glyphs {
+            let (glyph, glyph_color) = editor_cell_glyph(&settings, &editor, cell.row, cell.col);
+            **text = glyph;
+            color.0 = glyph_color;
         }
     }
 }
@@ -277,6 +426,30 @@ pub fn editor_tool_select(
     }
 }
 
+/// Handle size palette button clicks
+pub fn editor_size_select(
+    mut editor: ResMut<EditorState>,
+    mut sizes: Query<(&Interaction, &SizeButton, &mut BorderColor)>,
+) {
+    let mut new_selection = None;
+    for (interaction, size, _) in &sizes {
+        if *interaction == Interaction::Pressed {
+            new_selection = Some(size.0);
+        }
+    }
+
+    if let Some(sel) = new_selection {
+        editor.selected_span = sel;
+        for (_, size, mut border) in &mut sizes {
+            if size.0 == sel {
+                *border = BorderColor(TOOL_SELECTED_COLOR);
+            } else {
+                *border = BorderColor(Color::NONE);
+            }
+        }
+    }
+}
+
 /// Handle share button press
 pub fn editor_share(
     editor: Res<EditorState>,
@@ -288,8 +461,7 @@ pub fn editor_share(
             continue;
         }
 
-        let json = serde_json::to_string(&editor.grid).unwrap_or_default();
-        let encoded = base64_encode(&json);
+        let encoded = encode_stage(&editor);
 
         #[cfg(target_arch = "wasm32")]
         {
@@ -358,7 +530,7 @@ pub fn spawn_blocks_from_editor(mut commands: Commands, editor: Res<EditorState>
     for row in 0..EDITOR_ROWS {
         for col in 0..EDITOR_COLS {
             if let Some(block_type) = editor.grid[row][col] {
-                spawn_block(&mut commands, grid_x(col), grid_y(row), block_type, row);
+                spawn_grid_block(&mut commands, row, col, editor.spans[row][col], block_type);
             }
         }
     }
@@ -378,20 +550,56 @@ pub fn load_stage_from_url(
     mut next_state: ResMut<NextState<GameState>>,
 ) {
     if let Some(stage_data) = get_url_stage_param() {
-        if let Some(grid) = decode_stage(&stage_data) {
-            commands.insert_resource(EditorState {
-                selected_tool: Some(BlockType::Normal),
-                grid,
-            });
+        if let Some(editor) = decode_stage(&stage_data) {
+            commands.insert_resource(editor);
             next_state.set(GameState::Editor);
         }
     }
 }
 
-/// Decode a base64-encoded stage string into a grid
-fn decode_stage(encoded: &str) -> Option<[[Option<BlockType>; 10]; 7]> {
+/// Encode the editor stage as a base64 string.
+/// Stages with only single-cell blocks use the original bare-grid format.
+fn encode_stage(editor: &EditorState) -> String {
+    let mut spans = Vec::new();
+    for row in 0..EDITOR_ROWS {
+        for col in 0..EDITOR_COLS {
+            let span = editor.spans[row][col];
+            if editor.grid[row][col].is_some() && span.is_multi_cell() {
+                spans.push(StageSpan { row, col, cols: span.cols, rows: span.rows });
+            }
+        }
+    }
+
+    let json = if spans.is_empty() {
+        serde_json::to_string(&editor.grid)
+    } else {
+        serde_json::to_string(&StageData { grid: editor.grid, spans })
+    }
+    .unwrap_or_default();
+    base64_encode(&json)
+}
+
+/// Decode a base64-encoded stage string (bare grid or grid + spans) into editor state
+fn decode_stage(encoded: &str) -> Option<EditorState> {
     let json = base64_decode(encoded)?;
-    serde_json::from_str(&json).ok()
+    let data = serde_json::from_str::<StageData>(&json).ok().or_else(|| {
+        serde_json::from_str(&json)
+            .ok()
+            .map(|grid| StageData { grid, spans: Vec::new() })
+    })?;
+
+    let mut editor = EditorState {
+        grid: data.grid,
+        ..default()
+    };
+    for s in data.spans {
+        // Ignore footprints that are empty, unanchored, or spill off the grid
+        let fits = s.cols > 0 && s.rows > 0 && s.row + s.rows <= EDITOR_ROWS && s.col + s.cols <= EDITOR_COLS;
+        if fits && editor.grid[s.row][s.col].is_some() {
+            editor.spans[s.row][s.col] = BlockSpan::new(s.cols, s.rows);
+        }
+    }
+    Some(editor)
 }
 
 /// Simple Base64 encoding (URL-safe)
@@ -505,10 +713,7 @@ fn copy_to_clipboard_wasm(encoded: &str) {
         let pathname = location.pathname().ok()?;
         let url = format!("{}{}?stage={}", origin, pathname, encoded);
 
-        // Try clipboard API
-        let navigator = window.navigator();
-        let clipboard = navigator.clipboard();
-        let _ = clipboard.write_text(&url);
+        copy_text_to_clipboard(&url);
 
         // Also update URL bar
         let _ = window.history().ok()?.replace_state_with_url(
@@ -521,6 +726,14 @@ fn copy_to_clipboard_wasm(encoded: &str) {
     })();
 }
 
+/// Copy text via the browser clipboard API (WASM)
+#[cfg(target_arch = "wasm32")]
+pub fn copy_text_to_clipboard(text: &str) {
+    if let Some(window) = web_sys::window() {
+        let _ = window.navigator().clipboard().write_text(text);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -573,6 +786,45 @@ mod tests {
         // Should not panic, may return None or Some with garbage
     }
 
+    #[test]
+    fn encode_stage_single_cells_uses_bare_grid() {
+        let mut editor = EditorState::default();
+        editor.place(0, 0);
+        let json = base64_decode(&encode_stage(&editor)).unwrap();
+        let grid: [[Option<BlockType>; 10]; 7] = serde_json::from_str(&json).unwrap();
+        assert_eq!(grid, editor.grid);
+    }
+
+    #[test]
+    fn stage_roundtrip_with_large_blocks() {
+        let mut editor = EditorState {
+            selected_span: BlockSpan::new(3, 3),
+            ..Default::default()
+        };
+        editor.place(1, 2);
+        editor.selected_span = BlockSpan::new(2, 1);
+        editor.selected_tool = Some(BlockType::Explosive);
+        editor.place(6, 8);
+
+        let decoded = decode_stage(&encode_stage(&editor)).unwrap();
+        assert_eq!(decoded.grid, editor.grid);
+        assert_eq!(decoded.spans, editor.spans);
+        assert_eq!(decoded.block_at(3, 4), Some(BlockType::Normal));
+    }
+
+    #[test]
+    fn decode_stage_drops_spans_off_grid() {
+        let mut grid: [[Option<BlockType>; 10]; 7] = [[None; 10]; 7];
+        grid[6][9] = Some(BlockType::Normal);
+        let data = StageData {
+            grid,
+            spans: vec![StageSpan { row: 6, col: 9, cols: 2, rows: 2 }],
+        };
+        let encoded = base64_encode(&serde_json::to_string(&data).unwrap());
+        let decoded = decode_stage(&encoded).unwrap();
+        assert_eq!(decoded.spans[6][9], BlockSpan::SINGLE);
+    }
+
     #[test]
     fn editor_has_clearable_blocks_empty() {
         let editor = EditorState::default();
@@ -1,8 +1,12 @@
 use bevy::prelude::*;
+use bevy::text::FontSmoothing;
 
-use crate::components::{Ball, Particle, NewRecordFlash, RankMarker};
+use crate::components::{
+    Ball, BallKind, Block, BlockRow, FieldCamera, FieldForce, FieldZone, GlyphOverlay, Particle, NewRecordFlash,
+    PowerUp, RankMarker, Wall,
+};
 use crate::constants::*;
-use crate::resources::ScreenShake;
+use crate::resources::{GameSettings, PowerUpRegistry, ScreenShake, GAME_FONT_PATH};
 use crate::utils::rand_f32;
 
 /// Timer resource for ball trail spawn interval
@@ -15,6 +19,23 @@ impl Default for TrailTimer {
     }
 }
 
+/// Particles left of a burst of `count` at the particle density setting
+pub fn particle_count(count: usize, density: f32) -> usize {
+    (count as f32 * density).round() as usize
+}
+
+/// Error-diffusion thinning for steady particle streams (trail, motes): at a density of 0.5
+/// every other spawn goes ahead
+fn keep_particle(carry: &mut f32, density: f32) -> bool {
+    *carry += density;
+    if *carry >= 1.0 {
+        *carry -= 1.0;
+        true
+    } else {
+        false
+    }
+}
+
 /// Update particles: move, fade out, despawn when lifetime expires
 pub fn update_particles(
     mut commands: Commands,
@@ -40,25 +61,29 @@ pub fn update_particles(
     }
 }
 
-/// Spawn trail particles behind each ball
+/// Spawn trail particles behind each ball, tinted by its kind (unless trails are turned off),
+/// thinned to the particle density setting
 pub fn spawn_ball_trail(
     mut commands: Commands,
     time: Res<Time>,
+    settings: Res<GameSettings>,
     mut trail_timer: ResMut<TrailTimer>,
-    ball_query: Query<&Transform, With<Ball>>,
+    mut carry: Local<f32>,
+    ball_query: Query<(&Transform, &BallKind), With<Ball>>,
 ) {
     trail_timer.0.tick(time.delta());
-    if !trail_timer.0.just_finished() {
+    if !trail_timer.0.just_finished() || !settings.ball_trail {
         return;
     }
 
-    let trail_color = Color::srgba(1.0, 0.96, 0.88, TRAIL_INITIAL_ALPHA);
-
-    for ball_transform in &ball_query {
+    for (ball_transform, kind) in &ball_query {
+        if !keep_particle(&mut carry, settings.particles) {
+            continue;
+        }
         let pos = ball_transform.translation;
         commands.spawn((
             Sprite {
-                color: trail_color,
+                color: kind.trail_color().with_alpha(TRAIL_INITIAL_ALPHA),
                 custom_size: Some(Vec2::splat(TRAIL_PARTICLE_SIZE)),
                 ..default()
             },
@@ -72,11 +97,174 @@ pub fn spawn_ball_trail(
     }
 }
 
-/// Apply screen shake to camera using trauma-based system
+/// Spawn faint motes inside each field zone, drifting the way it pushes (thinned to the
+/// particle density setting)
+pub fn spawn_field_motes(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<GameSettings>,
+    mut elapsed: Local<f32>,
+    mut carry: Local<f32>,
+    zones: Query<(&Transform, &FieldZone)>,
+) {
+    *elapsed += time.delta_secs();
+    if *elapsed < FIELD_MOTE_INTERVAL {
+        return;
+    }
+    *elapsed = 0.0;
+
+    for (transform, zone) in &zones {
+        if !keep_particle(&mut carry, settings.particles) {
+            continue;
+        }
+        let offset = (Vec2::new(rand_f32(), rand_f32()) - 0.5) * zone.size;
+        let pos = transform.translation.truncate() + offset;
+        let velocity = match zone.force {
+            FieldForce::Accel(a) => a.normalize_or_zero() * FIELD_MOTE_SPEED,
+            FieldForce::Speed(s) => Vec2::Y * FIELD_MOTE_SPEED * 0.5 * s,
+        };
+        commands.spawn((
+            Sprite {
+                color: zone.force.color().with_alpha(FIELD_MOTE_ALPHA),
+                custom_size: Some(Vec2::splat(PARTICLE_SIZE * 0.75)),
+                ..default()
+            },
+            Transform::from_xyz(pos.x, pos.y, -0.5),
+            Particle {
+                lifetime: Timer::from_seconds(FIELD_MOTE_LIFETIME, TimerMode::Once),
+                velocity,
+                initial_alpha: FIELD_MOTE_ALPHA,
+            },
+        ));
+    }
+}
+
+/// Apply the high-contrast background and wall colors when the setting changes or walls spawn
+pub fn apply_display_settings(
+    settings: Res<GameSettings>,
+    mut clear_color: ResMut<ClearColor>,
+    mut walls: Query<(Ref<Wall>, &mut Sprite)>,
+) {
+    if settings.is_changed() {
+        clear_color.0 = settings.background_color();
+    }
+    for (wall, mut sprite) in &mut walls {
+        if settings.is_changed() || wall.is_added() {
+            sprite.color = settings.wall_color();
+        }
+    }
+}
+
+/// Recolor new or damaged blocks and new power-ups (or all of them after a settings change)
+/// with the chosen palette
+pub fn apply_block_palette(
+    settings: Res<GameSettings>,
+    registry: Res<PowerUpRegistry>,
+    mut blocks: Query<(Ref<Block>, &BlockRow, &mut Sprite), Without<PowerUp>>,
+    mut powerups: Query<(Ref<PowerUp>, &mut Sprite), Without<Block>>,
+) {
+    for (block, row, mut sprite) in &mut blocks {
+        if settings.is_changed() || block.is_changed() {
+            sprite.color = settings.block_color(&block.block_type, row.0);
+        }
+    }
+    for (power_up, mut sprite) in &mut powerups {
+        if settings.is_changed() || power_up.is_added() {
+            let alpha = sprite.color.alpha();
+            sprite.color = settings.powerup_color(registry.get(power_up.power_type)).with_alpha(alpha);
+        }
+    }
+}
+
+/// Dark glyph text on light backgrounds, white on dark ones
+pub fn glyph_color(background: Color) -> Color {
+    let linear = background.to_linear();
+    let luminance = 0.2126 * linear.red + 0.7152 * linear.green + 0.0722 * linear.blue;
+    if luminance > 0.18 {
+        Color::srgba(0.08, 0.08, 0.12, 0.85)
+    } else {
+        Color::srgba(1.0, 1.0, 1.0, 0.9)
+    }
+}
+
+/// Give new blocks and power-up capsules a glyph overlay child (hidden until `update_glyph_overlays`
+/// shows it). Hazard diamonds get a counter-rotated overlay so their icon stays upright.
+pub fn label_glyph_overlays(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    blocks: Query<Entity, Added<Block>>,
+    powerups: Query<(Entity, &Transform), Added<PowerUp>>,
+) {
+    let font: Handle<Font> = asset_server.load(GAME_FONT_PATH);
+    let overlays = blocks
+        .iter()
+        .map(|entity| (entity, GLYPH_BLOCK_FONT_SIZE, Quat::IDENTITY))
+        .chain(powerups.iter().map(|(entity, transform)| {
+            (entity, GLYPH_POWERUP_FONT_SIZE, transform.rotation.inverse())
+        }));
+
+    for (entity, font_size, rotation) in overlays {
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn((
+                Text2d::default(),
+                TextFont {
+                    font: font.clone(),
+                    font_size,
+                    font_smoothing: FontSmoothing::None,
+                },
+                TextColor::default(),
+                Transform::from_xyz(0.0, 0.0, 0.1).with_rotation(rotation),
+                Visibility::Hidden,
+                GlyphOverlay,
+            ));
+        });
+    }
+}
+
+/// Fill in glyph overlays from the glyph setting: letters on Steel / Explosive blocks, hit
+/// counts on Durable blocks and icons on power-ups, tracking damage and settings changes
+pub fn update_glyph_overlays(
+    settings: Res<GameSettings>,
+    registry: Res<PowerUpRegistry>,
+    blocks: Query<(Ref<Block>, &BlockRow)>,
+    powerups: Query<&PowerUp>,
+    mut overlays: Query<(Ref<GlyphOverlay>, &Parent, &mut Text2d, &mut TextColor, &mut Visibility)>,
+) {
+    for (overlay, parent, mut text, mut color, mut visibility) in &mut overlays {
+        let glyph = if let Ok((block, row)) = blocks.get(parent.get()) {
+            if !(settings.is_changed() || block.is_changed() || overlay.is_added()) {
+                continue;
+            }
+            settings
+                .block_glyph(&block.block_type)
+                .map(|glyph| (glyph, settings.block_color(&block.block_type, row.0)))
+        } else if let Ok(power_up) = powerups.get(parent.get()) {
+            if !(settings.is_changed() || overlay.is_added()) {
+                continue;
+            }
+            let def = registry.get(power_up.power_type);
+            settings.powerup_glyphs().then(|| (def.icon.to_string(), settings.powerup_color(def)))
+        } else {
+            continue;
+        };
+
+        match glyph {
+            Some((glyph, background)) => {
+                **text = glyph;
+                color.0 = glyph_color(background);
+                *visibility = Visibility::Inherited;
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+}
+
+/// Apply screen shake to camera using trauma-based system, scaled by the shake setting
 pub fn apply_screen_shake(
     time: Res<Time>,
+    settings: Res<GameSettings>,
     mut shake: ResMut<ScreenShake>,
-    mut camera_query: Query<&mut Transform, With<Camera2d>>,
+    mut camera_query: Query<&mut Transform, (With<Camera2d>, Without<FieldCamera>)>,
 ) {
     let dt = time.delta_secs();
 
@@ -89,7 +277,7 @@ pub fn apply_screen_shake(
 
     if shake.trauma > 0.0 {
         // Use trauma² for smooth falloff
-        let shake_amount = shake.trauma * shake.trauma;
+        let shake_amount = shake.trauma * shake.trauma * settings.shake;
         let offset_x = shake_amount * SHAKE_MAX_OFFSET * (rand_f32() * 2.0 - 1.0);
         let offset_y = shake_amount * SHAKE_MAX_OFFSET * (rand_f32() * 2.0 - 1.0);
         camera_transform.translation.x = offset_x;
@@ -114,14 +302,17 @@ pub fn update_rank_marker(
     }
 }
 
-/// Blink NEW RECORD text on/off
+/// Blink NEW RECORD text on/off (held steady with reduced flashing)
 pub fn update_new_record_flash(
     time: Res<Time>,
+    settings: Res<GameSettings>,
     mut query: Query<(&mut NewRecordFlash, &mut TextColor)>,
 ) {
     for (mut flash, mut text_color) in &mut query {
         flash.timer.tick(time.delta());
-        if flash.timer.just_finished() {
+        if settings.reduced_flashing {
+            text_color.0 = text_color.0.with_alpha(1.0);
+        } else if flash.timer.just_finished() {
             // Toggle visibility by alpha
             let current_alpha = text_color.0.alpha();
             if current_alpha > 0.5 {
@@ -273,4 +464,108 @@ mod tests {
             "Camera y should reset to 0"
         );
     }
+
+    /// Particles left after a block break, a trail and a field zone run for half a second
+    fn particles_at_density(density: f32) -> usize {
+        let mut app = test_app();
+        app.world_mut().resource_mut::<GameSettings>().particles = density;
+        spawn_test_block(app.world_mut(), Vec2::new(0.0, 100.0));
+        spawn_test_ball(app.world_mut(), Vec2::new(0.0, 100.0), Vec2::new(0.0, BALL_SPEED));
+        app.world_mut().spawn((
+            Transform::default(),
+            FieldZone {
+                force: FieldForce::Speed(FIELD_SLOW_SCALE),
+                size: Vec2::splat(200.0),
+            },
+        ));
+
+        app.add_systems(
+            Update,
+            (crate::systems::collision::ball_block_collision, spawn_ball_trail, spawn_field_motes),
+        );
+        for _ in 0..30 {
+            app.update();
+        }
+        app.world_mut().query::<&Particle>().iter(app.world()).count()
+    }
+
+    #[test]
+    fn particle_density_thins_spawns() {
+        let full = particles_at_density(1.0);
+        let half = particles_at_density(0.5);
+        assert!(full > 0);
+        assert!(half > 0 && half < full, "{half} of {full}");
+        assert_eq!(particles_at_density(0.0), 0, "Nothing is spawned at all, not even for a frame");
+        assert_eq!(particle_count(PARTICLE_COUNT, 0.5), PARTICLE_COUNT / 2);
+    }
+
+    #[test]
+    fn zero_shake_keeps_camera_still() {
+        let mut app = test_app();
+        app.world_mut().resource_mut::<GameSettings>().shake = 0.0;
+        app.world_mut().resource_mut::<ScreenShake>().trauma = 1.0;
+        let camera = app.world_mut().spawn((Camera2d, Transform::default())).id();
+
+        app.add_systems(Update, apply_screen_shake);
+        app.update();
+
+        let transform = app.world().entity(camera).get::<Transform>().unwrap();
+        assert_eq!(transform.translation, Vec3::ZERO);
+    }
+
+    #[test]
+    fn block_palette_recolors_blocks_and_capsules() {
+        use crate::components::{BlockType, PowerUpType};
+        use crate::resources::BlockPalette;
+
+        let mut app = test_app();
+        app.add_systems(Update, apply_block_palette);
+        let block = spawn_test_block(app.world_mut(), Vec2::ZERO);
+        app.world_mut().entity_mut(block).insert(BlockRow(2));
+        let capsule = app
+            .world_mut()
+            .spawn((Sprite::default(), PowerUp { power_type: PowerUpType::Laser }))
+            .id();
+
+        app.world_mut().resource_mut::<GameSettings>().palette = BlockPalette::Tritanopia;
+        app.update();
+
+        let settings = app.world().resource::<GameSettings>();
+        let def = app.world().resource::<PowerUpRegistry>().get(PowerUpType::Laser);
+        assert_eq!(app.world().get::<Sprite>(block).unwrap().color, settings.block_color(&BlockType::Normal, 2));
+        assert_eq!(app.world().get::<Sprite>(capsule).unwrap().color, settings.powerup_color(def));
+    }
+
+    #[test]
+    fn glyph_overlays_follow_setting_and_damage() {
+        use crate::components::BlockType;
+        use crate::resources::BlockPalette;
+
+        let mut app = test_app();
+        app.add_systems(Update, (apply_block_palette, update_glyph_overlays));
+
+        let block = spawn_test_block_typed(app.world_mut(), Vec2::ZERO, BlockType::Durable { hits_remaining: 3 });
+        let overlay = app
+            .world_mut()
+            .spawn((Text2d::default(), TextColor::default(), Visibility::Hidden, GlyphOverlay))
+            .set_parent(block)
+            .id();
+        app.update();
+        assert_eq!(app.world().get::<Visibility>(overlay), Some(&Visibility::Hidden), "Glyphs start off");
+
+        app.world_mut().resource_mut::<GameSettings>().glyphs = true;
+        app.update();
+        assert_eq!(app.world().get::<Text2d>(overlay).unwrap().0, "3");
+        assert_eq!(app.world().get::<Visibility>(overlay), Some(&Visibility::Inherited));
+
+        app.world_mut().get_mut::<Block>(block).unwrap().block_type = BlockType::Durable { hits_remaining: 1 };
+        app.update();
+        assert_eq!(app.world().get::<Text2d>(overlay).unwrap().0, "1", "Hit count tracks damage");
+
+        app.world_mut().resource_mut::<GameSettings>().palette = BlockPalette::Monochrome;
+        app.update();
+        let sprite = app.world().get::<Sprite>(block).unwrap();
+        assert_eq!(sprite.color, BlockPalette::Monochrome.durable_color(1));
+        assert_eq!(app.world().get::<TextColor>(overlay).unwrap().0, glyph_color(sprite.color));
+    }
 }
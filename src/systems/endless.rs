@@ -0,0 +1,206 @@
+use bevy::prelude::*;
+
+use crate::components::*;
+use crate::constants::*;
+use crate::resources::*;
+use crate::states::GameState;
+use crate::systems::audio::CollisionEvent;
+use crate::systems::setup::{generated_block_type, grid_x, grid_y, spawn_block};
+
+/// Distance the field descends for one inserted row
+pub const ROW_STEP: f32 = BLOCK_HEIGHT + BLOCK_GAP;
+
+/// Spawn the opening rows and the danger line of an endless run
+pub fn spawn_endless_start(commands: &mut Commands, mode: &mut EndlessMode) {
+    for row in 0..ENDLESS_START_ROWS {
+        spawn_endless_row(commands, grid_y(row as usize), ENDLESS_BASE_LEVEL, row);
+    }
+    mode.rows_pushed = ENDLESS_START_ROWS;
+    mode.scroll = 0.0;
+
+    commands.spawn((
+        Sprite {
+            color: Color::srgba(0.92, 0.44, 0.44, 0.35), // Salmon
+            custom_size: Some(Vec2::new(WINDOW_WIDTH - WALL_THICKNESS * 2.0, 2.0)),
+            ..default()
+        },
+        Transform::from_xyz(0.0, ENDLESS_DANGER_Y, -0.5),
+        DangerLine,
+    ));
+}
+
+/// Spawn one full row of generated blocks at the given height
+pub fn spawn_endless_row(commands: &mut Commands, y: f32, level: u32, row: u32) {
+    for col in 0..BLOCK_COLS {
+        let block_type = generated_block_type(level, row, col as u32);
+        spawn_block(commands, grid_x(col), y, block_type, row as usize);
+    }
+}
+
+/// Move the whole field down and push a new row in at the top each time there is room
+pub fn endless_descend(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut mode: ResMut<EndlessMode>,
+    mut block_query: Query<(&mut Transform, &Block)>,
+) {
+    let breakable_left = block_query
+        .iter()
+        .any(|(_, block)| block.block_type != BlockType::Steel);
+    // An emptied field pulls the next row in at once
+    let dy = if breakable_left {
+        mode.descent_speed() * time.delta_secs()
+    } else {
+        (ROW_STEP - mode.scroll).max(0.0)
+    };
+
+    for (mut transform, _) in &mut block_query {
+        transform.translation.y -= dy;
+    }
+    mode.scroll += dy;
+    if !breakable_left {
+        mode.scroll = mode.scroll.max(ROW_STEP);
+    }
+
+    while mode.scroll >= ROW_STEP {
+        mode.scroll -= ROW_STEP;
+        let (level, row) = (mode.difficulty_level(), mode.rows_pushed);
+        spawn_endless_row(&mut commands, grid_y(0) - mode.scroll, level, row);
+        mode.rows_pushed += 1;
+    }
+}
+
+/// End the run when a breakable block reaches the danger line (steel sinks away harmlessly)
+pub fn endless_danger_check(
+    mut commands: Commands,
+    block_query: Query<(Entity, &Transform, &Collider, &Block)>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut collision_events: EventWriter<CollisionEvent>,
+) {
+    for (entity, transform, collider, block) in &block_query {
+        if transform.translation.y - collider.size.y / 2.0 > ENDLESS_DANGER_Y {
+            continue;
+        }
+        if block.block_type == BlockType::Steel {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        collision_events.send(CollisionEvent::GameOver);
+        next_state.set(GameState::GameOver);
+        return;
+    }
+}
+
+/// Show the endless difficulty step in place of the level number
+pub fn update_endless_level_text(mode: Res<EndlessMode>, mut query: Query<&mut Text, With<LevelText>>) {
+    let label = format!(
+        "エンドレス {}",
+        mode.difficulty_level() - ENDLESS_BASE_LEVEL + 1
+    );
+    for mut text in &mut query {
+        if text.0 != label {
+            *text = Text::new(label.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    fn block_count(app: &mut App) -> usize {
+        app.world_mut().query::<&Block>().iter(app.world()).count()
+    }
+
+    #[test]
+    fn start_spawns_rows_and_danger_line() {
+        let mut app = test_app();
+        app.insert_resource(EndlessMode::default());
+        app.add_systems(Update, crate::systems::setup::spawn_blocks);
+        app.update();
+
+        assert_eq!(block_count(&mut app), (ENDLESS_START_ROWS as usize) * BLOCK_COLS);
+        let lines = app.world_mut().query::<&DangerLine>().iter(app.world()).count();
+        assert_eq!(lines, 1);
+        assert_eq!(app.world().resource::<EndlessMode>().rows_pushed, ENDLESS_START_ROWS);
+    }
+
+    #[test]
+    fn field_descends_and_inserts_row_at_top() {
+        let mut app = test_app();
+        app.insert_resource(EndlessMode {
+            rows_pushed: ENDLESS_START_ROWS,
+            scroll: ROW_STEP - 0.01,
+        });
+        let block = spawn_test_block(app.world_mut(), Vec2::new(0.0, 100.0));
+
+        app.add_systems(Update, endless_descend);
+        app.update();
+
+        let y = app.world().get::<Transform>(block).unwrap().translation.y;
+        assert!(y < 100.0, "Blocks move down");
+        assert_eq!(block_count(&mut app), 1 + BLOCK_COLS, "A full row was pushed in");
+        let mode = app.world().resource::<EndlessMode>();
+        assert_eq!(mode.rows_pushed, ENDLESS_START_ROWS + 1);
+        assert!(mode.scroll < ROW_STEP);
+    }
+
+    #[test]
+    fn empty_field_pulls_next_row_immediately() {
+        let mut app = test_app();
+        app.insert_resource(EndlessMode::default());
+        spawn_test_block_typed(app.world_mut(), Vec2::new(0.0, 100.0), BlockType::Steel);
+
+        app.add_systems(Update, endless_descend);
+        app.update();
+
+        assert_eq!(block_count(&mut app), 1 + BLOCK_COLS);
+    }
+
+    #[test]
+    fn block_at_danger_line_ends_run() {
+        let mut app = test_app();
+        spawn_test_block(app.world_mut(), Vec2::new(0.0, ENDLESS_DANGER_Y));
+
+        app.add_systems(Update, endless_danger_check);
+        app.update();
+
+        assert!(matches!(
+            app.world().resource::<NextState<GameState>>(),
+            NextState::Pending(GameState::GameOver)
+        ));
+    }
+
+    #[test]
+    fn steel_past_danger_line_is_removed() {
+        let mut app = test_app();
+        spawn_test_block_typed(app.world_mut(), Vec2::new(0.0, ENDLESS_DANGER_Y), BlockType::Steel);
+
+        app.add_systems(Update, endless_danger_check);
+        app.update();
+
+        assert_eq!(block_count(&mut app), 0);
+        assert!(!matches!(
+            app.world().resource::<NextState<GameState>>(),
+            NextState::Pending(GameState::GameOver)
+        ));
+    }
+
+    #[test]
+    fn difficulty_rises_with_rows_pushed() {
+        let early = EndlessMode::default();
+        let late = EndlessMode {
+            rows_pushed: ENDLESS_ROWS_PER_LEVEL * 3,
+            scroll: 0.0,
+        };
+        assert_eq!(early.difficulty_level(), ENDLESS_BASE_LEVEL);
+        assert_eq!(late.difficulty_level(), ENDLESS_BASE_LEVEL + 3);
+        assert!(late.descent_speed() > early.descent_speed());
+        let maxed = EndlessMode {
+            rows_pushed: 10_000,
+            scroll: 0.0,
+        };
+        assert_eq!(maxed.descent_speed(), ENDLESS_DESCENT_SPEED_MAX);
+    }
+}
@@ -1,3 +1,4 @@
+use bevy::ecs::system::SystemParam;
 use bevy::input::touch::Touches;
 use bevy::prelude::*;
 use bevy::text::FontSmoothing;
@@ -8,6 +9,10 @@ use crate::components::*;
 use crate::constants::*;
 use crate::resources::*;
 use crate::states::GameState;
+use crate::systems::daily::today_utc;
+use crate::systems::input::ActionInput;
+use crate::systems::option_list::{spawn_option_list, OptionListRow};
+use crate::systems::puzzle::{star_marks, PUZZLE_STAGES};
 
 /// Create a small left-pointing triangle image (9x9 pixels, gold colored)
 fn create_triangle_image() -> Image {
@@ -38,6 +43,7 @@ fn create_triangle_image() -> Image {
 }
 
 /// Spawn a volume control row: [cursor] [label] [< button] [value%] [> button]
+#[allow(clippy::too_many_arguments)]
 fn spawn_volume_row(
     parent: &mut ChildBuilder,
     font: &Handle<Font>,
@@ -142,7 +148,12 @@ fn spawn_volume_row(
 }
 
 /// Setup menu screen
-pub fn setup_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
+pub fn setup_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    daily_record: Res<DailyRecord>,
+    bindings: Res<KeyBindings>,
+) {
     let warm_white = Color::srgb(1.0, 0.96, 0.88);
     let cream = Color::srgb(0.95, 0.85, 0.65);
     let lavender = Color::srgb(0.55, 0.50, 0.65);
@@ -178,7 +189,7 @@ pub fn setup_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
 
             // Start instruction
             parent.spawn((
-                Text::new("SPACE / タップ でスタート"),
+                Text::new(format!("{} / タップ でスタート", bindings.primary_label(InputAction::Confirm))),
                 TextFont {
                     font: font_handle.clone(),
                     font_size: 24.0,
@@ -190,7 +201,7 @@ pub fn setup_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
 
             // Controls
             parent.spawn((
-                Text::new("← → / タップ  パドル操作\nESC  ポーズ"),
+                Text::new(menu_controls_hint(&bindings)),
                 TextFont {
                     font: font_handle.clone(),
                     font_size: 16.0,
@@ -200,7 +211,7 @@ pub fn setup_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
                 TextLayout::new_with_justify(JustifyText::Center),
             ));
 
-            // Button row
+            // Mode buttons
             parent
                 .spawn(Node {
                     flex_direction: FlexDirection::Row,
@@ -208,55 +219,91 @@ pub fn setup_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
                     ..default()
                 })
                 .with_children(|row| {
-                    // Settings button (tappable on mobile)
-                    row
-                        .spawn((
-                            Button,
-                            Node {
-                                padding: UiRect::axes(Val::Px(16.0), Val::Px(8.0)),
-                                ..default()
-                            },
-                            BackgroundColor(Color::NONE),
-                            SettingsButton,
-                        ))
-                        .with_children(|btn| {
-                            btn.spawn((
-                                Text::new("[ 設定 ]"),
-                                TextFont {
-                                    font: font_handle.clone(),
-                                    font_size: 16.0,
-                                    font_smoothing: FontSmoothing::None,
-                                },
-                                TextColor(lavender),
-                            ));
-                        });
-
-                    // Editor button
-                    row
-                        .spawn((
-                            Button,
-                            Node {
-                                padding: UiRect::axes(Val::Px(16.0), Val::Px(8.0)),
-                                ..default()
-                            },
-                            BackgroundColor(Color::NONE),
-                            EditorButton,
-                        ))
-                        .with_children(|btn| {
-                            btn.spawn((
-                                Text::new("[ エディタ ]"),
-                                TextFont {
-                                    font: font_handle,
-                                    font_size: 16.0,
-                                    font_smoothing: FontSmoothing::None,
-                                },
-                                TextColor(lavender),
-                            ));
-                        });
+                    spawn_menu_button(row, &font_handle, "[ タイムアタック ]", TimeAttackButton);
+                    spawn_menu_button(row, &font_handle, "[ エンドレス ]", EndlessButton);
+                    let daily_label = if daily_record.played(today_utc()) {
+                        "[ デイリー 済 ]"
+                    } else {
+                        "[ デイリー ]"
+                    };
+                    spawn_menu_button(row, &font_handle, daily_label, DailyButton);
+                    spawn_menu_button(row, &font_handle, "[ パズル ]", PuzzleButton);
+                });
+
+            // Two-player mode buttons
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(16.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    spawn_menu_button(row, &font_handle, "[ 2人協力 ]", CoopButton);
+                    spawn_menu_button(row, &font_handle, "[ 対戦 ]", VersusButton);
+                });
+
+            // Settings and editor buttons (tappable on mobile)
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(16.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    spawn_menu_button(row, &font_handle, "[ 設定 ]", SettingsButton);
+                    spawn_menu_button(row, &font_handle, "[ エディタ ]", EditorButton);
+                    spawn_menu_button(row, &font_handle, "[ 練習 ]", PracticeButton);
                 });
         });
 }
 
+/// Menu key hints, following the current key bindings
+pub fn menu_controls_hint(bindings: &KeyBindings) -> String {
+    let key = |action| bindings.primary_label(action);
+    format!(
+        "{} {} / タップ  パドル操作\n{}  ポーズ\n\
+         {}  タイムアタック    {}  エンドレス    {}  デイリー    {}  パズル\n\
+         {}  2人協力    {}  対戦    {}  練習",
+        key(InputAction::MoveLeft),
+        key(InputAction::MoveRight),
+        key(InputAction::Pause),
+        key(InputAction::TimeAttack),
+        key(InputAction::Endless),
+        key(InputAction::Daily),
+        key(InputAction::Puzzle),
+        key(InputAction::Coop),
+        key(InputAction::Versus),
+        key(InputAction::Practice),
+    )
+}
+
+/// Spawn a text-only menu button
+fn spawn_menu_button(row: &mut ChildBuilder, font: &Handle<Font>, label: &str, marker: impl Component) {
+    let lavender = Color::srgb(0.55, 0.50, 0.65);
+
+    row.spawn((
+        Button,
+        Node {
+            padding: UiRect::axes(Val::Px(16.0), Val::Px(8.0)),
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+        MenuFocusable,
+        marker,
+    ))
+    .with_children(|btn| {
+        btn.spawn((
+            Text::new(label),
+            TextFont {
+                font: font.clone(),
+                font_size: 16.0,
+                font_smoothing: FontSmoothing::None,
+            },
+            TextColor(lavender),
+        ));
+    });
+}
+
 /// Cleanup menu screen
 pub fn cleanup_menu(mut commands: Commands, query: Query<Entity, With<MenuUI>>) {
     for entity in &query {
@@ -265,6 +312,7 @@ pub fn cleanup_menu(mut commands: Commands, query: Query<Entity, With<MenuUI>>)
 }
 
 /// Setup game over screen
+#[allow(clippy::too_many_arguments)]
 pub fn setup_game_over(
     mut commands: Commands,
     score: Res<Score>,
@@ -273,6 +321,9 @@ pub fn setup_game_over(
     asset_server: Res<AssetServer>,
     mut images: ResMut<Assets<Image>>,
     test_play: Option<Res<TestPlayMode>>,
+    time_attack: Option<Res<TimeAttackMode>>,
+    level_stats: Res<LevelStats>,
+    bindings: Res<KeyBindings>,
 ) {
     let salmon = Color::srgb(0.92, 0.44, 0.44);
     let cream = Color::srgb(0.95, 0.85, 0.65);
@@ -298,8 +349,11 @@ pub fn setup_game_over(
         ))
         .with_children(|parent| {
             // Title
+            let time_up = time_attack
+                .as_ref()
+                .is_some_and(|mode| mode.remaining(&level_stats) <= 0.0);
             parent.spawn((
-                Text::new("ゲームオーバー"),
+                Text::new(if time_up { "タイムアップ！" } else { "ゲームオーバー" }),
                 TextFont {
                     font: font_handle.clone(),
                     font_size: 48.0,
@@ -344,8 +398,14 @@ pub fn setup_game_over(
             }
 
             // Ranking title
+            let ranking_title = match high_scores.table {
+                ScoreTable::Normal => "ランキング",
+                ScoreTable::TimeAttack => "タイムアタック ランキング",
+                ScoreTable::Endless => "エンドレス ランキング",
+                ScoreTable::Coop => "協力 ランキング",
+            };
             parent.spawn((
-                Text::new("ランキング"),
+                Text::new(ranking_title),
                 TextFont {
                     font: font_handle.clone(),
                     font_size: 24.0,
@@ -401,12 +461,12 @@ pub fn setup_game_over(
 
             // Restart instruction
             let restart_text = if test_play.is_some() {
-                "SPACE / タップ でエディタに戻る"
+                "エディタに戻る"
             } else {
-                "SPACE / タップ でリトライ"
+                "リトライ"
             };
             parent.spawn((
-                Text::new(restart_text),
+                Text::new(format!("{} / タップ で{}", bindings.primary_label(InputAction::Confirm), restart_text)),
                 TextFont {
                     font: font_handle,
                     font_size: 16.0,
@@ -426,6 +486,7 @@ pub fn cleanup_game_over(mut commands: Commands, query: Query<Entity, With<GameO
 }
 
 /// Setup level clear screen
+#[allow(clippy::too_many_arguments)]
 pub fn setup_level_clear(
     mut commands: Commands,
     score: Res<Score>,
@@ -433,6 +494,11 @@ pub fn setup_level_clear(
     level_stats: Res<LevelStats>,
     asset_server: Res<AssetServer>,
     test_play: Option<Res<TestPlayMode>>,
+    time_attack: Option<Res<TimeAttackMode>>,
+    daily: Option<Res<DailyChallenge>>,
+    coop: Option<Res<CoopMode>>,
+    puzzle: Option<Res<PuzzleMode>>,
+    bindings: Res<KeyBindings>,
 ) {
     let soft_green = Color::srgb(0.40, 0.80, 0.52);
     let cream = Color::srgb(0.95, 0.85, 0.65);
@@ -462,6 +528,10 @@ pub fn setup_level_clear(
             // Title
             let clear_title = if test_play.is_some() {
                 "ステージクリア！".to_string()
+            } else if daily.is_some() {
+                "デイリー クリア！".to_string()
+            } else if let Some(mode) = &puzzle {
+                format!("パズル {} クリア！", mode.stage + 1)
             } else {
                 format!("レベル {} クリア！", level.current)
             };
@@ -488,15 +558,23 @@ pub fn setup_level_clear(
                 TextLayout::new_with_justify(JustifyText::Center),
             ));
 
-            // Level statistics
+            // Level statistics (the word bonus only when it was spelled)
+            let word_bonus = if level_stats.word_bonus > 0 {
+                format!("  ワードボーナス {}", level_stats.word_bonus)
+            } else {
+                String::new()
+            };
             parent.spawn((
                 Text::new(format!(
-                    "破壊ブロック {}  最大コンボ x{}\nクリアタイム {}:{:02}  獲得スコア {}",
+                    "破壊ブロック {}  最大コンボ x{}\nクリアタイム {}:{:02}  獲得スコア {}\nコイン {}  コインボーナス {}{}",
                     level_stats.blocks_destroyed,
                     level_stats.max_combo,
                     time_min,
                     time_sec,
                     level_score,
+                    level_stats.coins_collected,
+                    level_stats.coin_score,
+                    word_bonus,
                 )),
                 TextFont {
                     font: font_handle.clone(),
@@ -507,14 +585,72 @@ pub fn setup_level_clear(
                 TextLayout::new_with_justify(JustifyText::Center),
             ));
 
+            // Co-op: each player's share of the level score
+            if coop.is_some() {
+                parent.spawn((
+                    Text::new(format!(
+                        "P1 {}  P2 {}",
+                        level_stats.player_scores[0], level_stats.player_scores[1]
+                    )),
+                    TextFont {
+                        font: font_handle.clone(),
+                        font_size: 20.0,
+                        font_smoothing: FontSmoothing::None,
+                    },
+                    TextColor(cream),
+                    TextLayout::new_with_justify(JustifyText::Center),
+                ));
+            }
+
+            // Puzzle: stars for the shots used
+            if let Some(mode) = &puzzle {
+                parent.spawn((
+                    Text::new(format!(
+                        "{}  ショット {} / {}",
+                        star_marks(mode.last_stars),
+                        mode.shots_used,
+                        PUZZLE_STAGES[mode.stage].shots
+                    )),
+                    TextFont {
+                        font: font_handle.clone(),
+                        font_size: 24.0,
+                        font_smoothing: FontSmoothing::None,
+                    },
+                    TextColor(Color::srgb(1.0, 0.85, 0.20)), // Gold
+                    TextLayout::new_with_justify(JustifyText::Center),
+                ));
+            }
+
+            // Time attack: bonus seconds carried into the next level
+            if let Some(mode) = &time_attack {
+                parent.spawn((
+                    Text::new(format!(
+                        "タイムボーナス +{:.0} 秒  残り {}",
+                        mode.last_bonus,
+                        crate::systems::time_attack::format_clock(mode.level_budget),
+                    )),
+                    TextFont {
+                        font: font_handle.clone(),
+                        font_size: 20.0,
+                        font_smoothing: FontSmoothing::None,
+                    },
+                    TextColor(cream),
+                    TextLayout::new_with_justify(JustifyText::Center),
+                ));
+            }
+
             // Next level instruction
             let next_text = if test_play.is_some() {
-                "SPACE / タップ でエディタに戻る"
+                "エディタに戻る"
+            } else if daily.is_some()
+                || puzzle.as_ref().is_some_and(|mode| mode.stage + 1 >= PUZZLE_STAGE_COUNT)
+            {
+                "結果へ"
             } else {
-                "SPACE / タップ で次のレベルへ"
+                "次のレベルへ"
             };
             parent.spawn((
-                Text::new(next_text),
+                Text::new(format!("{} / タップ で{}", bindings.primary_label(InputAction::Confirm), next_text)),
                 TextFont {
                     font: font_handle,
                     font_size: 16.0,
@@ -539,12 +675,17 @@ pub fn advance_level(mut level: ResMut<Level>) {
 }
 
 /// Reset game state when returning to menu
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn reset_game(
     mut commands: Commands,
     mut score: ResMut<Score>,
     mut level: ResMut<Level>,
     mut combo: ResMut<ComboTracker>,
     mut level_stats: ResMut<LevelStats>,
+    mut lives: ResMut<Lives>,
+    mut letters: ResMut<BonusLetters>,
+    mut high_scores: ResMut<HighScores>,
+    mut ramp: ResMut<SpeedRamp>,
     game_entities: Query<
         Entity,
         Or<(
@@ -552,11 +693,29 @@ pub fn reset_game(
             With<Block>,
             With<Paddle>,
             With<Wall>,
+            With<PowerUp>,
+            With<Pickup>,
+            With<Laser>,
+            With<Fog>,
+            With<Barrier>,
+            With<ComboPopup>,
+            With<DangerLine>,
+            With<FieldZone>,
+        )>,
+    >,
+    hud_entities: Query<
+        Entity,
+        Or<(
             With<ScoreText>,
             With<LevelText>,
             With<HighScoreText>,
-            With<PowerUp>,
-            With<ComboPopup>,
+            With<LivesText>,
+            With<PowerUpHud>,
+            With<BonusWordText>,
+            With<TimeAttackText>,
+            With<VersusStatusText>,
+            With<PuzzleShotsText>,
+            With<PracticePanel>,
             With<PauseButton>,
         )>,
     >,
@@ -571,24 +730,56 @@ pub fn reset_game(
 
     // Reset resources
     score.value = 0;
-    level.current = 1;
+    *level = Level::default();
+    *ramp = SpeedRamp::default();
     combo.count = 0;
     combo.timer.reset();
     *level_stats = LevelStats::default();
+    *lives = Lives::default();
+    *letters = BonusLetters::default();
+
+    // Leave any special mode and restore the normal ranking
+    commands.remove_resource::<TimeAttackMode>();
+    commands.remove_resource::<EndlessMode>();
+    commands.remove_resource::<DailyChallenge>();
+    commands.remove_resource::<CoopMode>();
+    commands.remove_resource::<VersusMode>();
+    commands.remove_resource::<PuzzleMode>();
+    commands.remove_resource::<PracticeMode>();
+    commands.insert_resource(DropRng::default());
+    if high_scores.table != ScoreTable::Normal {
+        *high_scores = HighScores::load(ScoreTable::Normal);
+    }
 
-    // Despawn game entities
-    for entity in &game_entities {
-        commands.entity(entity).despawn();
+    // Despawn game entities and HUD (letter tiles and the power-up HUD own child entities)
+    for entity in game_entities.iter().chain(&hud_entities) {
+        commands.entity(entity).despawn_recursive();
     }
 }
 
-/// Cleanup for next level (remove ball, paddle, power-ups, and combo popups)
+/// Cleanup for next level (remove ball, paddle, power-ups, pickups, lasers, fog, barriers, and combo popups)
+#[allow(clippy::type_complexity)]
 pub fn cleanup_for_next_level(
     mut commands: Commands,
-    entities: Query<Entity, Or<(With<Ball>, With<Paddle>, With<PowerUp>, With<ComboPopup>, With<Block>)>>,
+    entities: Query<
+        Entity,
+        Or<(
+            With<Ball>,
+            With<Paddle>,
+            With<PowerUp>,
+            With<Pickup>,
+            With<Laser>,
+            With<Fog>,
+            With<Barrier>,
+            With<ComboPopup>,
+            With<Block>,
+            With<FieldZone>,
+        )>,
+    >,
     mut paddle_query: Query<(Entity, &mut Sprite, &mut Collider), With<Paddle>>,
     mut combo: ResMut<ComboTracker>,
     mut level_stats: ResMut<LevelStats>,
+    mut ramp: ResMut<SpeedRamp>,
 ) {
     // Reset paddle size if power-up was active
     for (paddle_entity, mut sprite, mut collider) in &mut paddle_query {
@@ -601,11 +792,77 @@ pub fn cleanup_for_next_level(
     combo.count = 0;
     combo.timer.reset();
 
-    // Reset level stats (will be re-initialized on level start)
+    // Reset level stats (will be re-initialized on level start) and the in-level speed-up
     *level_stats = LevelStats::default();
+    *ramp = SpeedRamp::default();
 
     for entity in &entities {
-        commands.entity(entity).despawn();
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Every persisted setting the settings screen edits
+#[derive(SystemParam)]
+pub struct SettingsValues<'w> {
+    audio: ResMut<'w, AudioSettings>,
+    stick: ResMut<'w, StickSettings>,
+    mouse: ResMut<'w, MouseSettings>,
+    game: ResMut<'w, GameSettings>,
+}
+
+impl SettingsValues<'_> {
+    /// Value shown for an option row (None for the action row)
+    fn text(&self, option: SettingsOption) -> Option<String> {
+        let on_off = |on: bool| if on { "ON" } else { "OFF" }.to_string();
+        let percent = |value: f32| format!("{:>3}%", (value * 100.0).round() as u32);
+        Some(match option {
+            SettingsOption::BgmVolume => format!("{:>3}%", AudioSettings::volume_percent(self.audio.bgm_volume)),
+            SettingsOption::SfxVolume => format!("{:>3}%", AudioSettings::volume_percent(self.audio.sfx_volume)),
+            SettingsOption::ScreenShake => percent(self.game.shake),
+            SettingsOption::Particles => percent(self.game.particles),
+            SettingsOption::BallTrail => on_off(self.game.ball_trail),
+            SettingsOption::PaddleSpeed => format!("x{:.1}", self.game.paddle_speed),
+            SettingsOption::HighContrast => on_off(self.game.high_contrast),
+            SettingsOption::BlockPalette => self.game.palette.label().to_string(),
+            SettingsOption::BlockGlyphs => on_off(self.game.glyphs),
+            SettingsOption::ReducedFlashing => on_off(self.game.reduced_flashing),
+            SettingsOption::StickDeadzone => percent(self.stick.deadzone),
+            SettingsOption::StickAcceleration => format!("{:.1}秒", self.stick.acceleration),
+            SettingsOption::MouseMode => self.mouse.mode.label().to_string(),
+            SettingsOption::MouseSensitivity => format!("{:.2}", self.mouse.sensitivity),
+            SettingsOption::Controls => return None,
+        })
+    }
+
+    /// Step an option by `steps` (toggles flip either way) and save the settings it belongs to
+    fn step(&mut self, option: SettingsOption, steps: i32) {
+        let audio = &mut self.audio;
+        match option {
+            SettingsOption::BgmVolume if steps < 0 => audio.bgm_volume = AudioSettings::step_down(audio.bgm_volume),
+            SettingsOption::BgmVolume => audio.bgm_volume = AudioSettings::step_up(audio.bgm_volume),
+            SettingsOption::SfxVolume if steps < 0 => audio.sfx_volume = AudioSettings::step_down(audio.sfx_volume),
+            SettingsOption::SfxVolume => audio.sfx_volume = AudioSettings::step_up(audio.sfx_volume),
+            SettingsOption::ScreenShake => self.game.step_shake(steps as f32),
+            SettingsOption::Particles => self.game.step_particles(steps as f32),
+            SettingsOption::BallTrail => self.game.ball_trail = !self.game.ball_trail,
+            SettingsOption::PaddleSpeed => self.game.step_paddle_speed(steps as f32),
+            SettingsOption::HighContrast => self.game.high_contrast = !self.game.high_contrast,
+            SettingsOption::BlockPalette => self.game.step_palette(steps),
+            SettingsOption::BlockGlyphs => self.game.glyphs = !self.game.glyphs,
+            SettingsOption::ReducedFlashing => self.game.reduced_flashing = !self.game.reduced_flashing,
+            SettingsOption::StickDeadzone => self.stick.step_deadzone(steps as f32),
+            SettingsOption::StickAcceleration => self.stick.step_acceleration(steps as f32),
+            SettingsOption::MouseMode => self.mouse.step_mode(steps),
+            SettingsOption::MouseSensitivity => self.mouse.step_sensitivity(steps as f32),
+            SettingsOption::Controls => return,
+        }
+
+        match option {
+            SettingsOption::BgmVolume | SettingsOption::SfxVolume => self.audio.save(),
+            SettingsOption::StickDeadzone | SettingsOption::StickAcceleration => self.stick.save(),
+            SettingsOption::MouseMode | SettingsOption::MouseSensitivity => self.mouse.save(),
+            _ => self.game.save(),
+        }
     }
 }
 
@@ -613,13 +870,20 @@ pub fn cleanup_for_next_level(
 pub fn setup_settings(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    audio_settings: Res<AudioSettings>,
+    bindings: Res<KeyBindings>,
+    values: SettingsValues,
 ) {
     let warm_white = Color::srgb(1.0, 0.96, 0.88);
     let lavender = Color::srgb(0.55, 0.50, 0.65);
     let font_handle: Handle<Font> = asset_server.load(GAME_FONT_PATH);
 
-    commands.init_resource::<crate::components::SettingsSelection>();
+    let rows = SettingsOption::ALL
+        .into_iter()
+        .map(|option| OptionListRow {
+            label: option.label().to_string(),
+            value: values.text(option),
+        })
+        .collect();
 
     commands
         .spawn((
@@ -629,7 +893,7 @@ pub fn setup_settings(
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
                 flex_direction: FlexDirection::Column,
-                row_gap: Val::Px(20.0),
+                row_gap: Val::Px(12.0),
                 ..default()
             },
             BackgroundColor(Color::srgba(0.05, 0.05, 0.12, 0.88)),
@@ -648,17 +912,8 @@ pub fn setup_settings(
                 TextLayout::new_with_justify(JustifyText::Center),
             ));
 
-            // BGM volume row
-            spawn_volume_row(
-                parent, &font_handle, "BGM", audio_settings.bgm_volume,
-                true, VolumeButton::BgmDown, VolumeButton::BgmUp, SettingsBgmText,
-            );
-
-            // 効果音 volume row
-            spawn_volume_row(
-                parent, &font_handle, "効果音", audio_settings.sfx_volume,
-                false, VolumeButton::SfxDown, VolumeButton::SfxUp, SettingsSfxText,
-            );
+            // Volume, gameplay, accessibility, gamepad and mouse rows, then the key binding row
+            spawn_option_list(parent, &font_handle, rows);
 
             // Separator
             parent.spawn((
@@ -673,12 +928,16 @@ pub fn setup_settings(
 
             // Controls help
             parent.spawn((
-                Text::new(
+                Text::new(format!(
                     "操作方法\n\
-                     SPACE / タップ  ゲーム開始\n\
-                     ← → / A D  パドル移動\n\
-                     ESC  一時停止 / 再開",
-                ),
+                     {} / タップ  ゲーム開始\n\
+                     {}  {}  パドル移動\n\
+                     {}  一時停止 / 再開",
+                    bindings.label(InputAction::Confirm),
+                    bindings.label(InputAction::MoveLeft),
+                    bindings.label(InputAction::MoveRight),
+                    bindings.label(InputAction::Pause),
+                )),
                 TextFont {
                     font: font_handle.clone(),
                     font_size: 16.0,
@@ -690,7 +949,7 @@ pub fn setup_settings(
 
             // Back instruction
             parent.spawn((
-                Text::new("ESC / タップ で戻る"),
+                Text::new(format!("{} / タップ で戻る", bindings.primary_label(InputAction::Back))),
                 TextFont {
                     font: font_handle,
                     font_size: 16.0,
@@ -707,7 +966,6 @@ pub fn cleanup_settings(mut commands: Commands, query: Query<Entity, With<Settin
     for entity in &query {
         commands.entity(entity).despawn_recursive();
     }
-    commands.remove_resource::<crate::components::SettingsSelection>();
 }
 
 /// Setup pause overlay (top-aligned settings panel)
@@ -715,6 +973,7 @@ pub fn setup_pause(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     audio_settings: Res<AudioSettings>,
+    bindings: Res<KeyBindings>,
 ) {
     let warm_white = Color::srgb(1.0, 0.96, 0.88);
     let lavender = Color::srgb(0.55, 0.50, 0.65);
@@ -764,7 +1023,7 @@ pub fn setup_pause(
 
             // Resume instruction
             parent.spawn((
-                Text::new("ESC / タップ で再開"),
+                Text::new(format!("{} / タップ で再開", bindings.primary_label(InputAction::Back))),
                 TextFont {
                     font: font_handle,
                     font_size: 16.0,
@@ -785,8 +1044,9 @@ pub fn cleanup_pause(mut commands: Commands, query: Query<Entity, With<PauseUI>>
 }
 
 /// Handle input on pause overlay (volume adjust + resume)
+#[allow(clippy::too_many_arguments)]
 pub fn pause_overlay_input(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    actions: ActionInput,
     touches: Res<Touches>,
     mut next_state: ResMut<NextState<GameState>>,
     mut audio_settings: ResMut<AudioSettings>,
@@ -800,23 +1060,23 @@ pub fn pause_overlay_input(
     let gold = Color::srgb(1.0, 0.85, 0.20);
 
     // Navigate up/down
-    if keyboard.just_pressed(KeyCode::ArrowUp) || keyboard.just_pressed(KeyCode::KeyW) {
+    if actions.just_pressed(InputAction::MenuUp) {
         selection.index = selection.index.saturating_sub(1);
     }
-    if keyboard.just_pressed(KeyCode::ArrowDown) || keyboard.just_pressed(KeyCode::KeyS) {
+    if actions.just_pressed(InputAction::MenuDown) {
         selection.index = (selection.index + 1).min(1);
     }
 
     // Adjust volume via keyboard
     let mut changed = false;
-    if keyboard.just_pressed(KeyCode::ArrowLeft) || keyboard.just_pressed(KeyCode::KeyA) {
+    if actions.just_pressed(InputAction::MoveLeft) {
         match selection.index {
             0 => audio_settings.bgm_volume = AudioSettings::step_down(audio_settings.bgm_volume),
             _ => audio_settings.sfx_volume = AudioSettings::step_down(audio_settings.sfx_volume),
         }
         changed = true;
     }
-    if keyboard.just_pressed(KeyCode::ArrowRight) || keyboard.just_pressed(KeyCode::KeyD) {
+    if actions.just_pressed(InputAction::MoveRight) {
         match selection.index {
             0 => audio_settings.bgm_volume = AudioSettings::step_up(audio_settings.bgm_volume),
             _ => audio_settings.sfx_volume = AudioSettings::step_up(audio_settings.sfx_volume),
@@ -859,18 +1119,17 @@ pub fn pause_overlay_input(
     }
 
     // Update cursor visibility
-    let mut cursor_idx = 0;
-    for mut color in &mut cursors {
+    for (cursor_idx, mut color) in cursors.iter_mut().enumerate() {
         if cursor_idx == selection.index {
             *color = TextColor(gold);
         } else {
             *color = TextColor(Color::NONE);
         }
-        cursor_idx += 1;
     }
 
-    // Resume: ESC or tap outside buttons
-    if keyboard.just_pressed(KeyCode::Escape)
+    // Resume: ESC / START or tap outside buttons
+    if actions.just_pressed(InputAction::Pause)
+        || actions.just_pressed(InputAction::Back)
         || (!changed && !any_btn_pressed && touches.any_just_pressed())
     {
         next_state.set(GameState::Playing);
@@ -878,93 +1137,92 @@ pub fn pause_overlay_input(
 }
 
 /// Handle input on settings screen
+#[allow(clippy::too_many_arguments)]
 pub fn settings_input(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    actions: ActionInput,
     touches: Res<Touches>,
     mut next_state: ResMut<NextState<GameState>>,
-    mut audio_settings: ResMut<AudioSettings>,
-    mut selection: ResMut<crate::components::SettingsSelection>,
-    mut bgm_text: Query<&mut Text, (With<SettingsBgmText>, Without<SettingsSfxText>)>,
-    mut sfx_text: Query<&mut Text, (With<SettingsSfxText>, Without<SettingsBgmText>)>,
-    mut cursors: Query<&mut TextColor, With<SettingsCursor>>,
+    mut values: SettingsValues,
+    mut lists: Query<&mut OptionList>,
+    mut value_texts: Query<(&mut Text, &OptionValue)>,
     bgm_sink: Query<&AudioSink, With<BgmMusic>>,
-    volume_btns: Query<(&Interaction, &VolumeButton), Changed<Interaction>>,
+    step_btns: Query<(&Interaction, &OptionStep), Changed<Interaction>>,
+    activate_btns: Query<(&Interaction, &OptionActivate), Changed<Interaction>>,
+    scroll_btns: Query<(&Interaction, &OptionScroll), Changed<Interaction>>,
 ) {
-    let gold = Color::srgb(1.0, 0.85, 0.20);
-
-    // Navigate up/down
-    if keyboard.just_pressed(KeyCode::ArrowUp) || keyboard.just_pressed(KeyCode::KeyW) {
-        selection.index = selection.index.saturating_sub(1);
+    let Ok(mut list) = lists.get_single_mut() else {
+        return;
+    };
+    let pressed = |interaction: &Interaction| *interaction == Interaction::Pressed;
+
+    // Navigate with up / down or the ▲ ▼ hints
+    let mut moves = 0;
+    if actions.just_pressed(InputAction::MenuUp) {
+        moves -= 1;
     }
-    if keyboard.just_pressed(KeyCode::ArrowDown) || keyboard.just_pressed(KeyCode::KeyS) {
-        selection.index = (selection.index + 1).min(1);
+    if actions.just_pressed(InputAction::MenuDown) {
+        moves += 1;
     }
-
-    // Adjust volume via keyboard
-    let mut changed = false;
-    if keyboard.just_pressed(KeyCode::ArrowLeft) || keyboard.just_pressed(KeyCode::KeyA) {
-        match selection.index {
-            0 => audio_settings.bgm_volume = AudioSettings::step_down(audio_settings.bgm_volume),
-            _ => audio_settings.sfx_volume = AudioSettings::step_down(audio_settings.sfx_volume),
-        }
-        changed = true;
-    }
-    if keyboard.just_pressed(KeyCode::ArrowRight) || keyboard.just_pressed(KeyCode::KeyD) {
-        match selection.index {
-            0 => audio_settings.bgm_volume = AudioSettings::step_up(audio_settings.bgm_volume),
-            _ => audio_settings.sfx_volume = AudioSettings::step_up(audio_settings.sfx_volume),
-        }
-        changed = true;
+    let scroll_tapped = scroll_btns.iter().any(|(interaction, _)| pressed(interaction));
+    moves += scroll_btns
+        .iter()
+        .filter(|(interaction, _)| pressed(interaction))
+        .map(|(_, scroll)| scroll.0)
+        .sum::<i32>();
+    if moves != 0 {
+        list.select_by(moves);
     }
 
-    // Adjust volume via tappable buttons (Changed filter = fire once per tap)
-    let mut any_btn_pressed = false;
-    for (interaction, btn) in &volume_btns {
-        if *interaction == Interaction::Pressed {
-            any_btn_pressed = true;
-            match btn {
-                VolumeButton::BgmDown => audio_settings.bgm_volume = AudioSettings::step_down(audio_settings.bgm_volume),
-                VolumeButton::BgmUp => audio_settings.bgm_volume = AudioSettings::step_up(audio_settings.bgm_volume),
-                VolumeButton::SfxDown => audio_settings.sfx_volume = AudioSettings::step_down(audio_settings.sfx_volume),
-                VolumeButton::SfxUp => audio_settings.sfx_volume = AudioSettings::step_up(audio_settings.sfx_volume),
-            }
-            changed = true;
-        }
+    // Open the key binding screen
+    let activated = activate_btns
+        .iter()
+        .find(|(interaction, _)| pressed(interaction))
+        .map(|(_, activate)| activate.0)
+        .or_else(|| actions.just_pressed(InputAction::Confirm).then_some(list.selected));
+    if activated.is_some_and(|row| SettingsOption::ALL[row] == SettingsOption::Controls) {
+        next_state.set(GameState::Controls);
+        return;
     }
 
-    if changed {
-        if let Ok(mut text) = bgm_text.get_single_mut() {
-            **text = format!(
-                "{:>3}%",
-                AudioSettings::volume_percent(audio_settings.bgm_volume)
-            );
-        }
-        if let Ok(mut text) = sfx_text.get_single_mut() {
-            **text = format!(
-                "{:>3}%",
-                AudioSettings::volume_percent(audio_settings.sfx_volume)
-            );
-        }
-        for sink in bgm_sink.iter() {
-            sink.set_volume(audio_settings.bgm_volume);
-        }
-        audio_settings.save();
+    // Step the selected row with left / right, or any row with its < > arrows
+    let mut steps: Vec<(usize, i32)> = step_btns
+        .iter()
+        .filter(|(interaction, _)| pressed(interaction))
+        .map(|(_, step)| (step.row, step.step))
+        .collect();
+    let step_tapped = !steps.is_empty();
+    match (
+        actions.just_pressed(InputAction::MoveLeft),
+        actions.just_pressed(InputAction::MoveRight),
+    ) {
+        (true, false) => steps.push((list.selected, -1)),
+        (false, true) => steps.push((list.selected, 1)),
+        _ => {}
+    }
+    if step_tapped {
+        list.selected = steps[0].0;
     }
 
-    // Update cursor visibility
-    let mut cursor_idx = 0;
-    for mut color in &mut cursors {
-        if cursor_idx == selection.index {
-            *color = TextColor(gold);
-        } else {
-            *color = TextColor(Color::NONE);
+    for (row, step) in steps {
+        let option = SettingsOption::ALL[row];
+        values.step(option, step);
+        if option == SettingsOption::BgmVolume {
+            for sink in &bgm_sink {
+                sink.set_volume(values.audio.bgm_volume);
+            }
+        }
+        for (mut text, value) in &mut value_texts {
+            if value.0 == row {
+                if let Some(value_text) = values.text(option) {
+                    **text = value_text;
+                }
+            }
         }
-        cursor_idx += 1;
     }
 
-    // Back to menu (skip if volume button was tapped)
-    if keyboard.just_pressed(KeyCode::Escape)
-        || (!changed && !any_btn_pressed && touches.any_just_pressed())
+    // Back to menu (taps on the list's buttons stay on the screen)
+    if actions.just_pressed(InputAction::Back)
+        || (!scroll_tapped && !step_tapped && touches.any_just_pressed())
     {
         next_state.set(GameState::Menu);
     }
@@ -993,6 +1251,7 @@ mod tests {
         app.world_mut().resource_mut::<Level>().current = 5;
         app.world_mut().resource_mut::<ComboTracker>().count = 3;
         app.world_mut().resource_mut::<LevelStats>().blocks_destroyed = 10;
+        app.world_mut().resource_mut::<Lives>().spare = 2;
 
         app.add_systems(Update, reset_game);
         app.update();
@@ -1005,6 +1264,7 @@ mod tests {
         assert_eq!(level.current, 1);
         assert_eq!(combo.count, 0);
         assert_eq!(level_stats.blocks_destroyed, 0);
+        assert_eq!(app.world().resource::<Lives>().spare, 0);
     }
 
     #[test]
@@ -1039,6 +1299,124 @@ mod tests {
         assert_eq!(paddle_count, 0);
     }
 
+    #[test]
+    fn reset_game_leaves_time_attack() {
+        let mut app = test_app();
+        app.insert_resource(TimeAttackMode::default());
+
+        app.add_systems(Update, reset_game);
+        app.update();
+
+        assert!(!app.world().contains_resource::<TimeAttackMode>());
+        assert_eq!(app.world().resource::<HighScores>().table, ScoreTable::Normal);
+    }
+
+    #[test]
+    fn reset_game_leaves_daily_challenge() {
+        let mut app = test_app();
+        app.insert_resource(DailyChallenge {
+            day: 20_744,
+            cleared_time: None,
+        });
+        app.insert_resource(DropRng::seeded(1));
+
+        app.add_systems(Update, reset_game);
+        app.update();
+
+        assert!(!app.world().contains_resource::<DailyChallenge>());
+        assert_eq!(*app.world().resource::<DropRng>(), DropRng::default(), "Drops are no longer seeded");
+    }
+
+    #[test]
+    fn reset_game_leaves_endless() {
+        let mut app = test_app();
+        app.insert_resource(EndlessMode::default());
+        app.insert_resource(HighScores {
+            scores: [0; 3],
+            table: ScoreTable::Endless,
+        });
+        let line = app.world_mut().spawn(DangerLine).id();
+
+        app.add_systems(Update, reset_game);
+        app.update();
+
+        assert!(!app.world().contains_resource::<EndlessMode>());
+        assert_eq!(app.world().resource::<HighScores>().table, ScoreTable::Normal);
+        assert!(app.world().get_entity(line).is_err());
+    }
+
+    #[test]
+    fn reset_game_leaves_coop() {
+        let mut app = test_app();
+        app.insert_resource(CoopMode);
+        app.insert_resource(HighScores {
+            scores: [0; 3],
+            table: ScoreTable::Coop,
+        });
+
+        app.add_systems(Update, reset_game);
+        app.update();
+
+        assert!(!app.world().contains_resource::<CoopMode>());
+        assert_eq!(app.world().resource::<HighScores>().table, ScoreTable::Normal);
+    }
+
+    #[test]
+    fn reset_game_leaves_puzzle_and_restores_drops() {
+        let mut app = test_app();
+        app.insert_resource(PuzzleMode::default());
+        app.insert_resource(DropRng::disabled());
+
+        app.add_systems(Update, reset_game);
+        app.update();
+
+        assert!(!app.world().contains_resource::<PuzzleMode>());
+        assert!(app.world_mut().resource_mut::<DropRng>().roll() < 1.0, "Drops are back on");
+    }
+
+    #[test]
+    fn reset_game_despawns_powerup_hud_with_entries() {
+        let mut app = test_app();
+        let hud = app
+            .world_mut()
+            .spawn((Node::default(), PowerUpHud { font: Handle::default(), player: 0 }))
+            .id();
+        let row = app
+            .world_mut()
+            .spawn((
+                Node::default(),
+                PowerUpHudRow {
+                    effect_type: PowerUpType::WidePaddle,
+                    player: 0,
+                },
+            ))
+            .set_parent(hud)
+            .id();
+
+        app.add_systems(Update, reset_game);
+        app.update();
+
+        assert!(app.world().get_entity(hud).is_err());
+        assert!(app.world().get_entity(row).is_err(), "HUD entries go with the strip");
+    }
+
+    #[test]
+    fn cleanup_next_level_despawns_barrier() {
+        let mut app = test_app();
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, BARRIER_Y, 0.0),
+            Barrier {
+                timer: Timer::from_seconds(BARRIER_DURATION, TimerMode::Once),
+            },
+        ));
+
+        app.add_systems(Update, cleanup_for_next_level);
+        app.update();
+
+        let barrier_count = app.world_mut().query::<&Barrier>().iter(app.world()).count();
+        assert_eq!(barrier_count, 0);
+    }
+
     #[test]
     fn cleanup_next_level_despawns_ball_paddle_block() {
         let mut app = test_app();
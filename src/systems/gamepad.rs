@@ -0,0 +1,220 @@
+use bevy::input::gamepad::{GamepadConnection, GamepadConnectionEvent};
+use bevy::prelude::*;
+use bevy::text::FontSmoothing;
+
+use crate::components::*;
+use crate::constants::*;
+use crate::resources::*;
+use crate::states::GameState;
+use crate::systems::input::ActionInput;
+
+/// Move the menu focus with the gamepad and press the focused button with Confirm.
+/// Buttons are visited in reading order; a pressed button is released again next frame.
+pub fn menu_gamepad_focus(
+    actions: ActionInput,
+    mut focus: Local<Option<usize>>,
+    mut pressed: Local<Option<Entity>>,
+    mut buttons: Query<(Entity, &GlobalTransform, &mut Interaction, &mut BackgroundColor), With<MenuFocusable>>,
+) {
+    if let Some(entity) = pressed.take() {
+        if let Ok((_, _, mut interaction, _)) = buttons.get_mut(entity) {
+            if *interaction == Interaction::Pressed {
+                *interaction = Interaction::None;
+            }
+        }
+    }
+
+    let mut order: Vec<(Entity, Vec2)> = buttons
+        .iter()
+        .map(|(entity, transform, _, _)| (entity, transform.translation().truncate().round()))
+        .collect();
+    if order.is_empty() {
+        return;
+    }
+    order.sort_by(|a, b| a.1.y.total_cmp(&b.1.y).then(a.1.x.total_cmp(&b.1.x)));
+
+    let step = if actions.pad_just_pressed(InputAction::MoveRight) || actions.pad_just_pressed(InputAction::MenuDown) {
+        1
+    } else if actions.pad_just_pressed(InputAction::MoveLeft) || actions.pad_just_pressed(InputAction::MenuUp) {
+        -1
+    } else {
+        0
+    };
+    let count = order.len() as isize;
+    if step != 0 {
+        *focus = Some(focus.map_or(0, |index| (index as isize + step).rem_euclid(count) as usize));
+    }
+    let focused = focus.filter(|index| *index < order.len());
+    let activate = actions.pad_just_pressed(InputAction::Confirm);
+
+    for (index, (entity, _)) in order.iter().enumerate() {
+        let Ok((_, _, mut interaction, mut background)) = buttons.get_mut(*entity) else {
+            continue;
+        };
+        let is_focused = focused == Some(index);
+        let color = if is_focused {
+            Color::srgba(1.0, 0.85, 0.20, 0.18)
+        } else {
+            Color::NONE
+        };
+        if background.0 != color {
+            background.0 = color;
+        }
+        if is_focused && activate {
+            *interaction = Interaction::Pressed;
+            *pressed = Some(*entity);
+        }
+    }
+}
+
+/// Losing a gamepad mid-game pauses play
+pub fn pause_on_gamepad_loss(
+    mut events: EventReader<GamepadConnectionEvent>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let lost = events.read().any(|event| event.disconnected());
+    if lost && *state.get() == GameState::Playing {
+        next_state.set(GameState::Paused);
+    }
+}
+
+/// Announce gamepads being connected or removed
+pub fn show_gamepad_notice(
+    mut commands: Commands,
+    mut events: EventReader<GamepadConnectionEvent>,
+    asset_server: Res<AssetServer>,
+    notices: Query<Entity, With<GamepadNotice>>,
+) {
+    let Some(event) = events.read().last() else {
+        return;
+    };
+    let message = match &event.connection {
+        GamepadConnection::Connected { name, .. } => format!("コントローラーを接続しました（{}）", name),
+        GamepadConnection::Disconnected => "コントローラーが切断されました".to_string(),
+    };
+    for entity in &notices {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                top: Val::Px(48.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            GlobalZIndex(20),
+            GamepadNotice {
+                timer: Timer::from_seconds(GAMEPAD_NOTICE_SECS, TimerMode::Once),
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(message),
+                TextFont {
+                    font: asset_server.load(GAME_FONT_PATH),
+                    font_size: 16.0,
+                    font_smoothing: FontSmoothing::None,
+                },
+                TextColor(Color::srgb(0.95, 0.85, 0.65)),
+                Node {
+                    padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.05, 0.05, 0.12, 0.88)),
+            ));
+        });
+}
+
+/// Remove the gamepad notice once its time is up
+pub fn update_gamepad_notice(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut notices: Query<(Entity, &mut GamepadNotice)>,
+) {
+    for (entity, mut notice) in &mut notices {
+        if notice.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    fn pad_app() -> (App, Entity) {
+        let mut app = test_app();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        let pad = app.world_mut().spawn(Gamepad::default()).id();
+        (app, pad)
+    }
+
+    fn press_button(app: &mut App, pad: Entity, button: GamepadButton) {
+        let mut gamepad = app.world_mut().get_mut::<Gamepad>(pad).unwrap();
+        let digital = gamepad.digital_mut();
+        digital.release_all();
+        digital.clear();
+        digital.press(button);
+        app.update();
+    }
+
+    fn spawn_menu_button(app: &mut App, x: f32, y: f32) -> Entity {
+        app.world_mut()
+            .spawn((
+                Button,
+                Interaction::None,
+                BackgroundColor(Color::NONE),
+                GlobalTransform::from_xyz(x, y, 0.0),
+                MenuFocusable,
+            ))
+            .id()
+    }
+
+    #[test]
+    fn dpad_moves_focus_in_reading_order_and_confirm_presses() {
+        let (mut app, pad) = pad_app();
+        let second = spawn_menu_button(&mut app, 200.0, 100.0);
+        let first = spawn_menu_button(&mut app, 100.0, 100.0);
+        let third = spawn_menu_button(&mut app, 100.0, 200.0);
+        app.add_systems(Update, menu_gamepad_focus);
+
+        press_button(&mut app, pad, GamepadButton::DPadRight);
+        press_button(&mut app, pad, GamepadButton::DPadDown);
+        let focused = |app: &App, button: Entity| app.world().get::<BackgroundColor>(button).unwrap().0 != Color::NONE;
+        assert!(focused(&app, second));
+        assert!(!focused(&app, first) && !focused(&app, third));
+
+        press_button(&mut app, pad, GamepadButton::South);
+        assert_eq!(*app.world().get::<Interaction>(second).unwrap(), Interaction::Pressed);
+
+        // Released again on the next frame
+        press_button(&mut app, pad, GamepadButton::DPadUp);
+        assert_eq!(*app.world().get::<Interaction>(second).unwrap(), Interaction::None);
+        assert!(focused(&app, first));
+    }
+
+    #[test]
+    fn disconnect_during_play_pauses() {
+        let (mut app, pad) = pad_app();
+        app.add_event::<GamepadConnectionEvent>();
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+
+        app.world_mut()
+            .send_event(GamepadConnectionEvent::new(pad, GamepadConnection::Disconnected));
+        app.add_systems(Update, pause_on_gamepad_loss);
+        app.update();
+
+        assert!(matches!(
+            app.world().resource::<NextState<GameState>>(),
+            NextState::Pending(GameState::Paused)
+        ));
+    }
+}
@@ -1,63 +1,406 @@
+use bevy::ecs::system::SystemParam;
+use bevy::input::mouse::MouseMotion;
 use bevy::input::touch::Touches;
 use bevy::prelude::*;
+use bevy::window::{CursorGrabMode, PrimaryWindow};
 
 use crate::components::*;
 use crate::constants::*;
-use crate::resources::TestPlayMode;
+use crate::resources::{
+    CoopMode, DailyChallenge, DailyRecord, DropRng, EndlessMode, GameSettings, HighScores, InputAction,
+    KeyBindings, COOP_ACTIONS, MouseMode, MouseSettings, PracticeMode, PuzzleMode, ScoreTable, StickSettings,
+    TestPlayMode, TimeAttackMode, VersusMode,
+};
+use crate::systems::daily::{daily_drop_rng, today_utc};
 use crate::states::GameState;
 
-/// Handle paddle movement input
+/// Keyboard and every connected gamepad, read through the player's `KeyBindings`
+#[derive(SystemParam)]
+pub struct ActionInput<'w, 's> {
+    keyboard: Res<'w, ButtonInput<KeyCode>>,
+    bindings: Res<'w, KeyBindings>,
+    gamepads: Query<'w, 's, &'static Gamepad>,
+}
+
+impl ActionInput<'_, '_> {
+    pub fn pressed(&self, action: InputAction) -> bool {
+        self.bindings.pressed(&self.keyboard, action)
+            || self.gamepads.iter().any(|pad| self.bindings.pad_pressed(pad, action))
+    }
+
+    pub fn just_pressed(&self, action: InputAction) -> bool {
+        self.bindings.just_pressed(&self.keyboard, action) || self.pad_just_pressed(action)
+    }
+
+    /// Gamepad-only press (menu focus navigation, where keys are mode shortcuts instead)
+    pub fn pad_just_pressed(&self, action: InputAction) -> bool {
+        self.gamepads.iter().any(|pad| self.bindings.pad_just_pressed(pad, action))
+    }
+}
+
+/// Gamepads for a player: every pad drives a lone player, pad N drives player N with two players
+pub fn player_gamepads<'a>(
+    gamepads: impl IntoIterator<Item = (Entity, &'a Gamepad)>,
+    player: usize,
+    split: bool,
+) -> Vec<&'a Gamepad> {
+    let mut pads: Vec<(Entity, &Gamepad)> = gamepads.into_iter().collect();
+    // Connection order (entity order) decides who is player 1
+    pads.sort_by_key(|(entity, _)| *entity);
+    pads.into_iter()
+        .enumerate()
+        .filter(|(index, _)| !split || *index == player)
+        .map(|(_, (_, pad))| pad)
+        .collect()
+}
+
+/// Horizontal range for a paddle center (co-op paddles each keep to their half of the field)
+pub fn paddle_x_range(player: usize, coop: bool) -> (f32, f32) {
+    let half_paddle = PADDLE_WIDTH / 2.0;
+    let limit = WINDOW_WIDTH / 2.0 - WALL_THICKNESS - half_paddle;
+    match (coop, player) {
+        (false, _) => (-limit, limit),
+        (true, 0) => (-limit, -half_paddle),
+        (true, _) => (half_paddle, limit),
+    }
+}
+
+/// Co-op touch zone: the left half of the screen belongs to player 1, the right half to player 2
+pub fn touch_zone_player(x: f32, center_x: f32) -> usize {
+    if x < center_x { 0 } else { 1 }
+}
+
+/// Per-player launch/fire input: the Launch key, the Launch button, a click or any tap alone;
+/// each player's own key, gamepad or screen half in co-op and versus (the mouse belongs to 1P).
+/// Clicks on UI buttons (pause, practice panel) are left to the buttons. In puzzle mode a touch
+/// aims the serve, so lifting it launches instead.
+#[derive(SystemParam)]
+pub struct PlayerActions<'w, 's> {
+    keyboard: Res<'w, ButtonInput<KeyCode>>,
+    bindings: Res<'w, KeyBindings>,
+    touches: Res<'w, Touches>,
+    mouse_buttons: Res<'w, ButtonInput<MouseButton>>,
+    mouse: Res<'w, MouseSettings>,
+    coop: Option<Res<'w, CoopMode>>,
+    versus: Option<Res<'w, VersusMode>>,
+    puzzle: Option<Res<'w, PuzzleMode>>,
+    windows: Query<'w, 's, &'static Window>,
+    gamepads: Query<'w, 's, (Entity, &'static Gamepad)>,
+    interactions: Query<'w, 's, &'static Interaction>,
+}
+
+impl PlayerActions<'_, '_> {
+    pub fn just_pressed(&self, player: usize) -> bool {
+        let split = self.coop.is_some() || self.versus.is_some();
+        let pad_pressed = player_gamepads(&self.gamepads, player, split)
+            .into_iter()
+            .any(|pad| self.bindings.pad_just_pressed(pad, InputAction::Launch));
+        let clicked = player == 0
+            && self.mouse.enabled()
+            && self.mouse_buttons.just_pressed(MouseButton::Left)
+            && self.interactions.iter().all(|i| *i == Interaction::None);
+        if !split {
+            let tapped = if self.puzzle.is_some() {
+                self.touches.any_just_released()
+            } else {
+                self.touches.any_just_pressed()
+            };
+            return self.bindings.just_pressed(&self.keyboard, InputAction::Launch)
+                || pad_pressed
+                || clicked
+                || tapped;
+        }
+        let center_x = self.windows.get_single().map_or(WINDOW_WIDTH, |w| w.width()) / 2.0;
+        self.bindings.just_pressed(&self.keyboard, COOP_ACTIONS[player].launch)
+            || pad_pressed
+            || clicked
+            || self
+                .touches
+                .iter_just_pressed()
+                .any(|touch| touch_zone_player(touch.position().x, center_x) == player)
+    }
+}
+
+/// Gamepad steering for one player: D-pad (bound move buttons) or left stick, -1..1
+pub fn pad_direction(pads: &[&Gamepad], bindings: &KeyBindings, stick: &StickSettings) -> f32 {
+    let mut direction = 0.0;
+    for pad in pads {
+        if bindings.pad_pressed(pad, InputAction::MoveLeft) {
+            direction -= 1.0;
+        }
+        if bindings.pad_pressed(pad, InputAction::MoveRight) {
+            direction += 1.0;
+        }
+        direction += stick.stick_value(pad.left_stick().x);
+    }
+    direction.clamp(-1.0, 1.0)
+}
+
+/// Handle paddle movement input (mirrored while the reverse-controls hazard is active)
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn paddle_input(
     keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    stick: Res<StickSettings>,
+    gamepads: Query<(Entity, &Gamepad)>,
+    mut pad_held: Local<[f32; COOP_PLAYERS]>,
+    mouse: Res<MouseSettings>,
+    settings: Res<GameSettings>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut last_cursor: Local<Option<Vec2>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
     touches: Res<Touches>,
     time: Res<Time>,
-    mut query: Query<&mut Transform, With<Paddle>>,
-    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    coop: Option<Res<CoopMode>>,
+    versus: Option<Res<VersusMode>>,
+    mut query: Query<
+        (&mut Transform, &mut PaddleVelocity, &Player, &PlayfieldId, Option<&PowerUpEffects>),
+        With<Paddle>,
+    >,
+    camera_query: Query<(&Camera, &GlobalTransform, Option<&PlayfieldId>), With<Camera2d>>,
 ) {
-    let Ok(mut paddle_transform) = query.get_single_mut() else {
-        return;
+    let coop = coop.is_some();
+    let split_controls = coop || versus.is_some();
+
+    // Touch positions in world space: the first touch alone, every touch with two players
+    let screen_positions: Vec<Vec2> = if split_controls {
+        touches.iter().map(|touch| touch.position()).collect()
+    } else {
+        touches.first_pressed_position().into_iter().collect()
+    };
+    // Screen position to world X, with the versus field it lands on (the main camera only
+    // draws the HUD there)
+    let to_world = |pos: Vec2| -> Option<(Option<usize>, f32)> {
+        camera_query.iter().find_map(|(camera, cam_transform, field)| {
+            let rect = camera.logical_viewport_rect()?;
+            if versus.is_some() != field.is_some() || !rect.contains(pos) {
+                return None;
+            }
+            let world_pos = camera.viewport_to_world_2d(cam_transform, pos - rect.min).ok()?;
+            Some((field.map(|field| field.0), world_pos.x))
+        })
     };
 
-    let half_paddle = PADDLE_WIDTH / 2.0;
-    let limit = WINDOW_WIDTH / 2.0 - WALL_THICKNESS - half_paddle;
+    // Each touch steers one player: by screen half in co-op, by the field it lands on in versus
+    let mut touch_targets: Vec<(usize, f32)> = Vec::new();
+    for (field, x) in screen_positions.into_iter().filter_map(to_world) {
+        let player = match field {
+            Some(field) => field,
+            None if coop => touch_zone_player(x, 0.0),
+            None => 0,
+        };
+        touch_targets.push((player, x));
+    }
 
-    // Touch input: move paddle directly to touch X position
-    if let Some(screen_pos) = touches.first_pressed_position() {
-        if let Ok((camera, cam_transform)) = camera_query.get_single() {
-            if let Ok(world_pos) = camera.viewport_to_world_2d(cam_transform, screen_pos) {
-                paddle_transform.translation.x = world_pos.x.clamp(-limit, limit);
-                return;
+    // The mouse steers 1P: to the cursor X whenever the cursor moves, or by relative motion
+    let motion: f32 = mouse_motion.read().map(|motion| motion.delta.x).sum();
+    let mut mouse_dx = 0.0;
+    match mouse.mode {
+        MouseMode::Absolute => {
+            let cursor = windows.get_single().ok().and_then(Window::cursor_position);
+            if cursor != *last_cursor {
+                *last_cursor = cursor;
+                let target = cursor.and_then(to_world).filter(|(field, _)| field.unwrap_or(0) == 0);
+                if let Some((_, x)) = target {
+                    if !touch_targets.iter().any(|(player, _)| *player == 0) {
+                        touch_targets.push((0, x));
+                    }
+                }
             }
         }
+        MouseMode::Relative => mouse_dx = motion * mouse.sensitivity,
+        MouseMode::Off => {}
     }
 
-    // Keyboard input
-    let mut direction = 0.0;
+    let dt = time.delta_secs();
+    for (mut paddle_transform, mut velocity, player, field, effects) in &mut query {
+        let start_x = paddle_transform.translation.x;
+        let reversed = effects.is_some_and(|effects| {
+            effects.effects.iter().any(|e| e.effect_type == PowerUpType::ReverseControls)
+        });
+        let (min_x, max_x) = paddle_x_range(player.0, coop);
+        // Versus paddles range over their own field
+        let (min_x, max_x) = (min_x + field.origin_x(), max_x + field.origin_x());
 
-    if keyboard.pressed(KeyCode::ArrowLeft) || keyboard.pressed(KeyCode::KeyA) {
-        direction -= 1.0;
-    }
-    if keyboard.pressed(KeyCode::ArrowRight) || keyboard.pressed(KeyCode::KeyD) {
-        direction += 1.0;
+        // Touch input: move paddle directly to touch X position
+        let touch_x = touch_targets.iter().find(|(p, _)| *p == player.0).map(|(_, x)| *x);
+        if let Some(x) = touch_x {
+            // Reversed controls mirror the touch position within the paddle's range
+            let target_x = if reversed { min_x + max_x - x } else { x };
+            paddle_transform.translation.x = target_x.clamp(min_x, max_x);
+            velocity.0 = paddle_velocity(start_x, paddle_transform.translation.x, dt);
+            continue;
+        }
+
+        // Keyboard input
+        let (left, right) = if split_controls {
+            let actions = &COOP_ACTIONS[player.0];
+            (bindings.pressed(&keyboard, actions.left), bindings.pressed(&keyboard, actions.right))
+        } else {
+            (
+                bindings.pressed(&keyboard, InputAction::MoveLeft),
+                bindings.pressed(&keyboard, InputAction::MoveRight),
+            )
+        };
+        let mut direction: f32 = 0.0;
+        if left {
+            direction -= 1.0;
+        }
+        if right {
+            direction += 1.0;
+        }
+
+        // Gamepad input builds up to full speed over the acceleration time
+        let pads = player_gamepads(&gamepads, player.0, split_controls);
+        let pad = pad_direction(&pads, &bindings, &stick);
+        let held = &mut pad_held[player.0.min(COOP_PLAYERS - 1)];
+        *held = if pad == 0.0 { 0.0 } else { *held + dt };
+        direction = (direction + pad * stick.ramp(*held)).clamp(-1.0, 1.0);
+
+        if reversed {
+            direction = -direction;
+        }
+
+        let mut new_x = paddle_transform.translation.x + direction * PADDLE_SPEED * settings.paddle_speed * dt;
+        if player.0 == 0 {
+            new_x += if reversed { -mouse_dx } else { mouse_dx };
+        }
+        paddle_transform.translation.x = new_x.clamp(min_x, max_x);
+        velocity.0 = paddle_velocity(start_x, paddle_transform.translation.x, dt);
     }
+}
 
-    let new_x = paddle_transform.translation.x + direction * PADDLE_SPEED * time.delta_secs();
-    paddle_transform.translation.x = new_x.clamp(-limit, limit);
+/// Paddle speed from one frame's travel (zero on a zero-length frame)
+fn paddle_velocity(from_x: f32, to_x: f32, dt: f32) -> f32 {
+    if dt > 0.0 { (to_x - from_x) / dt } else { 0.0 }
 }
 
-/// Handle game start input (Space or tap to start, S for settings, E for editor)
+/// Hide the cursor while the mouse steers the paddle (locked in relative mode) and give it
+/// back in menus and pause, during practice (its panel is clicked) and over UI buttons.
+/// Browsers drop pointer lock on their own (ESC, focus loss), so on WASM a click re-requests it.
+pub fn update_cursor_grab(
+    state: Res<State<GameState>>,
+    mouse: Res<MouseSettings>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    practice: Option<Res<PracticeMode>>,
+    interactions: Query<&Interaction>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let over_ui = interactions.iter().any(|i| *i != Interaction::None);
+    let steering = mouse.enabled()
+        && matches!(state.get(), GameState::Playing | GameState::Countdown)
+        && practice.is_none()
+        && !over_ui;
+    let grab_mode = if steering && mouse.mode == MouseMode::Relative {
+        CursorGrabMode::Locked
+    } else {
+        CursorGrabMode::None
+    };
+    let relock = cfg!(target_arch = "wasm32")
+        && grab_mode == CursorGrabMode::Locked
+        && mouse_buttons.just_pressed(MouseButton::Left);
+
+    for mut window in &mut windows {
+        if relock {
+            // Only a changed grab mode reaches the browser: release now, lock again next frame
+            window.cursor_options.grab_mode = CursorGrabMode::None;
+            continue;
+        }
+        if window.cursor_options.grab_mode != grab_mode {
+            window.cursor_options.grab_mode = grab_mode;
+        }
+        if window.cursor_options.visible == steering {
+            window.cursor_options.visible = !steering;
+        }
+    }
+}
+
+/// Handle game start input (Confirm or tap to start).
+/// The mode actions (T, N, D, P, 2, V and L by default) start time attack, endless, the daily
+/// challenge, puzzle, co-op, versus and practice; Settings (S) and Editor (E) open those screens.
+#[allow(clippy::too_many_arguments)]
 pub fn start_game_input(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+    actions: ActionInput,
     touches: Res<Touches>,
     mut next_state: ResMut<NextState<GameState>>,
+    mut high_scores: ResMut<HighScores>,
     settings_btn: Query<&Interaction, (With<crate::components::SettingsButton>, Without<EditorButton>)>,
     editor_btn: Query<&Interaction, (With<EditorButton>, Without<crate::components::SettingsButton>)>,
+    time_attack_btn: Query<&Interaction, With<TimeAttackButton>>,
+    endless_btn: Query<&Interaction, With<EndlessButton>>,
+    daily_btn: Query<&Interaction, With<DailyButton>>,
+    coop_btn: Query<&Interaction, With<CoopButton>>,
+    versus_btn: Query<&Interaction, With<VersusButton>>,
+    puzzle_btn: Query<&Interaction, With<PuzzleButton>>,
+    practice_btn: Query<&Interaction, With<PracticeButton>>,
+    mut daily_record: ResMut<DailyRecord>,
 ) {
-    if keyboard.just_pressed(KeyCode::KeyS) {
+    let time_attack_pressed = time_attack_btn.iter().any(|i| *i == Interaction::Pressed);
+    if actions.just_pressed(InputAction::TimeAttack) || time_attack_pressed {
+        commands.insert_resource(TimeAttackMode::default());
+        *high_scores = HighScores::load(ScoreTable::TimeAttack);
+        next_state.set(GameState::Countdown);
+        return;
+    }
+    let endless_pressed = endless_btn.iter().any(|i| *i == Interaction::Pressed);
+    if actions.just_pressed(InputAction::Endless) || endless_pressed {
+        commands.insert_resource(EndlessMode::default());
+        *high_scores = HighScores::load(ScoreTable::Endless);
+        next_state.set(GameState::Countdown);
+        return;
+    }
+    let puzzle_pressed = puzzle_btn.iter().any(|i| *i == Interaction::Pressed);
+    if actions.just_pressed(InputAction::Puzzle) || puzzle_pressed {
+        // Puzzle stages are fixed: no random drops
+        commands.insert_resource(PuzzleMode::start());
+        commands.insert_resource(DropRng::disabled());
+        next_state.set(GameState::Countdown);
+        return;
+    }
+    let practice_pressed = practice_btn.iter().any(|i| *i == Interaction::Pressed);
+    if actions.just_pressed(InputAction::Practice) || practice_pressed {
+        commands.insert_resource(PracticeMode::default());
+        next_state.set(GameState::Countdown);
+        return;
+    }
+    let coop_pressed = coop_btn.iter().any(|i| *i == Interaction::Pressed);
+    if actions.just_pressed(InputAction::Coop) || coop_pressed {
+        commands.insert_resource(CoopMode);
+        *high_scores = HighScores::load(ScoreTable::Coop);
+        next_state.set(GameState::Countdown);
+        return;
+    }
+    let versus_pressed = versus_btn.iter().any(|i| *i == Interaction::Pressed);
+    if actions.just_pressed(InputAction::Versus) || versus_pressed {
+        // Versus is played without items so both fields stay even; both get the same garbage gaps
+        let seed = (crate::utils::rand_f32() * u32::MAX as f32) as u32;
+        commands.insert_resource(VersusMode::new(seed));
+        commands.insert_resource(DropRng::disabled());
+        next_state.set(GameState::Countdown);
+        return;
+    }
+    let daily_pressed = daily_btn.iter().any(|i| *i == Interaction::Pressed);
+    if actions.just_pressed(InputAction::Daily) || daily_pressed {
+        let today = today_utc();
+        // One attempt per day: the attempt counts as soon as it starts
+        if !daily_record.played(today) {
+            daily_record.start_attempt(today);
+            daily_record.save();
+            commands.insert_resource(DailyChallenge {
+                day: today,
+                cleared_time: None,
+            });
+            commands.insert_resource(daily_drop_rng(today));
+            next_state.set(GameState::Countdown);
+        }
+        return;
+    }
+    if actions.just_pressed(InputAction::Settings) {
         next_state.set(GameState::Settings);
         return;
     }
-    if keyboard.just_pressed(KeyCode::KeyE) {
+    if actions.just_pressed(InputAction::Editor) {
         next_state.set(GameState::Editor);
         return;
     }
@@ -75,31 +418,34 @@ pub fn start_game_input(
             return;
         }
     }
-    if keyboard.just_pressed(KeyCode::Space) || touches.any_just_pressed() {
+    if actions.just_pressed(InputAction::Confirm) || touches.any_just_pressed() {
         next_state.set(GameState::Countdown);
     }
 }
 
 /// Handle pause input (Playing state only — resume is handled by pause_overlay_input)
 pub fn pause_input(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    actions: ActionInput,
     mut next_state: ResMut<NextState<GameState>>,
     pause_btn: Query<&Interaction, With<PauseButton>>,
 ) {
     let btn_pressed = pause_btn.iter().any(|i| *i == Interaction::Pressed);
-    if keyboard.just_pressed(KeyCode::Escape) || btn_pressed {
+    if actions.just_pressed(InputAction::Pause) || btn_pressed {
         next_state.set(GameState::Paused);
     }
 }
 
 /// Handle restart input after game over
 pub fn restart_input(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    actions: ActionInput,
     touches: Res<Touches>,
     mut next_state: ResMut<NextState<GameState>>,
     test_play: Option<Res<TestPlayMode>>,
+    share_btn: Query<&Interaction, With<ShareButton>>,
 ) {
-    if keyboard.just_pressed(KeyCode::Space) || touches.any_just_pressed() {
+    // A tap on the daily result's share button only copies
+    let on_share = share_btn.iter().any(|i| *i != Interaction::None);
+    if actions.just_pressed(InputAction::Confirm) || (touches.any_just_pressed() && !on_share) {
         if test_play.is_some() {
             next_state.set(GameState::Editor);
         } else {
@@ -110,16 +456,245 @@ pub fn restart_input(
 
 /// Handle next level input
 pub fn next_level_input(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    actions: ActionInput,
     touches: Res<Touches>,
     mut next_state: ResMut<NextState<GameState>>,
     test_play: Option<Res<TestPlayMode>>,
+    daily: Option<Res<DailyChallenge>>,
+    puzzle: Option<Res<PuzzleMode>>,
 ) {
-    if keyboard.just_pressed(KeyCode::Space) || touches.any_just_pressed() {
+    if actions.just_pressed(InputAction::Confirm) || touches.any_just_pressed() {
+        let last_puzzle = puzzle.is_some_and(|mode| mode.stage + 1 >= PUZZLE_STAGE_COUNT);
         if test_play.is_some() {
             next_state.set(GameState::Editor);
+        } else if daily.is_some() || last_puzzle {
+            // The daily challenge is a single level and the puzzle set is done: go to the result screen
+            next_state.set(GameState::GameOver);
         } else {
             next_state.set(GameState::Countdown);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    #[test]
+    fn reverse_controls_flip_keyboard_direction() {
+        let mut app = test_app();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<Touches>();
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        app.world_mut().entity_mut(paddle).insert(PowerUpEffects {
+            effects: vec![ActiveEffect {
+                effect_type: PowerUpType::ReverseControls,
+                timer: Timer::from_seconds(REVERSE_CONTROLS_DURATION, TimerMode::Once),
+            }],
+        });
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ArrowRight);
+
+        app.add_systems(Update, paddle_input);
+        app.update();
+
+        let transform = app.world().entity(paddle).get::<Transform>().unwrap();
+        assert!(transform.translation.x < 0.0, "Right should move left while reversed");
+        let velocity = app.world().entity(paddle).get::<PaddleVelocity>().unwrap();
+        assert_eq!(velocity.0, -PADDLE_SPEED, "Velocity follows the actual travel");
+    }
+
+    #[test]
+    fn paddle_speed_setting_scales_keyboard_movement() {
+        let mut app = test_app();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<Touches>();
+        app.world_mut().resource_mut::<GameSettings>().paddle_speed = 1.5;
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ArrowRight);
+
+        app.add_systems(Update, paddle_input);
+        app.update();
+
+        let velocity = app.world().entity(paddle).get::<PaddleVelocity>().unwrap();
+        assert_eq!(velocity.0, PADDLE_SPEED * 1.5);
+    }
+
+    #[test]
+    fn coop_paddles_use_own_keys_and_half() {
+        let mut app = test_app();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<Touches>();
+        app.insert_resource(CoopMode);
+        let first = spawn_test_paddle(app.world_mut(), -COOP_PADDLE_OFFSET);
+        let second = spawn_test_paddle(app.world_mut(), PADDLE_WIDTH / 2.0);
+        app.world_mut().entity_mut(second).insert(Player(1));
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ArrowLeft);
+
+        app.add_systems(Update, paddle_input);
+        app.update();
+
+        let x = |app: &App, paddle: Entity| app.world().get::<Transform>(paddle).unwrap().translation.x;
+        assert_eq!(x(&app, first), -COOP_PADDLE_OFFSET, "Arrows only move player 2");
+        assert_eq!(x(&app, second), PADDLE_WIDTH / 2.0, "Player 2 stops at the center line");
+    }
+
+    #[test]
+    fn relative_mouse_motion_moves_first_player() {
+        let mut app = test_app();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<Touches>();
+        app.insert_resource(MouseSettings {
+            mode: MouseMode::Relative,
+            sensitivity: 1.5,
+        });
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        app.world_mut().send_event(MouseMotion {
+            delta: Vec2::new(20.0, -8.0),
+        });
+
+        app.add_systems(Update, paddle_input);
+        app.update();
+
+        let transform = app.world().entity(paddle).get::<Transform>().unwrap();
+        assert_eq!(transform.translation.x, 30.0);
+    }
+
+    fn set_cursor(app: &mut App, x: f32) {
+        let mut windows = app.world_mut().query::<&mut Window>();
+        let mut window = windows.single_mut(app.world_mut());
+        window.set_cursor_position(Some(Vec2::new(x, WINDOW_HEIGHT / 2.0)));
+    }
+
+    #[test]
+    fn absolute_mouse_moves_paddle_to_cursor() {
+        let mut app = windowed_test_app();
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        set_cursor(&mut app, WINDOW_WIDTH / 2.0 + 120.0);
+
+        app.add_systems(Update, paddle_input);
+        app.update();
+
+        let transform = app.world().entity(paddle).get::<Transform>().unwrap();
+        assert!((transform.translation.x - 120.0).abs() < 0.01, "{}", transform.translation.x);
+
+        // A resting cursor leaves the keyboard in charge
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ArrowLeft);
+        app.update();
+        let transform = app.world().entity(paddle).get::<Transform>().unwrap();
+        assert!(transform.translation.x < 120.0);
+    }
+
+    #[test]
+    fn click_launches_unless_it_hits_a_button() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut app = windowed_test_app();
+        let clicked = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<ButtonInput<MouseButton>>()
+                .press(MouseButton::Left);
+            let launched = app
+                .world_mut()
+                .run_system_once(|actions: PlayerActions| actions.just_pressed(0))
+                .unwrap();
+            app.world_mut().resource_mut::<ButtonInput<MouseButton>>().reset_all();
+            launched
+        };
+        assert!(clicked(&mut app));
+
+        app.world_mut().spawn((Button, Interaction::Pressed));
+        assert!(!clicked(&mut app), "The click belongs to the button");
+    }
+
+    /// Primary window grab mode and cursor visibility
+    fn cursor(app: &mut App) -> (CursorGrabMode, bool) {
+        let mut windows = app.world_mut().query::<&Window>();
+        let window = windows.single(app.world());
+        (window.cursor_options.grab_mode, window.cursor_options.visible)
+    }
+
+    fn cursor_app(mode: MouseMode) -> App {
+        let mut app = windowed_test_app();
+        app.insert_resource(MouseSettings { mode, sensitivity: 1.0 });
+        app.add_systems(Update, update_cursor_grab);
+        app
+    }
+
+    fn enter(app: &mut App, state: GameState) {
+        app.world_mut().resource_mut::<NextState<GameState>>().set(state);
+        app.update();
+    }
+
+    #[test]
+    fn cursor_hides_during_play_and_returns_in_pause_and_menus() {
+        let mut app = cursor_app(MouseMode::Absolute);
+        enter(&mut app, GameState::Menu);
+        assert_eq!(cursor(&mut app), (CursorGrabMode::None, true));
+        enter(&mut app, GameState::Playing);
+        assert_eq!(cursor(&mut app), (CursorGrabMode::None, false), "Hidden but free in cursor mode");
+        enter(&mut app, GameState::Paused);
+        assert_eq!(cursor(&mut app), (CursorGrabMode::None, true));
+
+        let mut app = cursor_app(MouseMode::Relative);
+        enter(&mut app, GameState::Playing);
+        assert_eq!(cursor(&mut app), (CursorGrabMode::Locked, false));
+        enter(&mut app, GameState::Paused);
+        assert_eq!(cursor(&mut app), (CursorGrabMode::None, true));
+        enter(&mut app, GameState::Playing);
+        assert_eq!(cursor(&mut app), (CursorGrabMode::Locked, false), "Locked again on resume");
+        enter(&mut app, GameState::Menu);
+        assert_eq!(cursor(&mut app), (CursorGrabMode::None, true));
+
+        let mut app = cursor_app(MouseMode::Off);
+        enter(&mut app, GameState::Playing);
+        assert_eq!(cursor(&mut app), (CursorGrabMode::None, true), "Mouse off leaves the cursor alone");
+    }
+
+    #[test]
+    fn click_keeps_the_lock_outside_the_browser() {
+        let mut app = cursor_app(MouseMode::Relative);
+        enter(&mut app, GameState::Playing);
+        // Only WASM releases for a frame so the browser sees a fresh lock request
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        app.update();
+        assert_eq!(cursor(&mut app), (CursorGrabMode::Locked, false));
+    }
+
+    #[test]
+    fn cursor_stays_free_in_practice_and_over_buttons() {
+        let mut app = cursor_app(MouseMode::Relative);
+        enter(&mut app, GameState::Playing);
+        assert_eq!(cursor(&mut app), (CursorGrabMode::Locked, false));
+
+        let button = app.world_mut().spawn((Button, Interaction::Hovered)).id();
+        app.update();
+        assert_eq!(cursor(&mut app), (CursorGrabMode::None, true));
+
+        app.world_mut().entity_mut(button).insert(Interaction::None);
+        app.insert_resource(PracticeMode::default());
+        app.update();
+        assert_eq!(cursor(&mut app), (CursorGrabMode::None, true));
+    }
+
+    #[test]
+    fn coop_ranges_split_the_field() {
+        let (solo_min, solo_max) = paddle_x_range(0, false);
+        let (p1_min, p1_max) = paddle_x_range(0, true);
+        let (p2_min, p2_max) = paddle_x_range(1, true);
+        assert_eq!((p1_min, p2_max), (solo_min, solo_max));
+        assert!(p1_max < p2_min, "Paddles never overlap");
+        assert_eq!(touch_zone_player(100.0, 400.0), 0);
+        assert_eq!(touch_zone_player(500.0, 400.0), 1);
+    }
+}
@@ -7,6 +7,16 @@ pub mod audio;
 pub mod game_state;
 pub mod effects;
 pub mod powerup;
+pub mod pickup;
+pub mod time_attack;
+pub mod endless;
+pub mod daily;
+pub mod versus;
+pub mod puzzle;
+pub mod practice;
+pub mod controls;
+pub mod gamepad;
+pub mod option_list;
 pub mod combo;
 pub mod countdown;
 pub mod editor;
@@ -20,6 +30,16 @@ pub use audio::*;
 pub use game_state::*;
 pub use effects::*;
 pub use powerup::*;
+pub use pickup::*;
+pub use time_attack::*;
+pub use endless::*;
+pub use daily::*;
+pub use versus::*;
+pub use puzzle::*;
+pub use practice::*;
+pub use controls::*;
+pub use gamepad::*;
+pub use option_list::*;
 pub use combo::*;
 pub use countdown::*;
 pub use editor::*;
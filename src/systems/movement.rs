@@ -1,12 +1,110 @@
 use bevy::prelude::*;
 
 use crate::components::*;
+use crate::constants::*;
+use crate::resources::{GameSettings, SpeedRamp};
+use crate::systems::collision::clamp_vertical_angle;
+use crate::systems::effects::particle_count;
 
-/// Move the ball based on its velocity
-pub fn ball_movement(time: Res<Time>, mut query: Query<(&mut Transform, &Velocity), With<Ball>>) {
-    for (mut transform, velocity) in &mut query {
-        transform.translation.x += velocity.0.x * time.delta_secs();
-        transform.translation.y += velocity.0.y * time.delta_secs();
+/// Combined acceleration and speed factor of the field zones containing `pos`
+pub fn field_forces<'a>(zones: impl IntoIterator<Item = (&'a Transform, &'a FieldZone)>, pos: Vec2) -> (Vec2, f32) {
+    let (mut accel, mut scale) = (Vec2::ZERO, 1.0);
+    for (transform, zone) in zones {
+        let center = transform.translation.truncate();
+        if !Rect::from_center_size(center, zone.size).contains(pos) {
+            continue;
+        }
+        match zone.force {
+            FieldForce::Accel(a) => accel += a,
+            FieldForce::Speed(s) => scale *= s,
+        }
+    }
+    (accel, scale)
+}
+
+/// Move the ball based on its velocity (caught balls follow the paddle instead).
+/// Heavy-ball gravity and field zones bend the path without changing the ball's speed, and
+/// slow / fast zones scale how far it moves while inside.
+#[allow(clippy::type_complexity)]
+pub fn ball_movement(
+    time: Res<Time>,
+    mut query: Query<(&mut Transform, &mut Velocity, &BallKind), (With<Ball>, Without<CaughtBall>)>,
+    zones: Query<(&Transform, &FieldZone), Without<Ball>>,
+) {
+    let dt = time.delta_secs();
+    for (mut transform, mut velocity, kind) in &mut query {
+        let (mut accel, scale) = field_forces(&zones, transform.translation.truncate());
+        if *kind == BallKind::Heavy {
+            accel.y -= HEAVY_BALL_GRAVITY;
+        }
+        if accel != Vec2::ZERO {
+            let speed = velocity.0.length();
+            velocity.0 = (velocity.0 + accel * dt).normalize_or_zero() * speed;
+        }
+        transform.translation.x += velocity.0.x * dt * scale;
+        transform.translation.y += velocity.0.y * dt * scale;
+    }
+}
+
+/// Bring every ball up to its field's speed ramp after a speed-up was earned.
+/// Scaling is relative, so slow / fast ball effects keep their ratio on top of the ramp.
+pub fn apply_speed_ramp(mut ramp: ResMut<SpeedRamp>, mut balls: Query<(&mut Velocity, &PlayfieldId), With<Ball>>) {
+    for field in 0..VERSUS_FIELDS {
+        let Some(scale) = ramp.take_change(field) else {
+            continue;
+        };
+        for (mut velocity, _) in balls.iter_mut().filter(|(_, ball_field)| ball_field.0 == field) {
+            velocity.0 *= scale;
+        }
+    }
+}
+
+/// Turn a ball that has gone `STUCK_BALL_TIME` without touching the paddle or a breakable block
+/// and keeps bouncing where it bounced before (e.g. looping between Steel blocks and walls),
+/// again every `STUCK_NUDGE_INTERVAL` while the loop repeats, alternating sides. A ball that is
+/// just travelling is left alone. A small burst marks each nudge.
+#[allow(clippy::type_complexity)]
+pub fn nudge_stuck_balls(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<GameSettings>,
+    mut query: Query<(&Transform, &mut Velocity, &mut BallIdle), (With<Ball>, Without<CaughtBall>, Without<PuzzleServe>)>,
+) {
+    for (transform, mut velocity, mut idle) in &mut query {
+        if velocity.0 == Vec2::ZERO {
+            continue;
+        }
+        idle.secs += time.delta_secs();
+        if idle.secs < STUCK_BALL_TIME || !idle.repeating() {
+            continue;
+        }
+
+        let side = if idle.nudges % 2 == 0 { 1.0 } else { -1.0 };
+        let turn = Vec2::from_angle(side * STUCK_NUDGE_ANGLE.to_radians());
+        velocity.0 = clamp_vertical_angle(turn.rotate(velocity.0));
+        idle.nudges += 1;
+        idle.secs = STUCK_BALL_TIME - STUCK_NUDGE_INTERVAL;
+        // The turned path has to repeat again before the next nudge
+        idle.contacts.clear();
+
+        let pos = transform.translation.truncate();
+        let count = particle_count(8, settings.particles);
+        for i in 0..count {
+            let direction = Vec2::from_angle(i as f32 * std::f32::consts::TAU / count as f32);
+            commands.spawn((
+                Sprite {
+                    color: Color::srgb(0.55, 0.85, 1.0),
+                    custom_size: Some(Vec2::splat(PARTICLE_SIZE)),
+                    ..default()
+                },
+                Transform::from_xyz(pos.x, pos.y, 1.0),
+                Particle {
+                    lifetime: Timer::from_seconds(PARTICLE_LIFETIME, TimerMode::Once),
+                    velocity: direction * PARTICLE_SPEED * 0.5,
+                    initial_alpha: 0.8,
+                },
+            ));
+        }
     }
 }
 
@@ -74,4 +172,139 @@ mod tests {
         assert!(ta.translation.x > 0.0 && ta.translation.y.abs() < 0.01);
         assert!(tb.translation.y > 0.0 && tb.translation.x.abs() < 0.01);
     }
+
+    #[test]
+    fn heavy_ball_falls_with_gravity() {
+        let mut app = test_app();
+        let start = Vec2::new(200.0, 200.0);
+        let heavy = spawn_test_ball(app.world_mut(), Vec2::ZERO, start);
+        app.world_mut().entity_mut(heavy).insert(BallKind::Heavy);
+        let normal = spawn_test_ball(app.world_mut(), Vec2::ZERO, start);
+
+        app.add_systems(Update, ball_movement);
+        for _ in 0..120 {
+            app.update();
+        }
+
+        let velocity = app.world().get::<Velocity>(heavy).unwrap().0;
+        assert!(velocity.y < start.y, "Gravity bends the climb down");
+        assert!((velocity.length() - start.length()).abs() < 0.01, "Speed holds however long it falls");
+        assert_eq!(app.world().get::<Velocity>(normal).unwrap().0, start);
+    }
+
+    fn spawn_zone(world: &mut World, force: FieldForce, center: Vec2, size: Vec2) {
+        world.spawn((Transform::from_translation(center.extend(-1.0)), FieldZone { force, size }));
+    }
+
+    #[test]
+    fn gravity_zone_bends_path_and_keeps_speed() {
+        let mut app = test_app();
+        spawn_zone(app.world_mut(), FieldForce::Accel(Vec2::NEG_Y * FIELD_GRAVITY), Vec2::ZERO, Vec2::splat(400.0));
+        let ball = spawn_test_ball(app.world_mut(), Vec2::ZERO, Vec2::new(300.0, 200.0));
+
+        app.add_systems(Update, ball_movement);
+        app.update();
+
+        let velocity = app.world().get::<Velocity>(ball).unwrap().0;
+        assert!(velocity.y / velocity.x < 200.0 / 300.0, "Path bent downward");
+        assert!((velocity.length() - Vec2::new(300.0, 200.0).length()).abs() < 0.01, "Speed kept");
+    }
+
+    #[test]
+    fn speed_zones_scale_travel_only_inside() {
+        let mut app = test_app();
+        spawn_zone(app.world_mut(), FieldForce::Speed(FIELD_SLOW_SCALE), Vec2::ZERO, Vec2::splat(100.0));
+        let inside = spawn_test_ball(app.world_mut(), Vec2::ZERO, Vec2::new(0.0, 300.0));
+        let outside = spawn_test_ball(app.world_mut(), Vec2::new(200.0, 0.0), Vec2::new(0.0, 300.0));
+
+        app.add_systems(Update, ball_movement);
+        app.update();
+
+        let y = |e| app.world().get::<Transform>(e).unwrap().translation.y;
+        assert!((y(inside) - 5.0 * FIELD_SLOW_SCALE).abs() < 0.01);
+        assert!((y(outside) - 5.0).abs() < 0.01);
+        assert_eq!(app.world().get::<Velocity>(inside).unwrap().0, Vec2::new(0.0, 300.0));
+    }
+
+    /// Closed box of Steel blocks: nothing inside can ever be hit
+    fn spawn_steel_box(world: &mut World) {
+        for x in [-105.0, -35.0, 35.0, 105.0] {
+            spawn_test_block_typed(world, Vec2::new(x, 72.5), BlockType::Steel);
+            spawn_test_block_typed(world, Vec2::new(x, -72.5), BlockType::Steel);
+        }
+        for y in [-50.0, -25.0, 0.0, 25.0, 50.0] {
+            spawn_test_block_typed(world, Vec2::new(-140.0, y), BlockType::Steel);
+            spawn_test_block_typed(world, Vec2::new(140.0, y), BlockType::Steel);
+        }
+    }
+
+    #[test]
+    fn ball_looping_in_steel_box_gets_nudged() {
+        let mut app = test_app();
+        spawn_steel_box(app.world_mut());
+        let ball = spawn_test_ball(app.world_mut(), Vec2::ZERO, Vec2::new(300.0, 200.0));
+        app.add_systems(
+            Update,
+            (ball_movement, crate::systems::collision::ball_block_collision, nudge_stuck_balls).chain(),
+        );
+
+        // Steel bounces alone keep the same |vx|, |vy| and never count as progress
+        for _ in 0..((STUCK_BALL_TIME - 0.5) * 60.0) as usize {
+            app.update();
+        }
+        let velocity = app.world().get::<Velocity>(ball).unwrap().0;
+        assert!((velocity.x.abs() - 300.0).abs() < 0.01, "No nudge before the stuck time");
+        assert_eq!(app.world().get::<BallIdle>(ball).unwrap().nudges, 0);
+
+        for _ in 0..60 {
+            app.update();
+        }
+        let velocity = app.world().get::<Velocity>(ball).unwrap().0;
+        let idle = app.world().get::<BallIdle>(ball).unwrap();
+        assert_eq!(idle.nudges, 1);
+        assert!((velocity.x.abs() - 300.0).abs() > 1.0, "Direction turned by the nudge");
+        assert!((velocity.length() - Vec2::new(300.0, 200.0).length()).abs() < 0.01, "Speed kept");
+
+        let pos = app.world().get::<Transform>(ball).unwrap().translation;
+        assert!(pos.x.abs() < 105.0 && pos.y.abs() < 60.0, "Ball stayed inside the box");
+        let mut particles = app.world_mut().query::<&Particle>();
+        assert!(particles.iter(app.world()).count() > 0, "Nudge shows a burst");
+    }
+
+    #[test]
+    fn ball_travelling_without_repeats_is_not_nudged() {
+        let mut app = test_app();
+        let ball = spawn_test_ball(app.world_mut(), Vec2::ZERO, Vec2::new(300.0, 200.0));
+        app.add_systems(Update, (ball_movement, nudge_stuck_balls).chain());
+
+        // Open space: no bounces at all
+        for _ in 0..((STUCK_BALL_TIME + 1.0) * 60.0) as usize {
+            app.update();
+        }
+        assert_eq!(app.world().get::<BallIdle>(ball).unwrap().nudges, 0);
+        assert_eq!(app.world().get::<Velocity>(ball).unwrap().0, Vec2::new(300.0, 200.0));
+
+        // Bounces in new places, and a long contact that is only recorded once, are no loop either
+        let mut idle = app.world_mut().get_mut::<BallIdle>(ball).unwrap();
+        for x in [-200.0, -100.0, 0.0, 100.0] {
+            idle.record(Vec2::new(x, 300.0));
+            idle.record(Vec2::new(x + 1.0, 300.0));
+        }
+        assert_eq!(idle.contacts.len(), 4);
+        assert!(!idle.repeating());
+        idle.record(Vec2::new(-98.0, 302.0));
+        assert!(idle.repeating(), "Back where it bounced before");
+    }
+
+    #[test]
+    fn breakable_block_contact_resets_idle_time() {
+        let mut app = test_app();
+        spawn_test_block(app.world_mut(), Vec2::new(0.0, 20.0));
+        let ball = spawn_test_ball(app.world_mut(), Vec2::new(0.0, 5.0), Vec2::new(0.0, 300.0));
+        app.world_mut().get_mut::<BallIdle>(ball).unwrap().secs = STUCK_BALL_TIME - 0.1;
+        app.add_systems(Update, crate::systems::collision::ball_block_collision);
+        app.update();
+
+        assert_eq!(app.world().get::<BallIdle>(ball).unwrap().secs, 0.0);
+    }
 }
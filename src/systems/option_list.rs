@@ -0,0 +1,171 @@
+use bevy::prelude::*;
+use bevy::text::FontSmoothing;
+
+use crate::components::*;
+use crate::constants::*;
+
+/// One row handed to `spawn_option_list`: a stepped value, or an action row when `value` is None
+pub struct OptionListRow {
+    pub label: String,
+    pub value: Option<String>,
+}
+
+/// Spawn a scrolling option list showing `OPTION_LIST_VISIBLE_ROWS` rows at a time.
+/// Each row is `[cursor] [label] [<] [value] [>]` (action rows: `[cursor] [label]`), with
+/// ▲ / ▼ hints above and below while rows are hidden that way. `update_option_list` keeps the
+/// selected row in view; the screen's input system reads the row components.
+pub fn spawn_option_list(parent: &mut ChildBuilder, font: &Handle<Font>, rows: Vec<OptionListRow>) {
+    let cream = Color::srgb(0.95, 0.85, 0.65);
+    let text_font = TextFont {
+        font: font.clone(),
+        font_size: 24.0,
+        font_smoothing: FontSmoothing::None,
+    };
+    let len = rows.len();
+
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            ..default()
+        })
+        .with_children(|column| {
+            column.spawn((Button, Text::new("▲"), text_font.clone(), TextColor(Color::NONE), OptionScroll(-1)));
+
+            column
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        height: Val::Px(OPTION_LIST_VISIBLE_ROWS.min(len) as f32 * OPTION_ROW_HEIGHT),
+                        overflow: Overflow::scroll_y(),
+                        ..default()
+                    },
+                    ScrollPosition::default(),
+                    OptionList { selected: 0, len },
+                ))
+                .with_children(|list| {
+                    for (index, row) in rows.into_iter().enumerate() {
+                        list.spawn(Node {
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(4.0),
+                            height: Val::Px(OPTION_ROW_HEIGHT),
+                            flex_shrink: 0.0,
+                            ..default()
+                        })
+                        .with_children(|line| {
+                            line.spawn((Text::new(">"), text_font.clone(), TextColor(Color::NONE), OptionCursor(index)));
+                            let Some(value) = row.value else {
+                                line.spawn((
+                                    Button,
+                                    Node {
+                                        padding: UiRect::axes(Val::Px(14.0), Val::Px(4.0)),
+                                        ..default()
+                                    },
+                                    BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.06)),
+                                    OptionActivate(index),
+                                ))
+                                .with_children(|btn| {
+                                    btn.spawn((Text::new(row.label), text_font.clone(), TextColor(cream)));
+                                });
+                                return;
+                            };
+
+                            line.spawn((
+                                Text::new(row.label),
+                                text_font.clone(),
+                                TextColor(cream),
+                                Node {
+                                    min_width: Val::Px(220.0),
+                                    ..default()
+                                },
+                            ));
+                            spawn_step_button(line, &text_font, "<", OptionStep { row: index, step: -1 });
+                            line.spawn((
+                                Text::new(value),
+                                text_font.clone(),
+                                TextColor(cream),
+                                Node {
+                                    min_width: Val::Px(150.0),
+                                    ..default()
+                                },
+                                TextLayout::new_with_justify(JustifyText::Center),
+                                OptionValue(index),
+                            ));
+                            spawn_step_button(line, &text_font, ">", OptionStep { row: index, step: 1 });
+                        });
+                    }
+                });
+
+            column.spawn((Button, Text::new("▼"), text_font, TextColor(Color::NONE), OptionScroll(1)));
+        });
+}
+
+fn spawn_step_button(line: &mut ChildBuilder, text_font: &TextFont, label: &str, step: OptionStep) {
+    line.spawn((
+        Button,
+        Node {
+            padding: UiRect::axes(Val::Px(14.0), Val::Px(4.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.06)),
+        step,
+    ))
+    .with_children(|btn| {
+        btn.spawn((Text::new(label), text_font.clone(), TextColor(Color::srgb(0.95, 0.85, 0.65))));
+    });
+}
+
+/// First visible row that keeps `selected` in view, moving the window as little as possible
+pub fn visible_start(first: usize, selected: usize, len: usize, visible: usize) -> usize {
+    let first = if selected < first {
+        selected
+    } else if selected >= first + visible {
+        selected + 1 - visible
+    } else {
+        first
+    };
+    first.min(len.saturating_sub(visible))
+}
+
+/// Scroll the list to keep the selection visible, move the cursor, and show the ▲ / ▼ hints
+pub fn update_option_list(
+    mut lists: Query<(&OptionList, &mut ScrollPosition), Changed<OptionList>>,
+    mut cursors: Query<(&OptionCursor, &mut TextColor), Without<OptionScroll>>,
+    mut hints: Query<(&OptionScroll, &mut TextColor), Without<OptionCursor>>,
+) {
+    let gold = Color::srgb(1.0, 0.85, 0.20);
+    let lavender = Color::srgb(0.55, 0.50, 0.65);
+
+    for (list, mut scroll) in &mut lists {
+        let current = (scroll.offset_y / OPTION_ROW_HEIGHT).round() as usize;
+        let first = visible_start(current, list.selected, list.len, OPTION_LIST_VISIBLE_ROWS);
+        scroll.offset_y = first as f32 * OPTION_ROW_HEIGHT;
+
+        for (cursor, mut color) in &mut cursors {
+            color.0 = if cursor.0 == list.selected { gold } else { Color::NONE };
+        }
+        for (hint, mut color) in &mut hints {
+            let more = if hint.0 < 0 { first > 0 } else { first + OPTION_LIST_VISIBLE_ROWS < list.len };
+            color.0 = if more { lavender } else { Color::NONE };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visible_window_follows_selection() {
+        let rows = OPTION_LIST_VISIBLE_ROWS;
+        let len = rows + 5;
+        assert_eq!(visible_start(0, 0, len, rows), 0);
+        assert_eq!(visible_start(0, rows - 1, len, rows), 0);
+        assert_eq!(visible_start(0, rows, len, rows), 1, "Scrolls one row down");
+        assert_eq!(visible_start(4, 2, len, rows), 2, "Scrolls back up to the selection");
+        assert_eq!(visible_start(3, 5, len, rows), 3, "Stays put while the selection is visible");
+        assert_eq!(visible_start(9, len - 1, len, rows), 5, "Never scrolls past the end");
+        assert_eq!(visible_start(0, 2, 3, rows), 0, "Short lists never scroll");
+    }
+}
@@ -0,0 +1,252 @@
+use bevy::prelude::*;
+use bevy::text::FontSmoothing;
+
+use crate::components::*;
+use crate::constants::*;
+use crate::resources::*;
+use crate::systems::audio::CollisionEvent;
+use crate::utils::aabb_collision;
+
+/// Roll for a score pickup drop from a destroyed block
+pub fn maybe_spawn_pickup(commands: &mut Commands, drop_rng: &mut DropRng, position: Vec2, field: PlayfieldId) {
+    let roll = drop_rng.roll();
+    if roll < PICKUP_DROP_CHANCE {
+        // Remap [0, PICKUP_DROP_CHANCE) → [0, 1) for kind selection
+        let pickup = spawn_score_pickup(commands, position, pickup_kind(roll / PICKUP_DROP_CHANCE));
+        commands.entity(pickup).insert(field);
+    }
+}
+
+/// Map a roll in [0, 1) to a pickup kind: rare letters, then gems, then coins
+pub fn pickup_kind(roll: f32) -> PickupKind {
+    if roll < LETTER_SHARE {
+        let index = (roll / LETTER_SHARE * BONUS_WORD.len() as f32) as usize;
+        PickupKind::Letter(index.min(BONUS_WORD.len() - 1))
+    } else if roll < LETTER_SHARE + GEM_SHARE {
+        PickupKind::Gem
+    } else {
+        PickupKind::Coin
+    }
+}
+
+/// Spawn a falling score pickup
+pub fn spawn_score_pickup(commands: &mut Commands, position: Vec2, kind: PickupKind) -> Entity {
+    let (color, size) = match kind {
+        PickupKind::Coin => (Color::srgb(1.0, 0.82, 0.25), PICKUP_SIZE), // Gold
+        PickupKind::Gem => (Color::srgb(0.70, 0.45, 1.0), PICKUP_SIZE), // Amethyst
+        PickupKind::Letter(_) => (Color::srgb(0.95, 0.95, 1.0), POWERUP_SIZE), // White tile
+    };
+
+    commands
+        .spawn((
+            Sprite {
+                color,
+                custom_size: Some(Vec2::splat(size)),
+                ..default()
+            },
+            Transform::from_xyz(position.x, position.y, 0.5),
+            Pickup { kind },
+            Velocity(Vec2::new(0.0, -POWERUP_FALL_SPEED)),
+            Collider {
+                size: Vec2::splat(size),
+            },
+        ))
+        .id()
+}
+
+/// Draw the letter on newly dropped bonus letter tiles
+pub fn label_letter_pickups(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    query: Query<(Entity, &Pickup), Added<Pickup>>,
+) {
+    for (entity, pickup) in &query {
+        let PickupKind::Letter(index) = pickup.kind else {
+            continue;
+        };
+        let letter = BONUS_WORD[index..].chars().next().unwrap_or('?');
+        commands.entity(entity).with_children(|tile| {
+            tile.spawn((
+                Text2d::new(letter.to_string()),
+                TextFont {
+                    font: asset_server.load(GAME_FONT_PATH),
+                    font_size: 16.0,
+                    font_smoothing: FontSmoothing::None,
+                },
+                TextColor(Color::srgb(0.15, 0.12, 0.30)),
+                Transform::from_xyz(0.0, 0.0, 0.1),
+            ));
+        });
+    }
+}
+
+/// Collect score pickups touching a paddle in their field (credited to that paddle's player,
+/// or to the field's own score in versus)
+#[allow(clippy::too_many_arguments)]
+pub fn paddle_pickup_collision(
+    mut commands: Commands,
+    pickup_query: Query<(Entity, &Transform, &Collider, &Pickup, &PlayfieldId), Without<Paddle>>,
+    paddle_query: Query<(&Transform, &Collider, &Player, &PlayfieldId), With<Paddle>>,
+    mut score: ResMut<Score>,
+    mut versus: Option<ResMut<VersusMode>>,
+    mut level_stats: ResMut<LevelStats>,
+    mut letters: ResMut<BonusLetters>,
+    mut collision_events: EventWriter<CollisionEvent>,
+) {
+    for (entity, transform, collider, pickup, field) in &pickup_query {
+        let Some(player) = paddle_query.iter().find_map(|(paddle_transform, paddle_collider, player, paddle_field)| {
+            (paddle_field == field
+                && aabb_collision(
+                    transform.translation.truncate(),
+                    collider.size,
+                    paddle_transform.translation.truncate(),
+                    paddle_collider.size,
+                ))
+            .then_some(player.0)
+        }) else {
+            continue;
+        };
+
+        commands.entity(entity).despawn_recursive();
+        collision_events.send(CollisionEvent::PowerUp);
+
+        let mut word_complete = false;
+        let value = match pickup.kind {
+            PickupKind::Coin => COIN_SCORE,
+            PickupKind::Gem => GEM_SCORE,
+            PickupKind::Letter(index) if !letters.collected[index] => {
+                letters.collected[index] = true;
+                if letters.collected.iter().all(|&got| got) {
+                    // Word complete: pay out and start spelling again
+                    *letters = BonusLetters::default();
+                    word_complete = true;
+                    BONUS_WORD_SCORE
+                } else {
+                    0
+                }
+            }
+            // Duplicate letters are worth a gem
+            PickupKind::Letter(_) => GEM_SCORE,
+        };
+
+        // Pickups bypass the combo multiplier
+        match versus.as_mut() {
+            Some(versus) => versus.fields[field.0].score.value += value,
+            None => score.value += value,
+        }
+        if word_complete {
+            level_stats.word_bonus += value;
+        } else {
+            level_stats.coin_score += value;
+        }
+        level_stats.player_scores[player] += value;
+        if matches!(pickup.kind, PickupKind::Coin | PickupKind::Gem) {
+            level_stats.coins_collected += 1;
+        }
+    }
+}
+
+/// Update collected bonus word letters display
+pub fn update_bonus_word_text(
+    letters: Res<BonusLetters>,
+    mut query: Query<&mut Text, With<BonusWordText>>,
+) {
+    if letters.is_changed() {
+        for mut text in &mut query {
+            *text = Text::new(letters.display());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    fn spawn_at_paddle(world: &mut World, kind: PickupKind) {
+        world.spawn((
+            Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+            Pickup { kind },
+            Collider {
+                size: Vec2::splat(PICKUP_SIZE),
+            },
+        ));
+    }
+
+    #[test]
+    fn pickup_kind_bands() {
+        assert_eq!(pickup_kind(0.0), PickupKind::Letter(0));
+        assert_eq!(pickup_kind(LETTER_SHARE - 0.001), PickupKind::Letter(BONUS_WORD.len() - 1));
+        assert_eq!(pickup_kind(LETTER_SHARE + 0.01), PickupKind::Gem);
+        assert_eq!(pickup_kind(0.9), PickupKind::Coin);
+    }
+
+    #[test]
+    fn coins_score_without_combo() {
+        let mut app = test_app();
+        spawn_test_paddle(app.world_mut(), 0.0);
+        app.world_mut().resource_mut::<ComboTracker>().count = 5;
+        spawn_at_paddle(app.world_mut(), PickupKind::Coin);
+        spawn_at_paddle(app.world_mut(), PickupKind::Gem);
+
+        app.add_systems(Update, paddle_pickup_collision);
+        app.update();
+
+        assert_eq!(app.world().resource::<Score>().value, COIN_SCORE + GEM_SCORE);
+        let stats = app.world().resource::<LevelStats>();
+        assert_eq!(stats.coins_collected, 2);
+        assert_eq!(stats.coin_score, COIN_SCORE + GEM_SCORE);
+        assert_eq!(app.world().resource::<ComboTracker>().count, 5, "Combo is untouched");
+        let left = app.world_mut().query::<&Pickup>().iter(app.world()).count();
+        assert_eq!(left, 0);
+    }
+
+    #[test]
+    fn spelling_bonus_word_pays_out_and_resets() {
+        let mut app = test_app();
+        spawn_test_paddle(app.world_mut(), 0.0);
+        app.add_systems(Update, paddle_pickup_collision);
+
+        for index in 0..BONUS_WORD.len() - 1 {
+            spawn_at_paddle(app.world_mut(), PickupKind::Letter(index));
+        }
+        app.update();
+        assert_eq!(app.world().resource::<Score>().value, 0);
+        assert_eq!(app.world().resource::<BonusLetters>().display(), "BONU_");
+
+        spawn_at_paddle(app.world_mut(), PickupKind::Letter(BONUS_WORD.len() - 1));
+        app.update();
+        assert_eq!(app.world().resource::<Score>().value, BONUS_WORD_SCORE);
+        assert_eq!(app.world().resource::<BonusLetters>().display(), "_____");
+        let stats = app.world().resource::<LevelStats>();
+        assert_eq!(stats.word_bonus, BONUS_WORD_SCORE);
+        assert_eq!(stats.coin_score, 0, "The word bonus is not a coin bonus");
+    }
+
+    #[test]
+    fn duplicate_letter_scores_as_gem() {
+        let mut app = test_app();
+        spawn_test_paddle(app.world_mut(), 0.0);
+        app.world_mut().resource_mut::<BonusLetters>().collected[0] = true;
+        spawn_at_paddle(app.world_mut(), PickupKind::Letter(0));
+
+        app.add_systems(Update, paddle_pickup_collision);
+        app.update();
+
+        assert_eq!(app.world().resource::<Score>().value, GEM_SCORE);
+    }
+
+    #[test]
+    fn pickup_is_credited_to_catching_player() {
+        let mut app = test_app();
+        spawn_test_paddle(app.world_mut(), -300.0);
+        let second = spawn_test_paddle(app.world_mut(), 0.0);
+        app.world_mut().entity_mut(second).insert(Player(1));
+        spawn_at_paddle(app.world_mut(), PickupKind::Coin);
+
+        app.add_systems(Update, paddle_pickup_collision);
+        app.update();
+
+        assert_eq!(app.world().resource::<LevelStats>().player_scores, [0, COIN_SCORE]);
+    }
+}
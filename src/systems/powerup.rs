@@ -1,324 +1,841 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use bevy::text::FontSmoothing;
 
 use crate::components::*;
 use crate::constants::*;
 use crate::resources::*;
+use crate::systems::audio::CollisionEvent;
+use crate::systems::input::PlayerActions;
+use crate::systems::movement::field_forces;
+use crate::systems::setup::{grid_y, spawn_kind_ball};
 use crate::utils::aabb_collision;
 
-/// Move power-up items downward and despawn when off-screen
+/// Fire-rate cooldown for each player's laser paddle
+#[derive(Resource)]
+pub struct LaserCooldown(pub [Timer; COOP_PLAYERS]);
+
+impl Default for LaserCooldown {
+    fn default() -> Self {
+        // Start finished so the first shot fires immediately
+        let mut timer = Timer::from_seconds(LASER_COOLDOWN, TimerMode::Once);
+        timer.tick(timer.duration());
+        Self([timer.clone(), timer])
+    }
+}
+
+/// Move power-up and score pickup items downward and despawn when off-screen.
+/// While the magnet is active, items also drift horizontally toward the nearest magnet paddle.
+/// Wind zones push items sideways (up to `FIELD_ITEM_DRIFT_MAX`) and slow / fast zones scale
+/// their fall; items already fall at a fixed speed, so gravity leaves them alone.
+#[allow(clippy::type_complexity)]
 pub fn powerup_movement(
     mut commands: Commands,
     time: Res<Time>,
-    mut query: Query<(Entity, &mut Transform, &Velocity), With<PowerUp>>,
+    mut query: Query<
+        (Entity, &mut Transform, &mut Velocity, &PlayfieldId),
+        Or<(With<PowerUp>, With<Pickup>)>,
+    >,
+    paddle_query: Query<
+        (&Transform, &PlayfieldId, Option<&PowerUpEffects>),
+        (With<Paddle>, Without<PowerUp>, Without<Pickup>),
+    >,
+    zones: Query<(&Transform, &FieldZone), (Without<PowerUp>, Without<Pickup>)>,
 ) {
-    for (entity, mut transform, velocity) in &mut query {
-        transform.translation.x += velocity.0.x * time.delta_secs();
-        transform.translation.y += velocity.0.y * time.delta_secs();
+    let magnets: Vec<(f32, PlayfieldId)> = paddle_query
+        .iter()
+        .filter(|(_, _, effects)| {
+            effects.is_some_and(|e| e.effects.iter().any(|e| e.effect_type == PowerUpType::Magnet))
+        })
+        .map(|(transform, field, _)| (transform.translation.x, *field))
+        .collect();
+
+    for (entity, mut transform, mut velocity, item_field) in &mut query {
+        let (accel, scale) = field_forces(&zones, transform.translation.truncate());
+        velocity.0.x = (velocity.0.x + accel.x * time.delta_secs()).clamp(-FIELD_ITEM_DRIFT_MAX, FIELD_ITEM_DRIFT_MAX);
+        transform.translation.x += velocity.0.x * time.delta_secs() * scale;
+        transform.translation.y += velocity.0.y * time.delta_secs() * scale;
+
+        let item_x = transform.translation.x;
+        let nearest = magnets
+            .iter()
+            .filter(|(_, field)| field == item_field)
+            .map(|(x, _)| *x)
+            .min_by(|a, b| (a - item_x).abs().total_cmp(&(b - item_x).abs()));
+        if let Some(target_x) = nearest {
+            let dx = target_x - transform.translation.x;
+            let step = MAGNET_PULL_SPEED * time.delta_secs();
+            transform.translation.x += dx.clamp(-step, step);
+        }
 
-        // Despawn if below screen
+        // Despawn if below screen (letter tiles and capsules carry a label child)
         if transform.translation.y < -WINDOW_HEIGHT / 2.0 {
-            commands.entity(entity).despawn();
+            commands.entity(entity).despawn_recursive();
         }
     }
 }
 
-/// Detect collision between paddle and falling power-ups
-pub fn paddle_powerup_collision(
-    mut commands: Commands,
-    powerup_query: Query<(Entity, &Transform, &Collider, &PowerUp)>,
-    mut paddle_query: Query<(Entity, &Transform, &Collider, &mut Sprite, Option<&mut PowerUpEffects>), With<Paddle>>,
-    mut ball_query: Query<(&Transform, &mut Velocity), With<Ball>>,
-    level: Res<Level>,
-) {
-    let Ok((paddle_entity, paddle_transform, paddle_collider, mut paddle_sprite, existing_effects)) =
-        paddle_query.get_single_mut()
-    else {
-        return;
-    };
+/// World access shared by the power-up start/end hooks
+#[derive(SystemParam)]
+#[allow(clippy::type_complexity)]
+pub struct EffectContext<'w, 's> {
+    commands: Commands<'w, 's>,
+    paddle: Query<
+        'w,
+        's,
+        (&'static mut Sprite, &'static mut Collider, &'static PlayfieldId),
+        With<Paddle>,
+    >,
+    balls: Query<
+        'w,
+        's,
+        (Entity, &'static Transform, &'static mut Velocity, Option<&'static CaughtBall>, &'static PlayfieldId),
+        With<Ball>,
+    >,
+    ball_kinds: Query<'w, 's, (&'static mut BallKind, &'static PlayfieldId)>,
+    fog: Query<'w, 's, (Entity, &'static PlayfieldId), With<Fog>>,
+    barriers: Query<'w, 's, (&'static mut Barrier, &'static PlayfieldId)>,
+    lives: ResMut<'w, Lives>,
+    level: Res<'w, Level>,
+    ramp: Res<'w, SpeedRamp>,
+}
 
-    for (powerup_entity, powerup_transform, powerup_collider, powerup) in &powerup_query {
-        if aabb_collision(
-            powerup_transform.translation.truncate(),
-            powerup_collider.size,
-            paddle_transform.translation.truncate(),
-            paddle_collider.size,
-        ) {
-            // Despawn the power-up item
-            commands.entity(powerup_entity).despawn();
-
-            match powerup.power_type {
-                PowerUpType::WidePaddle => {
-                    apply_wide_paddle(
-                        &mut commands,
-                        paddle_entity,
-                        &mut paddle_sprite,
-                        &existing_effects,
-                    );
-                }
-                PowerUpType::MultiBall => {
-                    spawn_extra_balls(&mut commands, &ball_query, &level);
-                }
-                PowerUpType::SlowBall => {
-                    apply_slow_ball(
-                        &mut commands,
-                        paddle_entity,
-                        &mut ball_query,
-                        &existing_effects,
-                    );
-                }
-                PowerUpType::FireBall => {
-                    apply_fireball(
-                        &mut commands,
-                        paddle_entity,
-                        &existing_effects,
-                    );
+/// One paddle's active effects, taken out of its component while pickups are applied
+struct PaddleEffects {
+    paddle: Entity,
+    field: PlayfieldId,
+    effects: Vec<ActiveEffect>,
+}
+
+impl PaddleEffects {
+    fn has(&self, power_type: PowerUpType) -> bool {
+        self.effects.iter().any(|e| e.effect_type == power_type)
+    }
+}
+
+/// Whether `other` holds `power_type` for the same balls or fog as `paddle` in `field`:
+/// the paddle itself, or any paddle in the field for field-wide effects
+fn shares_effect(
+    power_type: PowerUpType,
+    paddle: Entity,
+    field: PlayfieldId,
+    other: Entity,
+    other_field: PlayfieldId,
+) -> bool {
+    other == paddle || (power_type.field_wide() && other_field == field)
+}
+
+impl EffectContext<'_, '_> {
+    /// Shared pickup pipeline: resolve conflicts, apply the stacking rule, start new effects.
+    /// `all` holds every paddle's effects, so field-wide effects see the other player's pickups.
+    fn pick_up(&mut self, picker: usize, def: &PowerUpDef, all: &mut [PaddleEffects]) {
+        let (paddle, field) = (all[picker].paddle, all[picker].field);
+        let holders = move |power_type: PowerUpType, other: &PaddleEffects| {
+            shares_effect(power_type, paddle, field, other.paddle, other.field)
+        };
+
+        // Conflicting effects cancel out instead of stacking
+        let conflict = def
+            .conflicts
+            .iter()
+            .copied()
+            .find(|c| all.iter().any(|other| holders(*c, other) && other.has(*c)));
+        if let Some(conflict) = conflict {
+            for other in all.iter_mut().filter(|other| holders(conflict, other)) {
+                other.effects.retain(|e| e.effect_type != conflict);
+            }
+            self.end(paddle, conflict);
+            return;
+        }
+
+        let Some(duration) = def.duration else {
+            self.start(paddle, def.power_type);
+            return;
+        };
+
+        // A field-wide effect another paddle already holds is running on the shared balls
+        let running = all.iter().any(|other| holders(def.power_type, other) && other.has(def.power_type));
+        let effects = &mut all[picker].effects;
+        let active = effects.iter().position(|e| e.effect_type == def.power_type);
+        match (active, def.stacking) {
+            (Some(i), StackRule::Refresh) => {
+                effects[i].timer = Timer::from_seconds(duration, TimerMode::Once);
+            }
+            (Some(i), StackRule::Extend) => {
+                let remaining = effects[i].timer.remaining_secs();
+                effects[i].timer = Timer::from_seconds(remaining + duration, TimerMode::Once);
+            }
+            _ => effects.push(ActiveEffect {
+                effect_type: def.power_type,
+                timer: Timer::from_seconds(duration, TimerMode::Once),
+            }),
+        }
+
+        if !running {
+            self.start(paddle, def.power_type);
+        }
+    }
+
+    /// Playfield of `paddle`, whose balls, fog and barrier its effects act on
+    fn field(&self, paddle: Entity) -> PlayfieldId {
+        self.paddle.get(paddle).map_or_else(|_| PlayfieldId::default(), |(_, _, field)| *field)
+    }
+
+    /// Apply an effect as it becomes active on `paddle` (or fire an instant pickup)
+    fn start(&mut self, paddle: Entity, power_type: PowerUpType) {
+        let field = self.field(paddle);
+        match power_type {
+            PowerUpType::WidePaddle => {
+                self.set_paddle_width(paddle, PADDLE_WIDTH * WIDE_PADDLE_MULTIPLIER)
+            }
+            PowerUpType::ShrinkPaddle => {
+                self.set_paddle_width(paddle, PADDLE_WIDTH * SHRINK_PADDLE_MULTIPLIER)
+            }
+            PowerUpType::SlowBall => self.scale_ball_speed(field, SLOW_BALL_MULTIPLIER),
+            PowerUpType::FastBall => self.scale_ball_speed(field, FAST_BALL_MULTIPLIER),
+            PowerUpType::MultiBall => self.spawn_extra_balls(field),
+            PowerUpType::Fog => self.spawn_fog(field),
+            PowerUpType::Barrier => self.spawn_barrier(field),
+            PowerUpType::ExtraLife => {
+                self.lives.spare = (self.lives.spare + 1).min(MAX_SPARE_LIVES);
+            }
+            PowerUpType::HeavyBall => self.set_ball_kind(field, BallKind::Heavy),
+            PowerUpType::BouncyBall => self.set_ball_kind(field, BallKind::Bouncy),
+            PowerUpType::SplitBall => self.set_ball_kind(field, BallKind::Splitter),
+            PowerUpType::GhostBall => self.set_ball_kind(field, BallKind::Ghost),
+            // Read directly by their systems while active
+            PowerUpType::FireBall
+            | PowerUpType::Laser
+            | PowerUpType::Catch
+            | PowerUpType::Magnet
+            | PowerUpType::ReverseControls => {}
+        }
+    }
+
+    /// Revert an effect when its last timer expires or a conflicting pickup cancels it
+    fn end(&mut self, paddle: Entity, power_type: PowerUpType) {
+        let field = self.field(paddle);
+        match power_type {
+            PowerUpType::WidePaddle | PowerUpType::ShrinkPaddle => {
+                self.set_paddle_width(paddle, PADDLE_WIDTH)
+            }
+            PowerUpType::SlowBall | PowerUpType::FastBall => self.restore_ball_speed(field),
+            PowerUpType::Catch => self.release_caught_balls(paddle),
+            PowerUpType::Fog => {
+                for (fog_entity, fog_field) in &self.fog {
+                    if *fog_field == field {
+                        self.commands.entity(fog_entity).despawn();
+                    }
                 }
             }
+            // Instant pickups and input-only effects have nothing to revert
+            // (lasers already in flight finish on their own)
+            // (the fireball color gives way to each ball's kind color in `update_fireball_visual`)
+            PowerUpType::MultiBall
+            | PowerUpType::FireBall
+            | PowerUpType::Laser
+            | PowerUpType::Barrier
+            | PowerUpType::ExtraLife
+            | PowerUpType::Magnet
+            | PowerUpType::HeavyBall
+            | PowerUpType::BouncyBall
+            | PowerUpType::SplitBall
+            | PowerUpType::GhostBall
+            | PowerUpType::ReverseControls => {}
         }
     }
-}
 
-/// Apply wide paddle effect
-fn apply_wide_paddle(
-    commands: &mut Commands,
-    paddle_entity: Entity,
-    paddle_sprite: &mut Sprite,
-    existing_effects: &Option<Mut<PowerUpEffects>>,
-) {
-    let wide_width = PADDLE_WIDTH * WIDE_PADDLE_MULTIPLIER;
+    fn set_paddle_width(&mut self, paddle: Entity, width: f32) {
+        if let Ok((mut sprite, mut collider, _)) = self.paddle.get_mut(paddle) {
+            let size = Vec2::new(width, PADDLE_HEIGHT);
+            sprite.custom_size = Some(size);
+            collider.size = size;
+        }
+    }
 
-    if let Some(effects) = existing_effects {
-        // Check if already has wide paddle — reset timer if so
-        let already_active = effects.effects.iter().any(|e| e.effect_type == PowerUpType::WidePaddle);
-        if already_active {
-            // Timer will be reset below by removing and re-inserting
-        } else {
-            // Apply the width change
-            paddle_sprite.custom_size = Some(Vec2::new(wide_width, PADDLE_HEIGHT));
-            commands.entity(paddle_entity).insert(Collider {
-                size: Vec2::new(wide_width, PADDLE_HEIGHT),
-            });
+    fn scale_ball_speed(&mut self, field: PlayfieldId, multiplier: f32) {
+        for (_, _, mut velocity, _, ball_field) in &mut self.balls {
+            if *ball_field == field {
+                velocity.0 *= multiplier;
+            }
         }
+    }
 
-        // Update effects: remove old WidePaddle and add fresh one
-        let mut new_effects: Vec<ActiveEffect> = effects
-            .effects
-            .iter()
-            .filter(|e| e.effect_type != PowerUpType::WidePaddle)
-            .map(|e| ActiveEffect {
-                effect_type: e.effect_type,
-                timer: e.timer.clone(),
-            })
-            .collect();
-        new_effects.push(ActiveEffect {
-            effect_type: PowerUpType::WidePaddle,
-            timer: Timer::from_seconds(WIDE_PADDLE_DURATION, TimerMode::Once),
-        });
-        commands.entity(paddle_entity).insert(PowerUpEffects { effects: new_effects });
-    } else {
-        // No effects component yet — apply width and create it
-        paddle_sprite.custom_size = Some(Vec2::new(wide_width, PADDLE_HEIGHT));
-        commands.entity(paddle_entity).insert(Collider {
-            size: Vec2::new(wide_width, PADDLE_HEIGHT),
-        });
-        commands.entity(paddle_entity).insert(PowerUpEffects {
-            effects: vec![ActiveEffect {
-                effect_type: PowerUpType::WidePaddle,
-                timer: Timer::from_seconds(WIDE_PADDLE_DURATION, TimerMode::Once),
-            }],
-        });
+    /// Normalize each ball's velocity in `field` back to the level speed, keeping the ramp earned so far
+    fn restore_ball_speed(&mut self, field: PlayfieldId) {
+        let speed = self.ramp.speed(&self.level, field.0);
+        for (_, _, mut velocity, _, ball_field) in &mut self.balls {
+            let dir = velocity.0.normalize_or_zero();
+            if *ball_field == field && dir != Vec2::ZERO {
+                velocity.0 = dir * speed;
+            }
+        }
     }
-}
 
-/// Spawn 2 extra balls from existing ball position
-fn spawn_extra_balls(
-    commands: &mut Commands,
-    ball_query: &Query<(&Transform, &mut Velocity), With<Ball>>,
-    _level: &Level,
-) {
-    // Get the first ball's position and velocity as reference
-    let Some((ball_transform, ball_velocity)) = ball_query.iter().next() else {
-        return;
-    };
+    /// Spawn 2 extra balls of the same kind from the position of the first ball in `field`
+    fn spawn_extra_balls(&mut self, field: PlayfieldId) {
+        let Some((ball_entity, ball_transform, ball_velocity, _, _)) =
+            self.balls.iter().find(|(_, _, _, _, ball_field)| **ball_field == field)
+        else {
+            return;
+        };
+
+        let pos = ball_transform.translation.truncate();
+        let speed = ball_velocity.0.length();
+        let base_angle = ball_velocity.0.y.atan2(ball_velocity.0.x);
+        let kind = self
+            .ball_kinds
+            .get(ball_entity)
+            .map_or_else(|_| BallKind::default(), |(kind, _)| *kind);
+
+        // Spawn 2 extra balls at ±30° from the original direction
+        for offset in &[0.52, -0.52] { // ~30 degrees in radians
+            let angle = base_angle + offset;
+            let velocity = Vec2::new(angle.cos() * speed, angle.sin() * speed);
+            spawn_kind_ball(&mut self.commands, field, pos, velocity, kind);
+        }
+    }
 
-    let pos = ball_transform.translation.truncate();
-    let speed = ball_velocity.0.length();
-    let base_angle = ball_velocity.0.y.atan2(ball_velocity.0.x);
+    /// Turn every ball in play in `field` into `kind`
+    fn set_ball_kind(&mut self, field: PlayfieldId, kind: BallKind) {
+        for (mut ball_kind, ball_field) in &mut self.ball_kinds {
+            if *ball_field == field {
+                *ball_kind = kind;
+            }
+        }
+    }
+
+    /// Cover the upper block rows of `field`
+    fn spawn_fog(&mut self, field: PlayfieldId) {
+        let top = WINDOW_HEIGHT / 2.0 - WALL_THICKNESS;
+        let bottom = grid_y(FOG_ROWS - 1) - (BLOCK_HEIGHT + BLOCK_GAP) / 2.0;
+        self.commands.spawn((
+            Sprite {
+                color: Color::srgba(0.10, 0.10, 0.20, FOG_ALPHA), // Near-opaque night fog
+                custom_size: Some(Vec2::new(WINDOW_WIDTH - 2.0 * WALL_THICKNESS, top - bottom)),
+                ..default()
+            },
+            Transform::from_xyz(field.origin_x(), (top + bottom) / 2.0, 2.0),
+            Fog,
+            field,
+        ));
+    }
 
-    // Spawn 2 extra balls at ±30° from the original direction
-    for offset in &[0.52, -0.52] { // ~30 degrees in radians
-        let angle = base_angle + offset;
-        let velocity = Vec2::new(angle.cos() * speed, angle.sin() * speed);
+    /// Spawn the safety barrier in `field`, or restart its timer if one is already up there
+    fn spawn_barrier(&mut self, field: PlayfieldId) {
+        let existing = self.barriers.iter_mut().find(|(_, barrier_field)| **barrier_field == field);
+        if let Some((mut barrier, _)) = existing {
+            barrier.timer.reset();
+            return;
+        }
 
-        commands.spawn((
+        let size = Vec2::new(WINDOW_WIDTH - 2.0 * WALL_THICKNESS, BARRIER_HEIGHT);
+        self.commands.spawn((
             Sprite {
-                color: Color::srgb(1.0, 0.96, 0.88), // Warm white (same as original ball)
-                custom_size: Some(Vec2::new(BALL_SIZE, BALL_SIZE)),
+                color: Color::srgb(0.30, 0.95, 0.75), // Aqua green
+                custom_size: Some(size),
                 ..default()
             },
-            Transform::from_xyz(pos.x, pos.y, 0.0),
-            Ball,
-            Velocity(velocity),
-            Collider {
-                size: Vec2::new(BALL_SIZE, BALL_SIZE),
+            Transform::from_xyz(field.origin_x(), BARRIER_Y, 0.0),
+            Barrier {
+                timer: Timer::from_seconds(BARRIER_DURATION, TimerMode::Once),
             },
+            Collider { size },
+            field,
         ));
     }
+
+    /// Launch every ball still held on `paddle`
+    fn release_caught_balls(&mut self, paddle: Entity) {
+        let paddle_width = self
+            .paddle
+            .get(paddle)
+            .map_or(PADDLE_WIDTH, |(_, collider, _)| collider.size.x);
+        for (ball_entity, _, mut velocity, caught, _) in &mut self.balls {
+            if let Some(caught) = caught.filter(|c| c.paddle == paddle) {
+                let speed = velocity.0.length();
+                velocity.0 = paddle_launch_velocity(caught.offset, paddle_width, speed);
+                self.commands.entity(ball_entity).remove::<CaughtBall>();
+            }
+        }
+    }
 }
 
-/// Apply slow ball effect
-fn apply_slow_ball(
-    commands: &mut Commands,
-    paddle_entity: Entity,
-    ball_query: &mut Query<(&Transform, &mut Velocity), With<Ball>>,
-    existing_effects: &Option<Mut<PowerUpEffects>>,
+/// Detect collision between the paddles and falling power-ups (each item goes to one paddle
+/// in its own field)
+pub fn paddle_powerup_collision(
+    mut ctx: EffectContext,
+    registry: Res<PowerUpRegistry>,
+    powerup_query: Query<(Entity, &Transform, &Collider, &PowerUp, &PlayfieldId), Without<Paddle>>,
+    mut paddle_query: Query<(Entity, &Transform, &PlayfieldId, Option<&mut PowerUpEffects>), With<Paddle>>,
+    mut collision_events: EventWriter<CollisionEvent>,
 ) {
-    if let Some(effects) = existing_effects {
-        let already_active = effects.effects.iter().any(|e| e.effect_type == PowerUpType::SlowBall);
+    let mut taken: Vec<Entity> = Vec::new();
+    let mut picks: Vec<(Entity, Vec<(Entity, PowerUpType)>)> = Vec::new();
 
-        if !already_active {
-            // Apply slow effect to all balls
-            for (_, mut velocity) in ball_query.iter_mut() {
-                velocity.0 *= SLOW_BALL_MULTIPLIER;
-            }
-        }
+    for (paddle_entity, paddle_transform, paddle_field, _) in &paddle_query {
+        let Ok((_, paddle_collider, _)) = ctx.paddle.get(paddle_entity) else {
+            continue;
+        };
+        let paddle_pos = paddle_transform.translation.truncate();
+        let paddle_size = paddle_collider.size;
 
-        // Update effects: remove old SlowBall and add fresh one
-        let mut new_effects: Vec<ActiveEffect> = effects
-            .effects
+        let picked: Vec<(Entity, PowerUpType)> = powerup_query
             .iter()
-            .filter(|e| e.effect_type != PowerUpType::SlowBall)
-            .map(|e| ActiveEffect {
-                effect_type: e.effect_type,
-                timer: e.timer.clone(),
+            .filter(|(entity, transform, collider, _, field)| {
+                !taken.contains(entity)
+                    && *field == paddle_field
+                    && aabb_collision(transform.translation.truncate(), collider.size, paddle_pos, paddle_size)
             })
+            .map(|(entity, _, _, powerup, _)| (entity, powerup.power_type))
             .collect();
-        new_effects.push(ActiveEffect {
-            effect_type: PowerUpType::SlowBall,
-            timer: Timer::from_seconds(SLOW_BALL_DURATION, TimerMode::Once),
-        });
-        commands.entity(paddle_entity).insert(PowerUpEffects { effects: new_effects });
-    } else {
-        // Apply slow effect and create effects component
-        for (_, mut velocity) in ball_query.iter_mut() {
-            velocity.0 *= SLOW_BALL_MULTIPLIER;
+        if !picked.is_empty() {
+            taken.extend(picked.iter().map(|(entity, _)| *entity));
+            picks.push((paddle_entity, picked));
+        }
+    }
+    if picks.is_empty() {
+        return;
+    }
+
+    // Work on every paddle's list so pickups in the same frame, and field-wide effects the
+    // other player holds, see each other
+    let mut all: Vec<PaddleEffects> = paddle_query
+        .iter_mut()
+        .map(|(paddle, _, field, effects)| PaddleEffects {
+            paddle,
+            field: *field,
+            effects: effects.map(|mut e| std::mem::take(&mut e.effects)).unwrap_or_default(),
+        })
+        .collect();
+
+    for (paddle_entity, picked) in picks {
+        let Some(picker) = all.iter().position(|p| p.paddle == paddle_entity) else {
+            continue;
+        };
+        for (powerup_entity, power_type) in picked {
+            // Despawn the power-up item
+            ctx.commands.entity(powerup_entity).despawn_recursive();
+
+            let def = registry.get(power_type);
+            collision_events.send(if def.hazard {
+                CollisionEvent::Hazard
+            } else {
+                CollisionEvent::PowerUp
+            });
+            ctx.pick_up(picker, def, &mut all);
+        }
+    }
+
+    for (paddle_entity, _, _, existing_effects) in &mut paddle_query {
+        let Some(index) = all.iter().position(|p| p.paddle == paddle_entity) else {
+            continue;
+        };
+        let effects = all.swap_remove(index).effects;
+        if effects.is_empty() {
+            ctx.commands.entity(paddle_entity).remove::<PowerUpEffects>();
+        } else if let Some(mut existing) = existing_effects {
+            existing.effects = effects;
+        } else {
+            ctx.commands.entity(paddle_entity).insert(PowerUpEffects { effects });
         }
-        commands.entity(paddle_entity).insert(PowerUpEffects {
-            effects: vec![ActiveEffect {
-                effect_type: PowerUpType::SlowBall,
-                timer: Timer::from_seconds(SLOW_BALL_DURATION, TimerMode::Once),
-            }],
-        });
     }
 }
 
-/// Apply fireball effect
-fn apply_fireball(
-    commands: &mut Commands,
-    paddle_entity: Entity,
-    existing_effects: &Option<Mut<PowerUpEffects>>,
+/// Tick the barrier, flash it near expiry, and remove it when time runs out
+pub fn update_barrier(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<GameSettings>,
+    mut query: Query<(Entity, &mut Barrier, &mut Sprite)>,
 ) {
-    if let Some(effects) = existing_effects {
-        // Update effects: remove old FireBall and add fresh one
-        let mut new_effects: Vec<ActiveEffect> = effects
-            .effects
-            .iter()
-            .filter(|e| e.effect_type != PowerUpType::FireBall)
-            .map(|e| ActiveEffect {
-                effect_type: e.effect_type,
-                timer: e.timer.clone(),
-            })
-            .collect();
-        new_effects.push(ActiveEffect {
-            effect_type: PowerUpType::FireBall,
-            timer: Timer::from_seconds(FIREBALL_DURATION, TimerMode::Once),
-        });
-        commands.entity(paddle_entity).insert(PowerUpEffects { effects: new_effects });
-    } else {
-        commands.entity(paddle_entity).insert(PowerUpEffects {
-            effects: vec![ActiveEffect {
-                effect_type: PowerUpType::FireBall,
-                timer: Timer::from_seconds(FIREBALL_DURATION, TimerMode::Once),
-            }],
-        });
+    for (entity, mut barrier, mut sprite) in &mut query {
+        barrier.timer.tick(time.delta());
+
+        if barrier.timer.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let remaining = barrier.timer.remaining_secs();
+        let alpha = if remaining < BARRIER_FLASH_TIME {
+            blink_alpha(remaining, settings.reduced_flashing)
+        } else {
+            1.0
+        };
+        sprite.color = sprite.color.with_alpha(alpha);
+    }
+}
+
+/// Sprite alpha for an expiry blink with `remaining` seconds left (a steady dim with reduced flashing)
+fn blink_alpha(remaining: f32, reduced_flashing: bool) -> f32 {
+    if reduced_flashing {
+        return REDUCED_FLASH_ALPHA;
     }
+    let phase = (remaining * 8.0).fract();
+    if phase < 0.5 { 0.25 } else { 1.0 }
+}
+
+/// Launch velocity for a ball leaving the paddle at `offset` from its center
+pub fn paddle_launch_velocity(offset: f32, paddle_width: f32, speed: f32) -> Vec2 {
+    let normalized = (offset / (paddle_width / 2.0)).clamp(-1.0, 1.0);
+    Vec2::new(normalized * speed * 0.8, speed).normalize() * speed
 }
 
-/// Update active power-up effects: tick timers and revert when expired
+/// Update active power-up effects: tick timers and revert when the last entry of a type expires
+/// (for field-wide effects, the last entry held by any paddle in the field)
 pub fn update_powerup_effects(
-    mut commands: Commands,
+    mut ctx: EffectContext,
     time: Res<Time>,
-    mut paddle_query: Query<(Entity, &mut PowerUpEffects, &mut Sprite, &mut Collider), With<Paddle>>,
-    mut ball_query: Query<&mut Velocity, With<Ball>>,
-    mut ball_sprite_query: Query<&mut Sprite, (With<Ball>, Without<Paddle>)>,
-    level: Res<Level>,
+    mut effects_query: Query<(Entity, &PlayfieldId, &mut PowerUpEffects), With<Paddle>>,
 ) {
-    let Ok((paddle_entity, mut effects_comp, mut sprite, mut collider)) =
-        paddle_query.get_single_mut()
-    else {
-        return;
-    };
+    let mut expired: Vec<(Entity, PlayfieldId, PowerUpType)> = Vec::new();
+    for (paddle_entity, field, mut effects_comp) in &mut effects_query {
+        effects_comp.effects.retain_mut(|effect| {
+            effect.timer.tick(time.delta());
+            if effect.timer.finished() {
+                expired.push((paddle_entity, *field, effect.effect_type));
+                false
+            } else {
+                true
+            }
+        });
+
+        // Remove the component entirely if no effects remain
+        if effects_comp.effects.is_empty() {
+            ctx.commands.entity(paddle_entity).remove::<PowerUpEffects>();
+        }
+    }
 
-    let mut expired = Vec::new();
+    let mut ended: Vec<(Entity, PlayfieldId, PowerUpType)> = Vec::new();
+    for (paddle_entity, field, effect_type) in expired {
+        let holders = |other: Entity, other_field: PlayfieldId| {
+            shares_effect(effect_type, paddle_entity, field, other, other_field)
+        };
+        // Stacked entries keep the effect alive until the last one runs out
+        let still_active = effects_query.iter().any(|(other, other_field, effects)| {
+            holders(other, *other_field) && effects.effects.iter().any(|e| e.effect_type == effect_type)
+        });
+        let already_ended = ended
+            .iter()
+            .any(|(other, other_field, t)| *t == effect_type && holders(*other, *other_field));
+        if !still_active && !already_ended {
+            ctx.end(paddle_entity, effect_type);
+            ended.push((paddle_entity, field, effect_type));
+        }
+    }
+}
+
+/// Color balls orange-red while fireball is active in their field, otherwise by their kind
+pub fn update_fireball_visual(
+    paddle_query: Query<(&PowerUpEffects, &PlayfieldId), With<Paddle>>,
+    mut ball_sprite_query: Query<(&mut Sprite, &BallKind, &PlayfieldId), With<Ball>>,
+) {
+    let fireball_fields = fireball_fields(&paddle_query);
 
-    for (i, effect) in effects_comp.effects.iter_mut().enumerate() {
-        effect.timer.tick(time.delta());
+    for (mut sprite, kind, field) in &mut ball_sprite_query {
+        sprite.color = if fireball_fields.contains(field) {
+            Color::srgb(1.0, 0.35, 0.15) // Orange-red
+        } else {
+            kind.color()
+        };
+    }
+}
+
+/// Fields where a paddle holds the fireball effect
+pub fn fireball_fields(
+    paddle_query: &Query<(&PowerUpEffects, &PlayfieldId), With<Paddle>>,
+) -> Vec<PlayfieldId> {
+    paddle_query
+        .iter()
+        .filter(|(effects, _)| effects.effects.iter().any(|e| e.effect_type == PowerUpType::FireBall))
+        .map(|(_, field)| *field)
+        .collect()
+}
 
-        if effect.timer.just_finished() {
-            expired.push((i, effect.effect_type));
+/// Longest remaining time per active effect type, in pickup order
+fn effect_remaining(effects: &PowerUpEffects) -> Vec<(PowerUpType, f32, f32)> {
+    let mut active: Vec<(PowerUpType, f32, f32)> = Vec::new();
+    for effect in &effects.effects {
+        let remaining = effect.timer.remaining_secs();
+        let fraction = effect.timer.fraction_remaining();
+        match active.iter_mut().find(|(t, _, _)| *t == effect.effect_type) {
+            Some(entry) if remaining > entry.1 => *entry = (effect.effect_type, remaining, fraction),
+            Some(_) => {}
+            None => active.push((effect.effect_type, remaining, fraction)),
         }
     }
+    active
+}
 
-    // Process expired effects in reverse order to keep indices valid
-    for (i, effect_type) in expired.iter().rev() {
-        match effect_type {
-            PowerUpType::WidePaddle => {
-                // Restore paddle to original size
-                sprite.custom_size = Some(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT));
-                collider.size = Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT);
-            }
-            PowerUpType::SlowBall => {
-                // Restore ball speed: normalize each ball's velocity to the correct speed
-                let target_speed = BALL_SPEED * level.speed_multiplier();
-                for mut velocity in &mut ball_query {
-                    let dir = velocity.0.normalize_or_zero();
-                    if dir != Vec2::ZERO {
-                        velocity.0 = dir * target_speed;
-                    }
+/// Rebuild each player's power-up HUD entries when their active set changes, and drain each bar
+pub fn update_powerup_hud(
+    mut commands: Commands,
+    registry: Res<PowerUpRegistry>,
+    paddle_query: Query<(&Player, Option<&PowerUpEffects>), With<Paddle>>,
+    hud_query: Query<(Entity, &PowerUpHud)>,
+    row_query: Query<&PowerUpHudRow>,
+    mut bar_query: Query<(&PowerUpHudBar, &mut Node)>,
+) {
+    for (hud_entity, hud) in &hud_query {
+        let active = paddle_query
+            .iter()
+            .find(|(player, _)| player.0 == hud.player)
+            .and_then(|(_, effects)| effects)
+            .map(effect_remaining)
+            .unwrap_or_default();
+
+        let rows: Vec<&PowerUpHudRow> = row_query.iter().filter(|row| row.player == hud.player).collect();
+        let unchanged = rows.len() == active.len()
+            && rows
+                .iter()
+                .all(|row| active.iter().any(|(t, _, _)| *t == row.effect_type));
+
+        if unchanged {
+            for (bar, mut node) in &mut bar_query {
+                if bar.player != hud.player {
+                    continue;
+                }
+                if let Some((_, _, fraction)) = active.iter().find(|(t, _, _)| *t == bar.effect_type) {
+                    node.width = Val::Percent(fraction * 100.0);
                 }
             }
-            PowerUpType::MultiBall => {
-                // MultiBall has no timer-based expiry
-            }
-            PowerUpType::FireBall => {
-                // Restore ball color to warm white
-                for mut sprite in &mut ball_sprite_query {
-                    sprite.color = Color::srgb(1.0, 0.96, 0.88);
+            continue;
+        }
+
+        rebuild_powerup_hud(&mut commands, &registry, hud_entity, hud, &active);
+    }
+}
+
+/// Replace a HUD strip's entries with one icon and bar per active effect
+fn rebuild_powerup_hud(
+    commands: &mut Commands,
+    registry: &PowerUpRegistry,
+    hud_entity: Entity,
+    hud: &PowerUpHud,
+    active: &[(PowerUpType, f32, f32)],
+) {
+    commands.entity(hud_entity).despawn_descendants();
+    commands.entity(hud_entity).with_children(|strip| {
+        for (effect_type, _, fraction) in active {
+            let def = registry.get(*effect_type);
+            strip
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(2.0),
+                        ..default()
+                    },
+                    PowerUpHudRow {
+                        effect_type: *effect_type,
+                        player: hud.player,
+                    },
+                ))
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new(def.icon),
+                        TextFont {
+                            font: hud.font.clone(),
+                            font_size: 14.0,
+                            font_smoothing: FontSmoothing::None,
+                        },
+                        TextColor(def.color),
+                    ));
+                    // Bar track with a draining fill
+                    row.spawn((
+                        Node {
+                            width: Val::Px(POWERUP_HUD_BAR_WIDTH),
+                            height: Val::Px(4.0),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.15)),
+                    ))
+                    .with_children(|track| {
+                        track.spawn((
+                            Node {
+                                width: Val::Percent(fraction * 100.0),
+                                height: Val::Percent(100.0),
+                                ..default()
+                            },
+                            BackgroundColor(def.color),
+                            PowerUpHudBar {
+                                effect_type: *effect_type,
+                                player: hud.player,
+                            },
+                        ));
+                    });
+                });
+        }
+    });
+}
+
+/// Which sprites warn that an effect is about to run out
+#[derive(PartialEq)]
+enum BlinkTarget {
+    Paddle,
+    Balls,
+    Fog,
+}
+
+fn blink_target(power_type: PowerUpType) -> BlinkTarget {
+    match power_type {
+        PowerUpType::SlowBall | PowerUpType::FastBall | PowerUpType::FireBall => BlinkTarget::Balls,
+        PowerUpType::Fog => BlinkTarget::Fog,
+        _ => BlinkTarget::Paddle,
+    }
+}
+
+/// Blink the paddle, balls or fog during the last seconds before an effect reverts
+/// (each paddle blinks for its own effects; a field's balls and fog follow the soonest effect
+/// to end there, which lasts as long as any paddle in the field still holds it)
+#[allow(clippy::type_complexity)]
+pub fn blink_expiring_effects(
+    settings: Res<GameSettings>,
+    mut paddle_query: Query<(Option<&PowerUpEffects>, &PlayfieldId, &mut Sprite), With<Paddle>>,
+    mut ball_query: Query<(&PlayfieldId, &mut Sprite), (With<Ball>, Without<Paddle>)>,
+    mut fog_query: Query<(&PlayfieldId, &mut Sprite), (With<Fog>, Without<Paddle>, Without<Ball>)>,
+) {
+    let alpha = |left: Option<f32>| left.map_or(1.0, |left| blink_alpha(left, settings.reduced_flashing));
+
+    // Longest remaining time of each ball or fog effect in each field
+    let mut shared: Vec<(PlayfieldId, PowerUpType, f32)> = Vec::new();
+    for (effects, field, mut paddle_sprite) in &mut paddle_query {
+        let mut paddle_left = None::<f32>;
+        for (effect_type, remaining, _) in effects.map(effect_remaining).unwrap_or_default() {
+            if blink_target(effect_type) != BlinkTarget::Paddle {
+                match shared.iter_mut().find(|(f, t, _)| f == field && *t == effect_type) {
+                    Some(entry) => entry.2 = entry.2.max(remaining),
+                    None => shared.push((*field, effect_type, remaining)),
                 }
+            } else if remaining < POWERUP_WARNING_TIME {
+                paddle_left = Some(paddle_left.map_or(remaining, |r: f32| r.min(remaining)));
             }
         }
+        paddle_sprite.color = paddle_sprite.color.with_alpha(alpha(paddle_left));
+    }
+
+    // Shortest remaining time among a field's expiring effects for one target
+    let soonest = |field: PlayfieldId, target: BlinkTarget| {
+        shared
+            .iter()
+            .filter(|(f, t, left)| *f == field && blink_target(*t) == target && *left < POWERUP_WARNING_TIME)
+            .map(|(_, _, left)| *left)
+            .min_by(f32::total_cmp)
+    };
+    for (field, mut sprite) in &mut ball_query {
+        sprite.color = sprite.color.with_alpha(alpha(soonest(*field, BlinkTarget::Balls)));
+    }
+    for (field, mut sprite) in &mut fog_query {
+        // Fog keeps its own translucency and only dims while blinking
+        sprite.color = sprite.color.with_alpha(FOG_ALPHA * alpha(soonest(*field, BlinkTarget::Fog)));
+    }
+}
+
+/// Fire twin lasers from a paddle's edges on its player's action while the laser effect is active
+pub fn fire_lasers(
+    mut commands: Commands,
+    time: Res<Time>,
+    actions: PlayerActions,
+    mut cooldown: ResMut<LaserCooldown>,
+    paddle_query: Query<(&Transform, &Collider, &Player, &PlayfieldId, &PowerUpEffects), With<Paddle>>,
+) {
+    for timer in &mut cooldown.0 {
+        timer.tick(time.delta());
+    }
+
+    for (paddle_transform, paddle_collider, player, field, effects) in &paddle_query {
+        if !effects.effects.iter().any(|e| e.effect_type == PowerUpType::Laser) {
+            continue;
+        }
+        if !cooldown.0[player.0].finished() || !actions.just_pressed(player.0) {
+            continue;
+        }
+
+        cooldown.0[player.0].reset();
 
-        effects_comp.effects.remove(*i);
+        let paddle_pos = paddle_transform.translation.truncate();
+        let offset_x = paddle_collider.size.x / 2.0 - LASER_WIDTH * 2.0;
+        let y = paddle_pos.y + (paddle_collider.size.y + LASER_HEIGHT) / 2.0;
+
+        for x in [paddle_pos.x - offset_x, paddle_pos.x + offset_x] {
+            commands.spawn((
+                Sprite {
+                    color: Color::srgb(1.0, 0.30, 0.30), // Bright red
+                    custom_size: Some(Vec2::new(LASER_WIDTH, LASER_HEIGHT)),
+                    ..default()
+                },
+                Transform::from_xyz(x, y, 0.5),
+                Laser,
+                *player,
+                *field,
+                Velocity(Vec2::new(0.0, LASER_SPEED)),
+                Collider {
+                    size: Vec2::new(LASER_WIDTH, LASER_HEIGHT),
+                },
+            ));
+        }
     }
+}
 
-    // Remove the component entirely if no effects remain
-    if effects_comp.effects.is_empty() {
-        commands.entity(paddle_entity).remove::<PowerUpEffects>();
+/// Keep caught balls resting on their paddle at the contact offset
+pub fn caught_ball_follow_paddle(
+    paddle_query: Query<(&Transform, &Collider), With<Paddle>>,
+    mut ball_query: Query<(&mut Transform, &Collider, &CaughtBall), Without<Paddle>>,
+) {
+    for (mut ball_transform, ball_collider, caught) in &mut ball_query {
+        let Ok((paddle_transform, paddle_collider)) = paddle_query.get(caught.paddle) else {
+            continue;
+        };
+        ball_transform.translation.x = paddle_transform.translation.x + caught.offset;
+        ball_transform.translation.y = paddle_transform.translation.y
+            + (paddle_collider.size.y + ball_collider.size.y) / 2.0;
     }
 }
 
-/// Update ball color while fireball is active
-pub fn update_fireball_visual(
-    paddle_query: Query<&PowerUpEffects, With<Paddle>>,
-    mut ball_sprite_query: Query<&mut Sprite, With<Ball>>,
+/// Release a paddle's caught balls together on its player's action or when the first hold timer runs out
+pub fn release_caught_balls(
+    mut commands: Commands,
+    time: Res<Time>,
+    actions: PlayerActions,
+    paddle_query: Query<(&Collider, &Player), With<Paddle>>,
+    mut ball_query: Query<(Entity, &mut Velocity, &mut CaughtBall)>,
 ) {
-    let is_fireball = paddle_query.iter().any(|effects| {
-        effects.effects.iter().any(|e| e.effect_type == PowerUpType::FireBall)
-    });
+    let mut timed_out: Vec<Entity> = Vec::new();
+    for (_, _, mut caught) in &mut ball_query {
+        caught.hold.tick(time.delta());
+        if caught.hold.finished() && !timed_out.contains(&caught.paddle) {
+            timed_out.push(caught.paddle);
+        }
+    }
+
+    for (ball_entity, mut velocity, caught) in &mut ball_query {
+        // A ball whose paddle is gone is released at once
+        let paddle = paddle_query.get(caught.paddle).ok();
+        let pressed = paddle.is_none_or(|(_, player)| actions.just_pressed(player.0));
+        if !(pressed || timed_out.contains(&caught.paddle)) {
+            continue;
+        }
+
+        let paddle_width = paddle.map_or(PADDLE_WIDTH, |(collider, _)| collider.size.x);
+        let speed = velocity.0.length();
+        velocity.0 = paddle_launch_velocity(caught.offset, paddle_width, speed);
+        commands.entity(ball_entity).remove::<CaughtBall>();
+    }
+}
+
+/// Move lasers upward and despawn when off-screen
+pub fn laser_movement(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Transform, &Velocity), With<Laser>>,
+) {
+    for (entity, mut transform, velocity) in &mut query {
+        transform.translation.y += velocity.0.y * time.delta_secs();
 
-    if is_fireball {
-        for mut sprite in &mut ball_sprite_query {
-            sprite.color = Color::srgb(1.0, 0.35, 0.15); // Orange-red
+        // Despawn if above screen
+        if transform.translation.y > WINDOW_HEIGHT / 2.0 {
+            commands.entity(entity).despawn();
         }
     }
 }
@@ -419,6 +936,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn paddle_ignores_capsules_in_other_field() {
+        let mut app = test_app();
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        let capsule = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+                PowerUp {
+                    power_type: PowerUpType::WidePaddle,
+                },
+                Collider {
+                    size: Vec2::splat(POWERUP_SIZE),
+                },
+                PlayfieldId(1),
+            ))
+            .id();
+
+        app.add_systems(Update, paddle_powerup_collision);
+        app.update();
+
+        assert!(app.world().get_entity(capsule).is_ok(), "Capsule keeps falling");
+        let sprite = app.world().entity(paddle).get::<Sprite>().unwrap();
+        assert_eq!(sprite.custom_size, Some(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)));
+    }
+
     #[test]
     fn multi_ball_spawns_two() {
         let mut app = test_app();
@@ -455,15 +998,43 @@ mod tests {
     }
 
     #[test]
-    fn slow_ball_reduces_speed() {
+    fn ball_kind_pickup_changes_every_ball_and_multiball_inherits_it() {
         let mut app = test_app();
         spawn_test_paddle(app.world_mut(), 0.0);
-        let ball = spawn_test_ball(
-            app.world_mut(),
-            Vec2::new(0.0, 0.0),
-            Vec2::new(0.0, BALL_SPEED),
-        );
-        app.world_mut().spawn((
+        spawn_test_ball(app.world_mut(), Vec2::ZERO, Vec2::new(0.0, BALL_SPEED));
+        spawn_test_ball(app.world_mut(), Vec2::new(50.0, 0.0), Vec2::new(0.0, BALL_SPEED));
+        for (x, power_type) in [(0.0, PowerUpType::GhostBall), (30.0, PowerUpType::MultiBall)] {
+            app.world_mut().spawn((
+                Transform::from_xyz(x, PADDLE_Y, 0.0),
+                PowerUp { power_type },
+                Collider {
+                    size: Vec2::splat(POWERUP_SIZE),
+                },
+            ));
+        }
+
+        app.add_systems(Update, (paddle_powerup_collision, update_fireball_visual).chain());
+        app.update();
+        app.update();
+
+        let mut balls = app.world_mut().query::<(&BallKind, &Sprite)>();
+        let balls: Vec<_> = balls.iter(app.world()).collect();
+        assert_eq!(balls.len(), 4);
+        assert!(balls
+            .iter()
+            .all(|(kind, sprite)| **kind == BallKind::Ghost && sprite.color == BallKind::Ghost.color()));
+    }
+
+    #[test]
+    fn slow_ball_reduces_speed() {
+        let mut app = test_app();
+        spawn_test_paddle(app.world_mut(), 0.0);
+        let ball = spawn_test_ball(
+            app.world_mut(),
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.0, BALL_SPEED),
+        );
+        app.world_mut().spawn((
             Sprite {
                 custom_size: Some(Vec2::splat(POWERUP_SIZE)),
                 ..default()
@@ -568,4 +1139,829 @@ mod tests {
             Vec2::new(10.0, 10.0),
         ));
     }
+
+    /// App with input resources and a paddle that has the laser effect active
+    fn laser_app() -> App {
+        let mut app = test_app();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<Touches>();
+        app.world_mut().spawn((
+            Sprite {
+                custom_size: Some(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+                ..default()
+            },
+            Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+            Paddle,
+            Collider {
+                size: Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT),
+            },
+            PowerUpEffects {
+                effects: vec![ActiveEffect {
+                    effect_type: PowerUpType::Laser,
+                    timer: Timer::from_seconds(LASER_DURATION, TimerMode::Once),
+                }],
+            },
+        ));
+        app
+    }
+
+    #[test]
+    fn laser_effect_created() {
+        let mut app = test_app();
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        app.world_mut().spawn((
+            Sprite {
+                custom_size: Some(Vec2::splat(POWERUP_SIZE)),
+                ..default()
+            },
+            Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+            PowerUp {
+                power_type: PowerUpType::Laser,
+            },
+            Collider {
+                size: Vec2::splat(POWERUP_SIZE),
+            },
+        ));
+        spawn_test_ball(app.world_mut(), Vec2::new(0.0, 0.0), Vec2::new(0.0, BALL_SPEED));
+
+        app.add_systems(Update, paddle_powerup_collision);
+        app.update();
+
+        let effects = app.world().entity(paddle).get::<PowerUpEffects>().unwrap();
+        assert_eq!(effects.effects.len(), 1);
+        assert_eq!(effects.effects[0].effect_type, PowerUpType::Laser);
+    }
+
+    #[test]
+    fn fire_lasers_spawns_twin_projectiles() {
+        let mut app = laser_app();
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+
+        app.add_systems(Update, fire_lasers);
+        app.update();
+
+        let xs: Vec<f32> = app
+            .world_mut()
+            .query_filtered::<&Transform, With<Laser>>()
+            .iter(app.world())
+            .map(|t| t.translation.x)
+            .collect();
+        assert_eq!(xs.len(), 2, "Should fire one laser from each paddle edge");
+        assert!(xs.iter().any(|x| *x < 0.0) && xs.iter().any(|x| *x > 0.0));
+    }
+
+    #[test]
+    fn fire_lasers_respects_cooldown() {
+        let mut app = laser_app();
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+
+        app.add_systems(Update, fire_lasers);
+        // Input stays "just pressed" without InputPlugin, so only the cooldown gates the second frame
+        app.update();
+        app.update();
+
+        let count = app
+            .world_mut()
+            .query::<&Laser>()
+            .iter(app.world())
+            .count();
+        assert_eq!(count, 2, "Second shot should wait for the cooldown");
+    }
+
+    #[test]
+    fn fire_lasers_requires_effect() {
+        let mut app = test_app();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<Touches>();
+        spawn_test_paddle(app.world_mut(), 0.0);
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+
+        app.add_systems(Update, fire_lasers);
+        app.update();
+
+        let count = app
+            .world_mut()
+            .query::<&Laser>()
+            .iter(app.world())
+            .count();
+        assert_eq!(count, 0, "No lasers without the laser effect");
+    }
+
+    #[test]
+    fn laser_despawns_above_screen() {
+        let mut app = test_app();
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, WINDOW_HEIGHT / 2.0 - 1.0, 0.0),
+            Laser,
+            Velocity(Vec2::new(0.0, LASER_SPEED)),
+        ));
+
+        app.add_systems(Update, laser_movement);
+        app.update();
+
+        let count = app
+            .world_mut()
+            .query::<&Laser>()
+            .iter(app.world())
+            .count();
+        assert_eq!(count, 0, "Laser above screen should be despawned");
+    }
+
+    /// Spawn a ball already held on the paddle at `offset`
+    fn spawn_caught_ball(world: &mut World, paddle: Entity, offset: f32, hold: f32) -> Entity {
+        let ball = spawn_test_ball(world, Vec2::new(offset, PADDLE_Y), Vec2::new(0.0, -BALL_SPEED));
+        world.entity_mut(ball).insert(CaughtBall {
+            paddle,
+            offset,
+            hold: Timer::from_seconds(hold, TimerMode::Once),
+        });
+        ball
+    }
+
+    #[test]
+    fn caught_ball_follows_paddle() {
+        let mut app = test_app();
+        let paddle = spawn_test_paddle(app.world_mut(), 50.0);
+        let ball = spawn_caught_ball(app.world_mut(), paddle, -10.0, CATCH_HOLD_TIME);
+
+        app.add_systems(Update, caught_ball_follow_paddle);
+        app.update();
+
+        let transform = app.world().entity(ball).get::<Transform>().unwrap();
+        assert_eq!(transform.translation.x, 40.0);
+        assert!(transform.translation.y > PADDLE_Y, "Ball should rest on top of the paddle");
+    }
+
+    #[test]
+    fn caught_balls_release_together_on_input() {
+        let mut app = test_app();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<Touches>();
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        let left = spawn_caught_ball(app.world_mut(), paddle, -30.0, CATCH_HOLD_TIME);
+        let right = spawn_caught_ball(app.world_mut(), paddle, 30.0, CATCH_HOLD_TIME);
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+
+        app.add_systems(Update, release_caught_balls);
+        app.update();
+
+        for ball in [left, right] {
+            assert!(app.world().entity(ball).get::<CaughtBall>().is_none(), "All caught balls should release");
+            let vel = app.world().entity(ball).get::<Velocity>().unwrap();
+            assert!(vel.0.y > 0.0, "Released ball should launch upward");
+            assert!((vel.0.length() - BALL_SPEED).abs() < 1.0, "Speed should be preserved");
+        }
+        let left_vel = app.world().entity(left).get::<Velocity>().unwrap();
+        let right_vel = app.world().entity(right).get::<Velocity>().unwrap();
+        assert!(left_vel.0.x < 0.0 && right_vel.0.x > 0.0, "Launch angle should follow the offset");
+    }
+
+    #[test]
+    fn caught_balls_release_after_hold_timeout() {
+        let mut app = test_app();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<Touches>();
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        // First ball's hold is nearly over; the second was just caught
+        let first = spawn_caught_ball(app.world_mut(), paddle, 0.0, 0.01);
+        let second = spawn_caught_ball(app.world_mut(), paddle, 10.0, CATCH_HOLD_TIME);
+
+        app.add_systems(Update, release_caught_balls);
+        app.update();
+
+        assert!(app.world().entity(first).get::<CaughtBall>().is_none());
+        assert!(app.world().entity(second).get::<CaughtBall>().is_none(), "Balls release together");
+    }
+
+    #[test]
+    fn catch_expiry_releases_balls() {
+        let mut app = test_app();
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        app.world_mut().entity_mut(paddle).insert(PowerUpEffects {
+            effects: vec![ActiveEffect {
+                effect_type: PowerUpType::Catch,
+                timer: Timer::from_seconds(0.01, TimerMode::Once),
+            }],
+        });
+        let ball = spawn_caught_ball(app.world_mut(), paddle, 0.0, CATCH_HOLD_TIME);
+
+        app.add_systems(Update, update_powerup_effects);
+        app.update();
+
+        assert!(app.world().entity(ball).get::<CaughtBall>().is_none(), "Expiry should release the ball");
+        let vel = app.world().entity(ball).get::<Velocity>().unwrap();
+        assert!(vel.0.y > 0.0);
+    }
+
+    /// Spawn a power-up item overlapping a paddle at x = 0
+    fn spawn_pickup(world: &mut World, power_type: PowerUpType) {
+        spawn_pickup_at(world, 0.0, power_type);
+    }
+
+    /// Spawn a power-up item overlapping a paddle at `x`
+    fn spawn_pickup_at(world: &mut World, x: f32, power_type: PowerUpType) -> Entity {
+        world
+            .spawn((
+                Sprite {
+                    custom_size: Some(Vec2::splat(POWERUP_SIZE)),
+                    ..default()
+                },
+                Transform::from_xyz(x, PADDLE_Y, 0.0),
+                PowerUp { power_type },
+                Collider {
+                    size: Vec2::splat(POWERUP_SIZE),
+                },
+            ))
+            .id()
+    }
+
+    #[test]
+    fn shrink_paddle_shrinks() {
+        let mut app = test_app();
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        spawn_pickup(app.world_mut(), PowerUpType::ShrinkPaddle);
+
+        app.add_systems(Update, paddle_powerup_collision);
+        app.update();
+
+        let collider = app.world().entity(paddle).get::<Collider>().unwrap();
+        assert_eq!(collider.size.x, PADDLE_WIDTH * SHRINK_PADDLE_MULTIPLIER);
+    }
+
+    #[test]
+    fn shrink_cancels_wide_paddle() {
+        let mut app = test_app();
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        spawn_pickup(app.world_mut(), PowerUpType::WidePaddle);
+        app.add_systems(Update, paddle_powerup_collision);
+        app.update();
+
+        spawn_pickup(app.world_mut(), PowerUpType::ShrinkPaddle);
+        app.update();
+
+        let collider = app.world().entity(paddle).get::<Collider>().unwrap();
+        assert_eq!(collider.size.x, PADDLE_WIDTH, "Wide and shrink should cancel to normal width");
+        assert!(
+            app.world().entity(paddle).get::<PowerUpEffects>().is_none(),
+            "Neither effect should remain active"
+        );
+    }
+
+    #[test]
+    fn fast_ball_cancels_slow_ball() {
+        let mut app = test_app();
+        spawn_test_paddle(app.world_mut(), 0.0);
+        let ball = spawn_test_ball(app.world_mut(), Vec2::ZERO, Vec2::new(0.0, BALL_SPEED));
+        spawn_pickup(app.world_mut(), PowerUpType::SlowBall);
+        app.add_systems(Update, paddle_powerup_collision);
+        app.update();
+
+        spawn_pickup(app.world_mut(), PowerUpType::FastBall);
+        app.update();
+
+        let vel = app.world().entity(ball).get::<Velocity>().unwrap();
+        assert!(
+            (vel.0.length() - BALL_SPEED).abs() < 1.0,
+            "Slow and fast should cancel to normal speed, got {}",
+            vel.0.length()
+        );
+    }
+
+    #[test]
+    fn speed_ramp_keeps_slow_ratio_and_survives_restore() {
+        let mut app = test_app();
+        spawn_test_paddle(app.world_mut(), 0.0);
+        let ball = spawn_test_ball(app.world_mut(), Vec2::ZERO, Vec2::new(0.0, BALL_SPEED));
+        spawn_pickup(app.world_mut(), PowerUpType::SlowBall);
+        app.add_systems(
+            Update,
+            (paddle_powerup_collision, crate::systems::movement::apply_speed_ramp).chain(),
+        );
+        app.update();
+
+        // Speed-up earned while slowed: still slowed, but ramped
+        app.world_mut().resource_mut::<SpeedRamp>().top_wall_hit(&Level::default(), 0);
+        app.update();
+        let speed = app.world().get::<Velocity>(ball).unwrap().0.length();
+        let ramped = BALL_SPEED * (1.0 + SPEED_RAMP_TOP_WALL);
+        assert!((speed - ramped * SLOW_BALL_MULTIPLIER).abs() < 1.0, "got {speed}");
+
+        // Ending the slow effect restores the ramped speed, not the bare level speed
+        spawn_pickup(app.world_mut(), PowerUpType::FastBall);
+        app.update();
+        let speed = app.world().get::<Velocity>(ball).unwrap().0.length();
+        assert!((speed - ramped).abs() < 1.0, "got {speed}");
+    }
+
+    #[test]
+    fn fog_covers_until_expiry() {
+        let mut app = test_app();
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        spawn_pickup(app.world_mut(), PowerUpType::Fog);
+        app.add_systems(Update, paddle_powerup_collision);
+        app.update();
+
+        let fog_count = app.world_mut().query::<&Fog>().iter(app.world()).count();
+        assert_eq!(fog_count, 1, "Fog overlay should be spawned");
+
+        // Force the fog effect to expire on the next tick
+        app.world_mut()
+            .entity_mut(paddle)
+            .get_mut::<PowerUpEffects>()
+            .unwrap()
+            .effects[0]
+            .timer = Timer::from_seconds(0.01, TimerMode::Once);
+        app.add_systems(Update, update_powerup_effects);
+        app.update();
+
+        let fog_count = app.world_mut().query::<&Fog>().iter(app.world()).count();
+        assert_eq!(fog_count, 0, "Fog should clear when the effect expires");
+    }
+
+    /// Co-op paddles sharing one field: 1P at x = -200, 2P at x = 200
+    fn spawn_coop_paddles(world: &mut World) -> [Entity; 2] {
+        let left = spawn_test_paddle(world, -200.0);
+        let right = spawn_test_paddle(world, 200.0);
+        world.entity_mut(right).insert(Player(1));
+        [left, right]
+    }
+
+    /// Make every effect on `paddle` run out on the next tick
+    fn expire_effects(app: &mut App, paddle: Entity) {
+        let mut effects = app.world_mut().get_mut::<PowerUpEffects>(paddle).unwrap();
+        for effect in &mut effects.effects {
+            effect.timer = Timer::from_seconds(0.01, TimerMode::Once);
+        }
+    }
+
+    #[test]
+    fn ball_speed_effects_are_shared_between_coop_paddles() {
+        let mut app = test_app();
+        let [left, right] = spawn_coop_paddles(app.world_mut());
+        let ball = spawn_test_ball(app.world_mut(), Vec2::ZERO, Vec2::new(0.0, BALL_SPEED));
+        app.add_systems(Update, (paddle_powerup_collision, update_powerup_effects).chain());
+        let speed = |app: &App| app.world().get::<Velocity>(ball).unwrap().0.length();
+
+        // 2P's fast ball cancels 1P's slow ball instead of multiplying it
+        spawn_pickup_at(app.world_mut(), -200.0, PowerUpType::SlowBall);
+        app.update();
+        spawn_pickup_at(app.world_mut(), 200.0, PowerUpType::FastBall);
+        app.update();
+        assert!((speed(&app) - BALL_SPEED).abs() < 1.0, "got {}", speed(&app));
+        assert!(app.world().get::<PowerUpEffects>(left).is_none());
+        assert!(app.world().get::<PowerUpEffects>(right).is_none());
+
+        // Both players slowing the ball slow it once, until the last slow effect ends
+        spawn_pickup_at(app.world_mut(), -200.0, PowerUpType::SlowBall);
+        spawn_pickup_at(app.world_mut(), 200.0, PowerUpType::SlowBall);
+        app.update();
+        let slowed = BALL_SPEED * SLOW_BALL_MULTIPLIER;
+        assert!((speed(&app) - slowed).abs() < 1.0, "got {}", speed(&app));
+
+        expire_effects(&mut app, left);
+        app.update();
+        assert!((speed(&app) - slowed).abs() < 1.0, "2P's slow ball still runs");
+
+        expire_effects(&mut app, right);
+        app.update();
+        assert!((speed(&app) - BALL_SPEED).abs() < 1.0, "got {}", speed(&app));
+    }
+
+    #[test]
+    fn fog_stays_while_either_coop_player_holds_it() {
+        let mut app = test_app();
+        let [left, right] = spawn_coop_paddles(app.world_mut());
+        app.add_systems(Update, (paddle_powerup_collision, update_powerup_effects).chain());
+        let fog_count = |app: &mut App| app.world_mut().query::<&Fog>().iter(app.world()).count();
+
+        spawn_pickup_at(app.world_mut(), -200.0, PowerUpType::Fog);
+        spawn_pickup_at(app.world_mut(), 200.0, PowerUpType::Fog);
+        app.update();
+        assert_eq!(fog_count(&mut app), 1, "One fog covers the shared field");
+
+        expire_effects(&mut app, left);
+        app.update();
+        assert_eq!(fog_count(&mut app), 1, "2P's fog is still active");
+
+        expire_effects(&mut app, right);
+        app.update();
+        assert_eq!(fog_count(&mut app), 0);
+    }
+
+    #[test]
+    fn pickups_act_only_on_their_own_field() {
+        let mut app = test_app();
+        let field = PlayfieldId(1);
+        let x = field.origin_x();
+        let paddle = spawn_test_paddle(app.world_mut(), x);
+        app.world_mut().entity_mut(paddle).insert(field);
+        let home = spawn_test_ball(app.world_mut(), Vec2::new(x, 0.0), Vec2::new(0.0, BALL_SPEED));
+        app.world_mut().entity_mut(home).insert(field);
+        let other = spawn_test_ball(app.world_mut(), Vec2::ZERO, Vec2::new(0.0, BALL_SPEED));
+        let picked = [
+            PowerUpType::SlowBall,
+            PowerUpType::HeavyBall,
+            PowerUpType::Fog,
+            PowerUpType::Barrier,
+        ];
+        for power_type in picked {
+            let item = spawn_pickup_at(app.world_mut(), x, power_type);
+            app.world_mut().entity_mut(item).insert(field);
+        }
+        app.add_systems(Update, paddle_powerup_collision);
+        app.update();
+
+        let speed = |ball: Entity| app.world().get::<Velocity>(ball).unwrap().0.length();
+        assert!((speed(home) - BALL_SPEED * SLOW_BALL_MULTIPLIER).abs() < 1.0);
+        assert!((speed(other) - BALL_SPEED).abs() < 1.0, "The other field's ball keeps its speed");
+        assert_eq!(app.world().get::<BallKind>(home), Some(&BallKind::Heavy));
+        assert_eq!(app.world().get::<BallKind>(other), Some(&BallKind::Normal));
+
+        let fog: Vec<(f32, PlayfieldId)> = app
+            .world_mut()
+            .query_filtered::<(&Transform, &PlayfieldId), With<Fog>>()
+            .iter(app.world())
+            .map(|(transform, field)| (transform.translation.x, *field))
+            .collect();
+        assert_eq!(fog, vec![(x, field)]);
+        let barriers: Vec<(f32, PlayfieldId)> = app
+            .world_mut()
+            .query_filtered::<(&Transform, &PlayfieldId), With<Barrier>>()
+            .iter(app.world())
+            .map(|(transform, field)| (transform.translation.x, *field))
+            .collect();
+        assert_eq!(barriers, vec![(x, field)]);
+
+        // Multi-ball splits a ball from the picking paddle's field
+        let item = spawn_pickup_at(app.world_mut(), x, PowerUpType::MultiBall);
+        app.world_mut().entity_mut(item).insert(field);
+        app.update();
+        let fields: Vec<PlayfieldId> = app
+            .world_mut()
+            .query_filtered::<&PlayfieldId, With<Ball>>()
+            .iter(app.world())
+            .copied()
+            .collect();
+        assert_eq!(fields.iter().filter(|f| **f == field).count(), 3);
+        assert_eq!(fields.len(), 4);
+    }
+
+    #[test]
+    fn hazard_pickup_plays_hazard_sound() {
+        let mut app = test_app();
+        spawn_test_paddle(app.world_mut(), 0.0);
+        spawn_pickup(app.world_mut(), PowerUpType::ReverseControls);
+
+        app.add_systems(
+            Update,
+            paddle_powerup_collision.before(collect_collision_events),
+        );
+        app.add_systems(Update, collect_collision_events);
+        app.init_resource::<CollectedEvents>();
+        app.update();
+
+        let events = app.world().resource::<CollectedEvents>();
+        assert!(events.events.contains(&CollisionEvent::Hazard));
+        assert!(!events.events.contains(&CollisionEvent::PowerUp));
+    }
+
+    #[test]
+    fn extra_life_adds_spare_up_to_cap() {
+        let mut app = test_app();
+        spawn_test_paddle(app.world_mut(), 0.0);
+        app.world_mut().resource_mut::<Lives>().spare = MAX_SPARE_LIVES - 1;
+        spawn_pickup(app.world_mut(), PowerUpType::ExtraLife);
+        spawn_pickup(app.world_mut(), PowerUpType::ExtraLife);
+
+        app.add_systems(Update, paddle_powerup_collision);
+        app.update();
+
+        assert_eq!(app.world().resource::<Lives>().spare, MAX_SPARE_LIVES);
+    }
+
+    #[test]
+    fn barrier_pickup_spawns_single_barrier() {
+        let mut app = test_app();
+        spawn_test_paddle(app.world_mut(), 0.0);
+        spawn_pickup(app.world_mut(), PowerUpType::Barrier);
+        app.add_systems(Update, paddle_powerup_collision);
+        app.update();
+
+        // A second pickup refreshes the existing barrier instead of stacking
+        spawn_pickup(app.world_mut(), PowerUpType::Barrier);
+        app.update();
+
+        let count = app.world_mut().query::<&Barrier>().iter(app.world()).count();
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn barrier_flashes_then_expires() {
+        let mut app = test_app();
+        let barrier = app
+            .world_mut()
+            .spawn((
+                Sprite::default(),
+                Barrier {
+                    timer: Timer::from_seconds(BARRIER_FLASH_TIME, TimerMode::Once),
+                },
+            ))
+            .id();
+        app.add_systems(Update, update_barrier);
+
+        let mut saw_dim = false;
+        for _ in 0..(BARRIER_FLASH_TIME * 60.0) as usize + 2 {
+            app.update();
+            match app.world().get::<Sprite>(barrier) {
+                Some(sprite) => saw_dim |= sprite.color.alpha() < 1.0,
+                None => break,
+            }
+        }
+
+        assert!(saw_dim, "Barrier should blink before expiring");
+        assert!(app.world().get_entity(barrier).is_err(), "Barrier should expire");
+    }
+
+    // --- Stacking rules ---
+
+    fn effect_timers(app: &App, paddle: Entity, effect_type: PowerUpType) -> Vec<f32> {
+        app.world()
+            .entity(paddle)
+            .get::<PowerUpEffects>()
+            .map(|e| {
+                e.effects
+                    .iter()
+                    .filter(|e| e.effect_type == effect_type)
+                    .map(|e| e.timer.remaining_secs())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn refresh_rule_restarts_timer() {
+        let mut app = test_app();
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        spawn_pickup(app.world_mut(), PowerUpType::WidePaddle);
+        app.add_systems(Update, (paddle_powerup_collision, update_powerup_effects).chain());
+        for _ in 0..30 {
+            app.update();
+        }
+
+        spawn_pickup(app.world_mut(), PowerUpType::WidePaddle);
+        app.update();
+
+        let timers = effect_timers(&app, paddle, PowerUpType::WidePaddle);
+        assert_eq!(timers.len(), 1);
+        assert!(timers[0] > WIDE_PADDLE_DURATION - 0.1, "got {}", timers[0]);
+    }
+
+    #[test]
+    fn extend_rule_adds_to_remaining_time() {
+        let mut app = test_app();
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        spawn_pickup(app.world_mut(), PowerUpType::FireBall);
+        app.add_systems(Update, paddle_powerup_collision);
+        app.update();
+
+        spawn_pickup(app.world_mut(), PowerUpType::FireBall);
+        app.update();
+
+        let timers = effect_timers(&app, paddle, PowerUpType::FireBall);
+        assert_eq!(timers, vec![FIREBALL_DURATION * 2.0]);
+    }
+
+    #[test]
+    fn stack_rule_keeps_effect_until_last_timer() {
+        let mut app = test_app();
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        spawn_pickup(app.world_mut(), PowerUpType::Laser);
+        app.add_systems(Update, paddle_powerup_collision);
+        app.update();
+        spawn_pickup(app.world_mut(), PowerUpType::Laser);
+        app.update();
+        assert_eq!(effect_timers(&app, paddle, PowerUpType::Laser).len(), 2);
+
+        // Expire only the first stack
+        app.world_mut()
+            .entity_mut(paddle)
+            .get_mut::<PowerUpEffects>()
+            .unwrap()
+            .effects[0]
+            .timer = Timer::from_seconds(0.01, TimerMode::Once);
+        app.add_systems(Update, update_powerup_effects);
+        app.update();
+
+        assert_eq!(
+            effect_timers(&app, paddle, PowerUpType::Laser).len(),
+            1,
+            "Second laser stack should still be running"
+        );
+    }
+
+    // --- Timer HUD and expiry blink ---
+
+    fn spawn_hud(world: &mut World) -> Entity {
+        world
+            .spawn((Node::default(), PowerUpHud { font: Handle::default(), player: 0 }))
+            .id()
+    }
+
+    fn give_effect(world: &mut World, paddle: Entity, effect_type: PowerUpType, secs: f32) {
+        let effect = ActiveEffect {
+            effect_type,
+            timer: Timer::from_seconds(secs, TimerMode::Once),
+        };
+        match world.get_mut::<PowerUpEffects>(paddle) {
+            Some(mut effects) => effects.effects.push(effect),
+            None => {
+                world.entity_mut(paddle).insert(PowerUpEffects {
+                    effects: vec![effect],
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn hud_lists_active_effects_with_draining_bars() {
+        let mut app = test_app();
+        spawn_hud(app.world_mut());
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        give_effect(app.world_mut(), paddle, PowerUpType::WidePaddle, 8.0);
+        give_effect(app.world_mut(), paddle, PowerUpType::FireBall, 8.0);
+        app.add_systems(Update, (update_powerup_effects, update_powerup_hud).chain());
+        app.update();
+
+        let mut rows: Vec<PowerUpType> = app
+            .world_mut()
+            .query::<&PowerUpHudRow>()
+            .iter(app.world())
+            .map(|r| r.effect_type)
+            .collect();
+        rows.sort_by_key(|t| *t as u8);
+        assert_eq!(rows, vec![PowerUpType::WidePaddle, PowerUpType::FireBall]);
+
+        for _ in 0..60 {
+            app.update();
+        }
+        let widths: Vec<Val> = app
+            .world_mut()
+            .query::<(&PowerUpHudBar, &Node)>()
+            .iter(app.world())
+            .map(|(_, node)| node.width)
+            .collect();
+        assert_eq!(widths.len(), 2);
+        for width in widths {
+            let Val::Percent(pct) = width else {
+                panic!("bar width should be a percentage");
+            };
+            assert!(pct < 90.0 && pct > 80.0, "bar should drain, got {pct}");
+        }
+    }
+
+    #[test]
+    fn hud_clears_when_effects_end() {
+        let mut app = test_app();
+        spawn_hud(app.world_mut());
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        give_effect(app.world_mut(), paddle, PowerUpType::Laser, 0.01);
+        app.add_systems(Update, (update_powerup_effects, update_powerup_hud).chain());
+        app.update();
+        app.update();
+
+        let rows = app.world_mut().query::<&PowerUpHudRow>().iter(app.world()).count();
+        assert_eq!(rows, 0);
+    }
+
+    #[test]
+    fn paddle_blinks_before_paddle_effect_expires() {
+        let mut app = test_app();
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        let ball = spawn_test_ball(app.world_mut(), Vec2::ZERO, Vec2::new(0.0, BALL_SPEED));
+        give_effect(app.world_mut(), paddle, PowerUpType::WidePaddle, 1.0);
+        app.add_systems(Update, blink_expiring_effects);
+        app.update();
+
+        let paddle_alpha = app.world().get::<Sprite>(paddle).unwrap().color.alpha();
+        let ball_alpha = app.world().get::<Sprite>(ball).unwrap().color.alpha();
+        assert!(paddle_alpha < 1.0, "Paddle should blink near expiry");
+        assert_eq!(ball_alpha, 1.0, "Ball is unaffected by a paddle effect");
+    }
+
+    #[test]
+    fn no_blink_while_plenty_of_time_left() {
+        let mut app = test_app();
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        give_effect(app.world_mut(), paddle, PowerUpType::Laser, 1.0);
+        // A second laser stack keeps the effect alive well past the warning window
+        give_effect(app.world_mut(), paddle, PowerUpType::Laser, 5.0);
+        app.add_systems(Update, blink_expiring_effects);
+        app.update();
+
+        let paddle_alpha = app.world().get::<Sprite>(paddle).unwrap().color.alpha();
+        assert_eq!(paddle_alpha, 1.0);
+    }
+
+    // --- Magnet ---
+
+    #[test]
+    fn magnet_pulls_items_toward_paddle() {
+        let mut app = test_app();
+        let paddle = spawn_test_paddle(app.world_mut(), 100.0);
+        give_effect(app.world_mut(), paddle, PowerUpType::Magnet, MAGNET_DURATION);
+        let item = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(-100.0, 0.0, 0.0),
+                PowerUp {
+                    power_type: PowerUpType::MultiBall,
+                },
+                Velocity(Vec2::new(0.0, -POWERUP_FALL_SPEED)),
+            ))
+            .id();
+        let coin = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(99.0, 0.0, 0.0),
+                Pickup {
+                    kind: PickupKind::Coin,
+                },
+                Velocity(Vec2::new(0.0, -POWERUP_FALL_SPEED)),
+            ))
+            .id();
+
+        app.add_systems(Update, powerup_movement);
+        app.update();
+
+        let item_x = app.world().get::<Transform>(item).unwrap().translation.x;
+        assert!(
+            (item_x - (-100.0 + MAGNET_PULL_SPEED / 60.0)).abs() < 0.01,
+            "Item should drift right at the pull speed, got {item_x}"
+        );
+        let coin_x = app.world().get::<Transform>(coin).unwrap().translation.x;
+        assert_eq!(coin_x, 100.0, "Pull should stop at the paddle center");
+    }
+
+    #[test]
+    fn items_fall_straight_without_magnet() {
+        let mut app = test_app();
+        spawn_test_paddle(app.world_mut(), 100.0);
+        let item = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(-100.0, 0.0, 0.0),
+                Pickup {
+                    kind: PickupKind::Gem,
+                },
+                Velocity(Vec2::new(0.0, -POWERUP_FALL_SPEED)),
+            ))
+            .id();
+
+        app.add_systems(Update, powerup_movement);
+        app.update();
+
+        let transform = app.world().get::<Transform>(item).unwrap();
+        assert_eq!(transform.translation.x, -100.0);
+        assert!(transform.translation.y < 0.0);
+    }
+
+    #[test]
+    fn wind_drifts_items_up_to_the_cap() {
+        let mut app = test_app();
+        app.world_mut().spawn((
+            Transform::default(),
+            FieldZone {
+                force: FieldForce::Accel(Vec2::X * FIELD_WIND),
+                size: Vec2::splat(600.0),
+            },
+        ));
+        let item = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                PowerUp {
+                    power_type: PowerUpType::MultiBall,
+                },
+                Velocity(Vec2::new(0.0, -POWERUP_FALL_SPEED)),
+            ))
+            .id();
+
+        app.add_systems(Update, powerup_movement);
+        for _ in 0..60 {
+            app.update();
+        }
+
+        let velocity = app.world().get::<Velocity>(item).unwrap().0;
+        assert_eq!(velocity.x, FIELD_ITEM_DRIFT_MAX);
+        assert_eq!(velocity.y, -POWERUP_FALL_SPEED, "Fall speed unchanged");
+        assert!(app.world().get::<Transform>(item).unwrap().translation.x > 0.0);
+    }
 }
@@ -0,0 +1,458 @@
+use bevy::prelude::*;
+use bevy::text::FontSmoothing;
+
+use crate::components::*;
+use crate::constants::*;
+use crate::resources::*;
+use crate::states::GameState;
+use crate::systems::collision::spawn_powerup;
+use crate::systems::game_state::cleanup_for_next_level;
+use crate::systems::setup::{record_level_start_score, spawn_ball, spawn_blocks, spawn_paddle};
+
+/// Power-up name shown on the tuning panel
+pub fn power_up_name(power_type: PowerUpType) -> &'static str {
+    match power_type {
+        PowerUpType::WidePaddle => "ワイドパドル",
+        PowerUpType::MultiBall => "マルチボール",
+        PowerUpType::SlowBall => "スローボール",
+        PowerUpType::FireBall => "ファイアボール",
+        PowerUpType::Laser => "レーザー",
+        PowerUpType::Catch => "キャッチ",
+        PowerUpType::Barrier => "バリア",
+        PowerUpType::ExtraLife => "エクストラライフ",
+        PowerUpType::Magnet => "マグネット",
+        PowerUpType::HeavyBall => "ヘビーボール",
+        PowerUpType::BouncyBall => "バウンシーボール",
+        PowerUpType::SplitBall => "スプリットボール",
+        PowerUpType::GhostBall => "ゴーストボール",
+        PowerUpType::ShrinkPaddle => "シュリンク",
+        PowerUpType::FastBall => "ファストボール",
+        PowerUpType::ReverseControls => "リバース",
+        PowerUpType::Fog => "フォグ",
+    }
+}
+
+/// Panel text for one tuned value
+pub fn practice_label_text(label: PracticeLabel, practice: &PracticeMode, level: &Level) -> String {
+    match label {
+        PracticeLabel::Level => format!("レベル {}", level.current),
+        PracticeLabel::Speed => format!("速度 x{:.1}", level.speed_scale),
+        PracticeLabel::PowerUp => power_up_name(practice.power_up).to_string(),
+        PracticeLabel::Invincible => format!("無敵 {}", if practice.invincible { "ON" } else { "OFF" }),
+    }
+}
+
+/// Spawn the tuning panel (right edge, below the HUD bar)
+/// Shortcut key list on the tuning panel, following the current bindings
+fn practice_key_hint(bindings: &KeyBindings) -> String {
+    let key = |action| bindings.primary_label(action);
+    format!(
+        "{} {}  レベル\n{} {}  速度\n{} {} {}  アイテム\n{}  無敵  {}  リスタート\n{}  終了",
+        key(InputAction::PracticeLevelDown),
+        key(InputAction::PracticeLevelUp),
+        key(InputAction::PracticeSpeedDown),
+        key(InputAction::PracticeSpeedUp),
+        key(InputAction::PracticePrevPowerUp),
+        key(InputAction::PracticeNextPowerUp),
+        key(InputAction::PracticeSpawnPowerUp),
+        key(InputAction::PracticeInvincible),
+        key(InputAction::PracticeRestart),
+        key(InputAction::PracticeQuit),
+    )
+}
+
+pub fn setup_practice_panel(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    practice: Res<PracticeMode>,
+    level: Res<Level>,
+    bindings: Res<KeyBindings>,
+) {
+    let cream = Color::srgb(0.95, 0.85, 0.65);
+    let lavender = Color::srgb(0.55, 0.50, 0.65);
+    let font_handle: Handle<Font> = asset_server.load(GAME_FONT_PATH);
+    let font = TextFont {
+        font: font_handle.clone(),
+        font_size: 14.0,
+        font_smoothing: FontSmoothing::None,
+    };
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(80.0),
+                right: Val::Px(16.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.12, 0.75)),
+            PracticePanel,
+        ))
+        .with_children(|panel| {
+            panel.spawn((Text::new("練習"), font.clone(), TextColor(cream)));
+
+            // Stepped values: [<] value [>]
+            let steppers = [
+                (PracticeAction::LevelDown, PracticeLabel::Level, PracticeAction::LevelUp),
+                (PracticeAction::SpeedDown, PracticeLabel::Speed, PracticeAction::SpeedUp),
+                (PracticeAction::PrevPowerUp, PracticeLabel::PowerUp, PracticeAction::NextPowerUp),
+            ];
+            for (down, label, up) in steppers {
+                panel
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(4.0),
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        spawn_panel_button(row, &font, "<", down, None);
+                        row.spawn((
+                            Text::new(practice_label_text(label, &practice, &level)),
+                            font.clone(),
+                            TextColor(cream),
+                            label,
+                        ));
+                        spawn_panel_button(row, &font, ">", up, None);
+                    });
+            }
+
+            let invincible = practice_label_text(PracticeLabel::Invincible, &practice, &level);
+            spawn_panel_button(panel, &font, "アイテムを出す", PracticeAction::SpawnPowerUp, None);
+            spawn_panel_button(
+                panel,
+                &font,
+                &invincible,
+                PracticeAction::ToggleInvincible,
+                Some(PracticeLabel::Invincible),
+            );
+            spawn_panel_button(panel, &font, "リスタート", PracticeAction::Restart, None);
+            spawn_panel_button(panel, &font, "終了", PracticeAction::Quit, None);
+
+            panel.spawn((
+                Text::new(practice_key_hint(&bindings)),
+                TextFont {
+                    font: font_handle.clone(),
+                    font_size: 12.0,
+                    font_smoothing: FontSmoothing::None,
+                },
+                TextColor(lavender),
+            ));
+        });
+}
+
+/// Spawn one panel button (its text may track a tuned value)
+fn spawn_panel_button(
+    parent: &mut ChildBuilder,
+    font: &TextFont,
+    label: &str,
+    action: PracticeAction,
+    value: Option<PracticeLabel>,
+) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.06)),
+            action,
+        ))
+        .with_children(|btn| {
+            let mut text = btn.spawn((Text::new(label), font.clone(), TextColor(Color::srgb(0.55, 0.50, 0.65))));
+            if let Some(value) = value {
+                text.insert(value);
+            }
+        });
+}
+
+/// Apply tuning panel buttons and shortcut keys
+#[allow(clippy::too_many_arguments)]
+pub fn practice_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    buttons: Query<(&Interaction, &PracticeAction), Changed<Interaction>>,
+    mut practice: ResMut<PracticeMode>,
+    mut level: ResMut<Level>,
+    mut score: ResMut<Score>,
+    registry: Res<PowerUpRegistry>,
+    paddle_query: Query<&Transform, With<Paddle>>,
+    mut ball_query: Query<&mut Velocity, With<Ball>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let actions: Vec<PracticeAction> = buttons
+        .iter()
+        .filter(|(interaction, _)| **interaction == Interaction::Pressed)
+        .map(|(_, action)| *action)
+        .chain(
+            PRACTICE_ACTIONS
+                .iter()
+                .filter(|(input, _)| bindings.just_pressed(&keyboard, *input))
+                .map(|(_, action)| *action),
+        )
+        .collect();
+
+    let mut restart = false;
+    for action in actions {
+        match action {
+            PracticeAction::LevelDown | PracticeAction::LevelUp => {
+                let step = if action == PracticeAction::LevelUp { 1 } else { -1 };
+                level.current = level.current.saturating_add_signed(step).clamp(1, PRACTICE_MAX_LEVEL);
+                restart = true;
+            }
+            PracticeAction::SpeedDown | PracticeAction::SpeedUp => {
+                let step = if action == PracticeAction::SpeedUp {
+                    PRACTICE_SPEED_STEP
+                } else {
+                    -PRACTICE_SPEED_STEP
+                };
+                let old_scale = level.speed_scale;
+                let scale = ((old_scale + step) * 10.0).round() / 10.0;
+                level.speed_scale = scale.clamp(PRACTICE_SPEED_MIN, PRACTICE_SPEED_MAX);
+                // Balls in play speed up or slow down at once, keeping their own pace
+                // (slow / fast ball effects)
+                for mut velocity in &mut ball_query {
+                    velocity.0 *= level.speed_scale / old_scale;
+                }
+            }
+            PracticeAction::PrevPowerUp | PracticeAction::NextPowerUp => {
+                let count = registry.defs.len();
+                let index = registry
+                    .defs
+                    .iter()
+                    .position(|def| def.power_type == practice.power_up)
+                    .unwrap_or(0);
+                let next = if action == PracticeAction::NextPowerUp {
+                    (index + 1) % count
+                } else {
+                    (index + count - 1) % count
+                };
+                practice.power_up = registry.defs[next].power_type;
+            }
+            PracticeAction::SpawnPowerUp => {
+                let x = paddle_query.iter().next().map_or(0.0, |t| t.translation.x);
+                spawn_powerup(&mut commands, &registry, Vec2::new(x, PRACTICE_DROP_Y), practice.power_up);
+            }
+            PracticeAction::ToggleInvincible => practice.invincible = !practice.invincible,
+            PracticeAction::Restart => restart = true,
+            PracticeAction::Quit => {
+                next_state.set(GameState::GameOver);
+                return;
+            }
+        }
+    }
+
+    if restart {
+        // Rebuild the field in place: no countdown, and the score starts over
+        score.value = 0;
+        commands.run_system_cached(cleanup_for_next_level);
+        commands.run_system_cached(spawn_paddle);
+        commands.run_system_cached(spawn_ball);
+        commands.run_system_cached(spawn_blocks);
+        commands.run_system_cached(record_level_start_score);
+    }
+}
+
+/// Refresh the panel values after a change
+pub fn update_practice_panel(
+    practice: Res<PracticeMode>,
+    level: Res<Level>,
+    mut labels: Query<(&mut Text, &PracticeLabel)>,
+) {
+    if !practice.is_changed() && !level.is_changed() {
+        return;
+    }
+    for (mut text, label) in &mut labels {
+        text.0 = practice_label_text(*label, &practice, &level);
+    }
+}
+
+/// Setup the practice end screen (practice is never ranked)
+pub fn setup_practice_result(
+    mut commands: Commands,
+    score: Res<Score>,
+    level: Res<Level>,
+    asset_server: Res<AssetServer>,
+    bindings: Res<KeyBindings>,
+) {
+    let cream = Color::srgb(0.95, 0.85, 0.65);
+    let lavender = Color::srgb(0.55, 0.50, 0.65);
+    let font_handle: Handle<Font> = asset_server.load(GAME_FONT_PATH);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.12, 0.88)),
+            GameOverUI,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("練習終了"),
+                TextFont {
+                    font: font_handle.clone(),
+                    font_size: 48.0,
+                    font_smoothing: FontSmoothing::None,
+                },
+                TextColor(cream),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+
+            parent.spawn((
+                Text::new(format!(
+                    "レベル {}  スコア {}\n練習の記録はランキングに残りません",
+                    level.current, score.value
+                )),
+                TextFont {
+                    font: font_handle.clone(),
+                    font_size: 20.0,
+                    font_smoothing: FontSmoothing::None,
+                },
+                TextColor(cream),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+
+            parent.spawn((
+                Text::new(format!("{} / タップ でメニューへ", bindings.primary_label(InputAction::Confirm))),
+                TextFont {
+                    font: font_handle,
+                    font_size: 16.0,
+                    font_smoothing: FontSmoothing::None,
+                },
+                TextColor(lavender),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::collision::ball_wall_collision;
+    use crate::test_helpers::*;
+
+    fn practice_app() -> App {
+        let mut app = test_app();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<PracticeMode>();
+        app
+    }
+
+    fn press(app: &mut App, key: KeyCode) {
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release_all();
+        keyboard.clear();
+        keyboard.press(key);
+    }
+
+    #[test]
+    fn invincible_ball_bounces_off_bottom() {
+        let mut app = practice_app();
+        app.world_mut().resource_mut::<PracticeMode>().invincible = true;
+        let ball = spawn_test_ball(
+            app.world_mut(),
+            Vec2::new(0.0, -WINDOW_HEIGHT / 2.0 - 10.0),
+            Vec2::new(0.0, -BALL_SPEED),
+        );
+
+        app.add_systems(Update, ball_wall_collision);
+        app.update();
+
+        let velocity = app.world().get::<Velocity>(ball).expect("The ball stays in play");
+        assert!(velocity.0.y > 0.0);
+    }
+
+    #[test]
+    fn lost_ball_costs_no_life_in_practice() {
+        let mut app = practice_app();
+        app.world_mut().resource_mut::<Lives>().spare = 0;
+        spawn_test_ball(
+            app.world_mut(),
+            Vec2::new(0.0, -WINDOW_HEIGHT / 2.0 - 10.0),
+            Vec2::new(0.0, -BALL_SPEED),
+        );
+
+        app.add_systems(Update, ball_wall_collision);
+        app.update();
+
+        assert!(matches!(
+            app.world().resource::<NextState<GameState>>(),
+            NextState::Pending(GameState::Countdown)
+        ));
+        assert_eq!(app.world().resource::<Lives>().spare, 0);
+    }
+
+    #[test]
+    fn speed_step_rescales_balls_in_play() {
+        let mut app = practice_app();
+        let ball = spawn_test_ball(app.world_mut(), Vec2::ZERO, Vec2::new(0.0, BALL_SPEED));
+        let slow = spawn_test_ball(app.world_mut(), Vec2::ZERO, Vec2::new(0.0, BALL_SPEED * 0.5));
+        press(&mut app, KeyCode::KeyX);
+
+        app.add_systems(Update, practice_input);
+        app.update();
+
+        let level = app.world().resource::<Level>();
+        assert!((level.speed_scale - 1.1).abs() < 0.001);
+        let speed = app.world().get::<Velocity>(ball).unwrap().0.length();
+        assert!((speed - BALL_SPEED * 1.1).abs() < 0.01);
+        let slow_speed = app.world().get::<Velocity>(slow).unwrap().0.length();
+        assert!((slow_speed - BALL_SPEED * 0.55).abs() < 0.01, "A slowed ball stays slower");
+    }
+
+    #[test]
+    fn level_change_restarts_field_in_place() {
+        let mut app = practice_app();
+        let old_block = spawn_test_block(app.world_mut(), Vec2::new(0.0, 100.0));
+        app.world_mut().resource_mut::<Score>().value = 500;
+        press(&mut app, KeyCode::Period);
+
+        app.add_systems(Update, practice_input);
+        app.update();
+
+        assert_eq!(app.world().resource::<Level>().current, 2);
+        assert_eq!(app.world().resource::<Score>().value, 0);
+        assert!(app.world().get_entity(old_block).is_err());
+        let blocks = app.world_mut().query::<&Block>().iter(app.world()).count();
+        assert!(blocks > 1, "Level 2 layout is spawned");
+        let balls = app.world_mut().query::<&Ball>().iter(app.world()).count();
+        let paddles = app.world_mut().query::<&Paddle>().iter(app.world()).count();
+        assert_eq!((balls, paddles), (1, 1));
+        assert!(matches!(
+            app.world().resource::<NextState<GameState>>(),
+            NextState::Unchanged
+        ));
+    }
+
+    #[test]
+    fn power_up_choice_wraps_and_spawns() {
+        let mut app = practice_app();
+        press(&mut app, KeyCode::KeyC);
+        app.add_systems(Update, practice_input);
+        app.update();
+        assert_eq!(app.world().resource::<PracticeMode>().power_up, PowerUpType::Fog);
+
+        press(&mut app, KeyCode::KeyF);
+        app.update();
+        let dropped: Vec<PowerUpType> = app
+            .world_mut()
+            .query::<&PowerUp>()
+            .iter(app.world())
+            .map(|p| p.power_type)
+            .collect();
+        assert_eq!(dropped, vec![PowerUpType::Fog]);
+    }
+}
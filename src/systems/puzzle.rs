@@ -0,0 +1,510 @@
+use bevy::prelude::*;
+use bevy::text::FontSmoothing;
+
+use crate::components::*;
+use crate::constants::*;
+use crate::resources::*;
+use crate::systems::input::ActionInput;
+use crate::systems::powerup::paddle_launch_velocity;
+use crate::systems::setup::{grid_x, grid_y, spawn_block};
+
+/// Hand-built puzzle stage
+pub struct PuzzleStage {
+    pub name: &'static str,
+    /// Serves available to clear the stage
+    pub shots: u32,
+    /// Shots for three stars
+    pub par: u32,
+    /// Paddle stays put for the whole stage (only the launch angle is chosen)
+    pub paddle_locked: bool,
+    /// Layout rows from the top: '.' empty, 'N' normal, '2'/'3' durable, 'S' steel, 'X' explosive
+    pub rows: &'static [&'static str],
+}
+
+impl PuzzleStage {
+    /// Stars for clearing with `shots_used`: 3 at par, 2 with a shot to spare, otherwise 1
+    pub fn stars(&self, shots_used: u32) -> u32 {
+        if shots_used <= self.par {
+            3
+        } else if shots_used < self.shots {
+            2
+        } else {
+            1
+        }
+    }
+}
+
+pub const PUZZLE_STAGES: [PuzzleStage; PUZZLE_STAGE_COUNT] = [
+    PuzzleStage {
+        name: "連鎖",
+        shots: 2,
+        par: 1,
+        paddle_locked: true,
+        rows: &["NNNNNNNNNN", "XXXXXXXXXX"],
+    },
+    PuzzleStage {
+        name: "すき間",
+        shots: 3,
+        par: 1,
+        paddle_locked: true,
+        rows: &["2222222222", "NNNXNNXNNN", "SSSS..SSSS"],
+    },
+    PuzzleStage {
+        name: "鉄の天井",
+        shots: 3,
+        par: 2,
+        paddle_locked: false,
+        rows: &["XNNNNNNNNX", "NSSSSSSSSN", "N........N", "NNN....NNN"],
+    },
+    PuzzleStage {
+        name: "二重の壁",
+        shots: 4,
+        par: 2,
+        paddle_locked: true,
+        rows: &["3333333333", "XXX....XXX", "2222222222", "....SS...."],
+    },
+    PuzzleStage {
+        name: "最後の一発",
+        shots: 5,
+        par: 3,
+        paddle_locked: false,
+        rows: &["2X22SS22X2", "NNNN..NNNN", "S.SXNNXS.S", "..NN..NN.."],
+    },
+];
+
+/// Block for one layout character
+pub fn puzzle_block_type(cell: char) -> Option<BlockType> {
+    match cell {
+        'N' => Some(BlockType::Normal),
+        '2' => Some(BlockType::Durable { hits_remaining: 2 }),
+        '3' => Some(BlockType::Durable { hits_remaining: 3 }),
+        'S' => Some(BlockType::Steel),
+        'X' => Some(BlockType::Explosive),
+        _ => None,
+    }
+}
+
+/// Spawn the current stage's layout and refill its shot budget
+pub fn spawn_puzzle_stage(commands: &mut Commands, mode: &mut PuzzleMode) {
+    let stage = &PUZZLE_STAGES[mode.stage];
+    for (row, cells) in stage.rows.iter().enumerate() {
+        for (col, cell) in cells.chars().enumerate() {
+            if let Some(block_type) = puzzle_block_type(cell) {
+                spawn_block(commands, grid_x(col), grid_y(row), block_type, row);
+            }
+        }
+    }
+    mode.shots_left = stage.shots;
+    mode.shots_used = 0;
+}
+
+/// Run condition: the paddle may move (in puzzle mode the keys aim a held serve instead,
+/// and locked stages keep the paddle still throughout)
+pub fn paddle_unlocked(puzzle: Option<Res<PuzzleMode>>, held: Query<(), With<PuzzleServe>>) -> bool {
+    match puzzle {
+        Some(mode) => !PUZZLE_STAGES[mode.stage].paddle_locked && held.is_empty(),
+        None => true,
+    }
+}
+
+/// Put the next serve on the paddle once the previous ball is gone
+pub fn serve_puzzle_ball(
+    mut commands: Commands,
+    mode: Res<PuzzleMode>,
+    ball_query: Query<(), With<Ball>>,
+    paddle_query: Query<(Entity, &Transform, &Collider), With<Paddle>>,
+) {
+    if mode.shots_left == 0 || !ball_query.is_empty() {
+        return;
+    }
+    let Ok((paddle, paddle_transform, paddle_collider)) = paddle_query.get_single() else {
+        return;
+    };
+
+    let y = paddle_transform.translation.y + (paddle_collider.size.y + BALL_SIZE) / 2.0;
+    commands
+        .spawn((
+            Sprite {
+                color: Color::srgb(1.0, 0.96, 0.88), // Warm white
+                custom_size: Some(Vec2::new(BALL_SIZE, BALL_SIZE)),
+                ..default()
+            },
+            Transform::from_xyz(paddle_transform.translation.x, y, 0.0),
+            Ball,
+            Velocity(Vec2::Y * BALL_SPEED),
+            Collider {
+                size: Vec2::new(BALL_SIZE, BALL_SIZE),
+            },
+            CaughtBall {
+                paddle,
+                offset: 0.0,
+                hold: Timer::from_seconds(PUZZLE_AIM_TIME, TimerMode::Once),
+            },
+            PuzzleServe,
+        ))
+        .with_child((
+            Sprite {
+                color: Color::srgba(1.0, 0.96, 0.88, 0.35),
+                custom_size: Some(Vec2::new(2.0, PUZZLE_GUIDE_LENGTH)),
+                ..default()
+            },
+            Transform::from_xyz(0.0, PUZZLE_GUIDE_LENGTH / 2.0, -0.1),
+            AimGuide,
+        ));
+}
+
+/// Slide the held serve along the paddle with the move keys, or put it under a held touch,
+/// to choose the launch angle (lifting the touch launches it)
+#[allow(clippy::type_complexity)]
+pub fn puzzle_aim_input(
+    actions: ActionInput,
+    time: Res<Time>,
+    touches: Res<Touches>,
+    camera_query: Query<(&Camera, &GlobalTransform), (With<Camera2d>, Without<FieldCamera>)>,
+    paddle_query: Query<(&Transform, &Collider), With<Paddle>>,
+    mut serve_query: Query<&mut CaughtBall, With<PuzzleServe>>,
+) {
+    let mut direction = 0.0;
+    if actions.pressed(InputAction::MoveLeft) {
+        direction -= 1.0;
+    }
+    if actions.pressed(InputAction::MoveRight) {
+        direction += 1.0;
+    }
+    // World X under the held touch
+    let touch_x = touches.first_pressed_position().and_then(|pos| {
+        let (camera, cam_transform) = camera_query.get_single().ok()?;
+        let rect = camera.logical_viewport_rect()?;
+        camera.viewport_to_world_2d(cam_transform, pos - rect.min).ok().map(|world| world.x)
+    });
+
+    for mut caught in &mut serve_query {
+        let paddle = paddle_query.get(caught.paddle).ok();
+        let half_width = paddle.map_or(PADDLE_WIDTH, |(_, c)| c.size.x) / 2.0;
+        let offset = match (touch_x, paddle) {
+            (Some(x), Some((paddle_transform, _))) => x - paddle_transform.translation.x,
+            _ => caught.offset + direction * PUZZLE_AIM_SPEED * time.delta_secs(),
+        };
+        caught.offset = offset.clamp(-half_width, half_width);
+    }
+}
+
+/// Point each aim guide along its serve's launch direction
+pub fn update_aim_guide(
+    paddle_query: Query<&Collider, With<Paddle>>,
+    serve_query: Query<(&CaughtBall, &Children), With<PuzzleServe>>,
+    mut guide_query: Query<&mut Transform, With<AimGuide>>,
+) {
+    for (caught, children) in &serve_query {
+        let paddle_width = paddle_query.get(caught.paddle).map_or(PADDLE_WIDTH, |c| c.size.x);
+        let direction = paddle_launch_velocity(caught.offset, paddle_width, 1.0);
+        for &child in children {
+            if let Ok(mut transform) = guide_query.get_mut(child) {
+                transform.translation = (direction * PUZZLE_GUIDE_LENGTH / 2.0).extend(-0.1);
+                transform.rotation = Quat::from_rotation_z(-direction.x.atan2(direction.y));
+            }
+        }
+    }
+}
+
+/// Spend a shot for each serve that has left the paddle
+pub fn count_puzzle_shots(
+    mut commands: Commands,
+    mut mode: ResMut<PuzzleMode>,
+    launched: Query<Entity, (With<PuzzleServe>, Without<CaughtBall>)>,
+) {
+    for ball in &launched {
+        mode.shots_left = mode.shots_left.saturating_sub(1);
+        mode.shots_used += 1;
+        commands.entity(ball).remove::<PuzzleServe>().despawn_descendants();
+    }
+}
+
+/// Show the shots left and the stage number
+pub fn update_puzzle_hud(
+    mode: Res<PuzzleMode>,
+    mut shots_query: Query<&mut Text, (With<PuzzleShotsText>, Without<LevelText>)>,
+    mut level_query: Query<&mut Text, (With<LevelText>, Without<PuzzleShotsText>)>,
+) {
+    let shots = format!("ショット {}", mode.shots_left);
+    for mut text in &mut shots_query {
+        if text.0 != shots {
+            text.0 = shots.clone();
+        }
+    }
+    let label = puzzle_level_label(&mode);
+    for mut text in &mut level_query {
+        if text.0 != label {
+            text.0 = label.clone();
+        }
+    }
+}
+
+/// Level text for the current stage
+pub fn puzzle_level_label(mode: &PuzzleMode) -> String {
+    format!("パズル {}", mode.stage + 1)
+}
+
+/// Award and save the stars for a cleared stage
+pub fn record_puzzle_stars(mut mode: ResMut<PuzzleMode>) {
+    let stars = PUZZLE_STAGES[mode.stage].stars(mode.shots_used);
+    mode.record_stars(stars);
+    mode.save();
+}
+
+/// Move on to the next stage after a clear
+pub fn advance_puzzle_stage(mut mode: ResMut<PuzzleMode>) {
+    mode.stage += 1;
+}
+
+/// Stars as filled and empty marks, e.g. "★★☆"
+pub fn star_marks(stars: u32) -> String {
+    let filled = stars.min(3) as usize;
+    "★".repeat(filled) + &"☆".repeat(3 - filled)
+}
+
+/// Setup the puzzle result screen (every stage's best stars instead of a ranking)
+pub fn setup_puzzle_result(
+    mut commands: Commands,
+    mode: Res<PuzzleMode>,
+    asset_server: Res<AssetServer>,
+    bindings: Res<KeyBindings>,
+) {
+    let soft_green = Color::srgb(0.40, 0.80, 0.52);
+    let salmon = Color::srgb(0.92, 0.44, 0.44);
+    let cream = Color::srgb(0.95, 0.85, 0.65);
+    let lavender = Color::srgb(0.55, 0.50, 0.65);
+    let font_handle: Handle<Font> = asset_server.load(GAME_FONT_PATH);
+
+    // The run ends past the last stage on a clear, on the current stage when shots run out
+    let (title, title_color) = if mode.stage >= PUZZLE_STAGE_COUNT {
+        ("パズル コンプリート！".to_string(), soft_green)
+    } else {
+        (format!("パズル {} 失敗", mode.stage + 1), salmon)
+    };
+    let stage_lines: Vec<String> = PUZZLE_STAGES
+        .iter()
+        .zip(mode.best_stars)
+        .enumerate()
+        .map(|(i, (stage, stars))| format!("{}. {}  {}", i + 1, stage.name, star_marks(stars)))
+        .collect();
+    let total: u32 = mode.best_stars.iter().sum();
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.12, 0.88)),
+            GameOverUI,
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn((
+                Text::new(title),
+                TextFont {
+                    font: font_handle.clone(),
+                    font_size: 48.0,
+                    font_smoothing: FontSmoothing::None,
+                },
+                TextColor(title_color),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+
+            // Best stars per stage
+            parent.spawn((
+                Text::new(format!(
+                    "{}\n合計 ★ {} / {}",
+                    stage_lines.join("\n"),
+                    total,
+                    PUZZLE_STAGE_COUNT * 3
+                )),
+                TextFont {
+                    font: font_handle.clone(),
+                    font_size: 20.0,
+                    font_smoothing: FontSmoothing::None,
+                },
+                TextColor(cream),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+
+            parent.spawn((
+                Text::new(format!(
+                    "{} / タップ でメニューへ（次はまだ星のないステージから）",
+                    bindings.primary_label(InputAction::Confirm)
+                )),
+                TextFont {
+                    font: font_handle,
+                    font_size: 16.0,
+                    font_smoothing: FontSmoothing::None,
+                },
+                TextColor(lavender),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    fn puzzle_app(stage: usize) -> App {
+        let mut app = test_app();
+        app.insert_resource(PuzzleMode {
+            stage,
+            shots_left: PUZZLE_STAGES[stage].shots,
+            ..default()
+        });
+        app
+    }
+
+    #[test]
+    fn stages_are_well_formed() {
+        for stage in &PUZZLE_STAGES {
+            assert!(stage.par >= 1 && stage.par <= stage.shots, "{}", stage.name);
+            assert!(stage.rows.len() <= EDITOR_ROWS, "{}", stage.name);
+            for row in stage.rows {
+                assert_eq!(row.chars().count(), BLOCK_COLS, "{}", stage.name);
+            }
+            let breakable = stage
+                .rows
+                .iter()
+                .flat_map(|row| row.chars())
+                .filter_map(puzzle_block_type)
+                .any(|block| block != BlockType::Steel);
+            assert!(breakable, "{} has something to clear", stage.name);
+        }
+    }
+
+    #[test]
+    fn fewer_shots_earn_more_stars() {
+        let stage = &PUZZLE_STAGES[3];
+        assert_eq!(stage.stars(1), 3);
+        assert_eq!(stage.stars(stage.par), 3);
+        assert_eq!(stage.stars(stage.par + 1), 2);
+        assert_eq!(stage.stars(stage.shots), 1);
+        assert_eq!(star_marks(2), "★★☆");
+    }
+
+    #[test]
+    fn run_starts_at_first_unsolved_stage() {
+        assert_eq!(PuzzleMode::with_best_stars([3, 1, 0, 0, 0]).stage, 2);
+        assert_eq!(PuzzleMode::with_best_stars([3; PUZZLE_STAGE_COUNT]).stage, 0);
+
+        let mut mode = PuzzleMode::with_best_stars([2, 0, 0, 0, 0]);
+        mode.stage = 0;
+        mode.record_stars(1);
+        assert_eq!(mode.best_stars[0], 2, "A worse clear keeps the best");
+        assert_eq!(mode.last_stars, 1);
+    }
+
+    #[test]
+    fn serve_waits_on_paddle_and_shot_is_spent_on_launch() {
+        let mut app = puzzle_app(0);
+        spawn_test_paddle(app.world_mut(), 0.0);
+        app.add_systems(Update, (serve_puzzle_ball, count_puzzle_shots));
+        app.update();
+
+        let ball = app
+            .world_mut()
+            .query_filtered::<Entity, With<PuzzleServe>>()
+            .single(app.world());
+        assert!(app.world().get::<CaughtBall>(ball).is_some(), "The serve is held");
+        assert_eq!(app.world().resource::<PuzzleMode>().shots_left, 2);
+
+        app.world_mut().entity_mut(ball).remove::<CaughtBall>();
+        app.update();
+
+        let mode = app.world().resource::<PuzzleMode>();
+        assert_eq!((mode.shots_left, mode.shots_used), (1, 1));
+        assert!(app.world().get::<PuzzleServe>(ball).is_none());
+        let guides = app.world_mut().query::<&AimGuide>().iter(app.world()).count();
+        assert_eq!(guides, 0, "The aim guide goes with the launch");
+        let balls = app.world_mut().query::<&Ball>().iter(app.world()).count();
+        assert_eq!(balls, 1, "No new serve while the ball is in play");
+    }
+
+    #[test]
+    fn aim_keys_move_the_serve_not_the_paddle() {
+        let mut app = puzzle_app(2);
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<Touches>();
+        let paddle = spawn_test_paddle(app.world_mut(), 0.0);
+        app.add_systems(
+            Update,
+            (
+                serve_puzzle_ball,
+                puzzle_aim_input,
+                crate::systems::input::paddle_input.run_if(paddle_unlocked),
+            )
+                .chain(),
+        );
+        app.update();
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ArrowRight);
+        app.update();
+
+        let caught = app
+            .world_mut()
+            .query::<&CaughtBall>()
+            .single(app.world());
+        assert!(caught.offset > 0.0, "Right aims right");
+        let x = app.world().get::<Transform>(paddle).unwrap().translation.x;
+        assert_eq!(x, 0.0, "The paddle holds still while aiming");
+    }
+
+    #[test]
+    fn held_touch_aims_the_serve_and_lifting_launches() {
+        use crate::systems::input::PlayerActions;
+        use bevy::ecs::system::RunSystemOnce;
+        use bevy::input::touch::TouchPhase;
+
+        let mut app = windowed_test_app();
+        app.insert_resource(PuzzleMode {
+            stage: 2,
+            shots_left: PUZZLE_STAGES[2].shots,
+            ..default()
+        });
+        spawn_test_paddle(app.world_mut(), 0.0);
+        app.add_systems(Update, (serve_puzzle_ball, puzzle_aim_input).chain());
+        app.update();
+
+        // Screen x 420 is world x 20 on the 800-wide window
+        send_touch(&mut app, TouchPhase::Started, Vec2::new(420.0, 700.0));
+        app.update();
+        let caught = app.world_mut().query::<&CaughtBall>().single(app.world());
+        assert!(
+            (caught.offset - 20.0).abs() < 0.01,
+            "The serve sits under the touch"
+        );
+        let launch = |app: &mut App| {
+            app.world_mut()
+                .run_system_once(|actions: PlayerActions| actions.just_pressed(0))
+                .unwrap()
+        };
+        assert!(!launch(&mut app), "Touching down only aims");
+
+        send_touch(&mut app, TouchPhase::Ended, Vec2::new(420.0, 700.0));
+        app.update();
+        assert!(launch(&mut app), "Lifting the touch launches");
+    }
+
+    #[test]
+    fn locked_stage_keeps_paddle_still_in_flight() {
+        let mut app = puzzle_app(0);
+        let unlocked = app.world_mut().run_system_cached(paddle_unlocked).unwrap();
+        assert!(!unlocked, "Stage 1 is locked");
+
+        app.world_mut().resource_mut::<PuzzleMode>().stage = 2;
+        let unlocked = app.world_mut().run_system_cached(paddle_unlocked).unwrap();
+        assert!(unlocked, "Stage 3 lets the paddle move once the serve is away");
+    }
+}
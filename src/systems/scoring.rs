@@ -13,10 +13,30 @@ pub fn update_score_text(score: Res<Score>, mut query: Query<&mut Text, With<Sco
 }
 
 /// Update level text display
-pub fn update_level_text(level: Res<Level>, mut query: Query<&mut Text, With<LevelText>>) {
+pub fn update_level_text(
+    level: Res<Level>,
+    daily: Option<Res<DailyChallenge>>,
+    versus: Option<Res<VersusMode>>,
+    mut query: Query<&mut Text, With<LevelText>>,
+) {
     if level.is_changed() {
         for mut text in &mut query {
-            *text = Text::new(format!("レベル {}", level.current));
+            *text = if daily.is_some() {
+                Text::new("デイリー")
+            } else if versus.is_some() {
+                Text::new("対戦")
+            } else {
+                Text::new(format!("レベル {}", level.current))
+            };
+        }
+    }
+}
+
+/// Update spare lives text display
+pub fn update_lives_text(lives: Res<Lives>, mut query: Query<&mut Text, With<LivesText>>) {
+    if lives.is_changed() {
+        for mut text in &mut query {
+            *text = Text::new(format!("残機 {}", lives.spare));
         }
     }
 }
@@ -5,6 +5,10 @@ use bevy::render::camera::ScalingMode;
 use crate::components::*;
 use crate::constants::*;
 use crate::resources::*;
+use crate::systems::daily::spawn_daily_level;
+use crate::systems::endless::spawn_endless_start;
+use crate::systems::versus::{spawn_versus_fields, versus_status};
+use crate::systems::puzzle::{puzzle_level_label, spawn_puzzle_stage};
 
 /// Setup the 2D camera with scaling to fit mobile screens
 pub fn setup_camera(mut commands: Commands) {
@@ -34,88 +38,151 @@ pub fn update_ui_scale(
     }
 }
 
-/// Spawn the paddle
-pub fn spawn_paddle(mut commands: Commands) {
-    commands.spawn((
-        Sprite {
-            color: Color::srgb(0.95, 0.85, 0.65), // Cream
-            custom_size: Some(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
-            ..default()
-        },
-        Transform::from_xyz(0.0, PADDLE_Y, 0.0),
-        Paddle,
-        Collider {
-            size: Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT),
-        },
-    ));
+/// Spawn the paddle (one per player in co-op, each starting on its own half;
+/// one per field in versus)
+pub fn spawn_paddle(
+    mut commands: Commands,
+    coop: Option<Res<CoopMode>>,
+    versus: Option<Res<VersusMode>>,
+) {
+    let cream = Color::srgb(0.95, 0.85, 0.65);
+    let sky_blue = Color::srgb(0.45, 0.75, 0.95);
+    let paddles: &[(f32, Color)] = if coop.is_some() {
+        &[(-COOP_PADDLE_OFFSET, cream), (COOP_PADDLE_OFFSET, sky_blue)]
+    } else if versus.is_some() {
+        &[(0.0, cream), (0.0, sky_blue)]
+    } else {
+        &[(0.0, cream)]
+    };
+
+    for (player, (x, color)) in paddles.iter().enumerate() {
+        // Versus paddles each sit in their own field
+        let field = PlayfieldId(if versus.is_some() { player } else { 0 });
+        commands.spawn((
+            Sprite {
+                color: *color,
+                custom_size: Some(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+                ..default()
+            },
+            Transform::from_xyz(field.origin_x() + x, PADDLE_Y, 0.0),
+            Paddle,
+            Player(player),
+            field,
+            Collider {
+                size: Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT),
+            },
+        ));
+    }
+}
+
+/// Spawn the ball (one per field in versus; puzzle serves are put on the paddle by `serve_puzzle_ball`).
+/// Story levels serve their own ball kind; the other modes always start with a normal ball.
+pub fn spawn_ball(
+    mut commands: Commands,
+    level: Res<Level>,
+    ramp: Res<SpeedRamp>,
+    versus: Option<Res<VersusMode>>,
+    puzzle: Option<Res<PuzzleMode>>,
+    endless: Option<Res<EndlessMode>>,
+    daily: Option<Res<DailyChallenge>>,
+) {
+    if puzzle.is_some() {
+        return;
+    }
+    let kind = if versus.is_some() || endless.is_some() || daily.is_some() {
+        BallKind::Normal
+    } else {
+        level_ball_kind(level.current)
+    };
+    let fields = if versus.is_some() { VERSUS_FIELDS } else { 1 };
+    for field in 0..fields {
+        spawn_field_ball(&mut commands, PlayfieldId(field), ramp.speed(&level, field), kind);
+    }
 }
 
-/// Spawn the ball
-pub fn spawn_ball(mut commands: Commands, level: Res<Level>) {
+/// Serve a ball from the center of a field
+pub fn spawn_field_ball(commands: &mut Commands, field: PlayfieldId, speed: f32, kind: BallKind) {
     let initial_direction = Vec2::new(0.15, -1.0).normalize();
-    let speed = BALL_SPEED * level.speed_multiplier();
+    spawn_kind_ball(commands, field, Vec2::new(field.origin_x(), 0.0), initial_direction * speed, kind);
+}
 
-    commands.spawn((
-        Sprite {
-            color: Color::srgb(1.0, 0.96, 0.88), // Warm white
-            custom_size: Some(Vec2::new(BALL_SIZE, BALL_SIZE)),
-            ..default()
-        },
-        Transform::from_xyz(0.0, 0.0, 0.0),
-        Ball,
-        Velocity(initial_direction * speed),
-        Collider {
-            size: Vec2::new(BALL_SIZE, BALL_SIZE),
-        },
-    ));
+/// Spawn a free ball of the given kind
+pub fn spawn_kind_ball(
+    commands: &mut Commands,
+    field: PlayfieldId,
+    pos: Vec2,
+    velocity: Vec2,
+    kind: BallKind,
+) -> Entity {
+    commands
+        .spawn((
+            Sprite {
+                color: kind.color(),
+                custom_size: Some(Vec2::new(BALL_SIZE, BALL_SIZE)),
+                ..default()
+            },
+            Transform::from_xyz(pos.x, pos.y, 0.0),
+            Ball,
+            kind,
+            field,
+            Velocity(velocity),
+            Collider {
+                size: Vec2::new(BALL_SIZE, BALL_SIZE),
+            },
+        ))
+        .id()
 }
 
-/// Get color for a block type
+/// Get color for a block type (standard palette; `apply_block_palette` recolors for the player's settings)
 pub fn block_type_color(block_type: &BlockType, row: usize) -> Color {
-    let normal_colors = [
-        Color::srgb(0.92, 0.44, 0.44), // Coral
-        Color::srgb(0.95, 0.60, 0.35), // Orange
-        Color::srgb(0.95, 0.85, 0.40), // Yellow
-        Color::srgb(0.40, 0.80, 0.52), // Green
-        Color::srgb(0.44, 0.60, 0.92), // Blue
-    ];
-
-    match block_type {
-        BlockType::Normal => normal_colors[row % normal_colors.len()],
-        BlockType::Durable { hits_remaining } => match hits_remaining {
-            3 => Color::srgb(0.55, 0.15, 0.15), // Dark red
-            2 => Color::srgb(0.80, 0.35, 0.15), // Dark orange
-            _ => Color::srgb(0.95, 0.60, 0.35), // Orange (about to break)
-        },
-        BlockType::Steel => Color::srgb(0.50, 0.50, 0.55),    // Grey
-        BlockType::Explosive => Color::srgb(0.90, 0.30, 0.30), // Red-purple
-    }
+    BlockPalette::Standard.block_color(block_type, row)
 }
 
-/// Get color for a durable block based on remaining hits
+/// Get color for a durable block based on remaining hits (standard palette)
 pub fn durable_color(hits_remaining: u32) -> Color {
-    match hits_remaining {
-        3 => Color::srgb(0.55, 0.15, 0.15),
-        2 => Color::srgb(0.80, 0.35, 0.15),
-        _ => Color::srgb(0.95, 0.60, 0.35),
-    }
+    BlockPalette::Standard.durable_color(hits_remaining)
+}
+
+/// Spawn a single standard-size block at the given position
+pub fn spawn_block(commands: &mut Commands, x: f32, y: f32, block_type: BlockType, row: usize) -> Entity {
+    spawn_sized_block(commands, x, y, Vec2::new(BLOCK_WIDTH, BLOCK_HEIGHT), block_type, row)
 }
 
-/// Spawn a single block at the given position
-pub fn spawn_block(commands: &mut Commands, x: f32, y: f32, block_type: BlockType, row: usize) {
+/// Spawn a block of arbitrary size centered at the given position
+pub fn spawn_sized_block(
+    commands: &mut Commands,
+    x: f32,
+    y: f32,
+    size: Vec2,
+    block_type: BlockType,
+    row: usize,
+) -> Entity {
     let color = block_type_color(&block_type, row);
-    commands.spawn((
-        Sprite {
-            color,
-            custom_size: Some(Vec2::new(BLOCK_WIDTH, BLOCK_HEIGHT)),
-            ..default()
-        },
-        Transform::from_xyz(x, y, 0.0),
-        Block { block_type },
-        Collider {
-            size: Vec2::new(BLOCK_WIDTH, BLOCK_HEIGHT),
-        },
-    ));
+    commands
+        .spawn((
+            Sprite {
+                color,
+                custom_size: Some(size),
+                ..default()
+            },
+            Transform::from_xyz(x, y, 0.0),
+            Block { block_type },
+            BlockRow(row),
+            Collider { size },
+        ))
+        .id()
+}
+
+/// Spawn a block covering `span` grid cells, anchored at its top-left cell
+pub fn spawn_grid_block(
+    commands: &mut Commands,
+    row: usize,
+    col: usize,
+    span: BlockSpan,
+    block_type: BlockType,
+) {
+    let center = grid_block_center(row, col, span);
+    spawn_sized_block(commands, center.x, center.y, block_span_size(span), block_type, row);
 }
 
 /// Grid helper: compute x position for a column
@@ -130,8 +197,50 @@ pub fn grid_y(row: usize) -> f32 {
     BLOCKS_START_Y - row as f32 * (BLOCK_HEIGHT + BLOCK_GAP)
 }
 
-/// Spawn blocks based on current level
-pub fn spawn_blocks(mut commands: Commands, level: Res<Level>) {
+/// Grid helper: world size of a block spanning several cells (inner gaps are filled)
+pub fn block_span_size(span: BlockSpan) -> Vec2 {
+    Vec2::new(
+        span.cols as f32 * (BLOCK_WIDTH + BLOCK_GAP) - BLOCK_GAP,
+        span.rows as f32 * (BLOCK_HEIGHT + BLOCK_GAP) - BLOCK_GAP,
+    )
+}
+
+/// Grid helper: center position of a multi-cell block anchored at (row, col)
+pub fn grid_block_center(row: usize, col: usize, span: BlockSpan) -> Vec2 {
+    let last_col = col + span.cols.max(1) - 1;
+    let last_row = row + span.rows.max(1) - 1;
+    Vec2::new(
+        (grid_x(col) + grid_x(last_col)) / 2.0,
+        (grid_y(row) + grid_y(last_row)) / 2.0,
+    )
+}
+
+/// Spawn blocks based on current level (endless runs start with a few generated rows)
+pub fn spawn_blocks(
+    mut commands: Commands,
+    level: Res<Level>,
+    endless: Option<ResMut<EndlessMode>>,
+    daily: Option<Res<DailyChallenge>>,
+    versus: Option<Res<VersusMode>>,
+    puzzle: Option<ResMut<PuzzleMode>>,
+) {
+    if versus.is_some() {
+        spawn_versus_fields(&mut commands);
+        return;
+    }
+    if let Some(mut puzzle) = puzzle {
+        spawn_puzzle_stage(&mut commands, &mut puzzle);
+        return;
+    }
+    if let Some(mut endless) = endless {
+        spawn_endless_start(&mut commands, &mut endless);
+        return;
+    }
+    if let Some(daily) = daily {
+        spawn_daily_level(&mut commands, daily.day);
+        return;
+    }
+
     match level.current {
         1 => spawn_level_1(&mut commands),
         2 => spawn_level_2(&mut commands),
@@ -184,7 +293,7 @@ fn spawn_level_3(commands: &mut Commands) {
             let block_type = if row == 3 && col != 2 && col != 7 {
                 // Row 3: Steel barrier with gaps at col 2 and 7
                 BlockType::Steel
-            } else if row <= 1 && col >= 3 && col <= 6 {
+            } else if row <= 1 && (3..=6).contains(&col) {
                 // Rows 0-1, center: Explosive blocks (behind Steel, reached last)
                 BlockType::Explosive
             } else if row <= 2 {
@@ -213,9 +322,7 @@ fn spawn_level_4(commands: &mut Commands) {
             // Horizontal Steel barriers at row 3 (with gaps at cols 1, 4, 8)
             let is_h_barrier = row == 3 && col != 1 && col != 4 && col != 8;
 
-            let block_type = if is_pillar_col && !is_gap_row {
-                BlockType::Steel
-            } else if is_h_barrier {
+            let block_type = if (is_pillar_col && !is_gap_row) || is_h_barrier {
                 BlockType::Steel
             } else if row == 0 || row == rows - 1 {
                 // Top/bottom rows: Durable blocks (harder to clear)
@@ -258,9 +365,9 @@ fn spawn_level_5(commands: &mut Commands) {
         }
     }
 
-    for row in 0..rows {
-        for col in 0..cols {
-            if !grid[row][col] { continue; }
+    for (row, grid_row) in grid.iter().enumerate() {
+        for (col, &in_spiral) in grid_row.iter().enumerate() {
+            if !in_spiral { continue; }
 
             // Distance from center determines block type
             let center_r = (rows as f32 - 1.0) / 2.0;
@@ -326,7 +433,7 @@ fn spawn_level_7(commands: &mut Commands) {
             } else if is_entry {
                 // Entry points are Normal — breakable to get in
                 BlockType::Normal
-            } else if row >= 2 && row <= 4 && col >= 3 && col <= 6 {
+            } else if (2..=4).contains(&row) && (3..=6).contains(&col) {
                 // Inner core: alternating Durable(3) and Explosive
                 if (row + col) % 2 == 0 {
                     BlockType::Explosive
@@ -354,16 +461,10 @@ fn spawn_level_8(commands: &mut Commands) {
                 || (col == 5 && row != 0 && row != 6);
 
             // Explosive clusters in each quadrant corner
-            let is_explosive_spot =
-                (row == 1 && col == 1)
-                || (row == 1 && col == 8)
-                || (row == 5 && col == 1)
-                || (row == 5 && col == 8);
+            let is_explosive_spot = (row == 1 || row == 5) && (col == 1 || col == 8);
 
             // Top two corners: Durable(3) blocks (hardest)
-            let is_hard_corner =
-                (row <= 1 && (col <= 1 || col >= 8))
-                || (row >= 5 && (col <= 1 || col >= 8));
+            let is_hard_corner = (row <= 1 || row >= 5) && (col <= 1 || col >= 8);
 
             let block_type = if is_cross {
                 BlockType::Steel
@@ -390,37 +491,44 @@ fn spawn_level_8(commands: &mut Commands) {
 
 /// Level 9+: Auto-generated grid with increasing special block ratios
 fn spawn_generated_level(commands: &mut Commands, level: u32) {
-    // Probabilities increase with level
-    let durable_chance = (0.10 + (level - 9) as f32 * 0.05).min(0.35);
-    let steel_chance = (0.05 + (level - 9) as f32 * 0.03).min(0.15);
-    let explosive_chance = (0.05 + (level - 9) as f32 * 0.02).min(0.12);
-
     for row in 0..BLOCK_ROWS {
         for col in 0..BLOCK_COLS {
-            let roll = level_rand(level, row as u32, col as u32);
-
-            let block_type = if roll < steel_chance {
-                BlockType::Steel
-            } else if roll < steel_chance + explosive_chance {
-                BlockType::Explosive
-            } else if roll < steel_chance + explosive_chance + durable_chance {
-                let hits = if level >= 6 && level_rand(level, row as u32 + 100, col as u32) < 0.3 {
-                    3
-                } else {
-                    2
-                };
-                BlockType::Durable { hits_remaining: hits }
-            } else {
-                BlockType::Normal
-            };
-
+            let block_type = generated_block_type(level, row as u32, col as u32);
             spawn_block(commands, grid_x(col), grid_y(row), block_type, row);
         }
     }
 }
 
-/// Deterministic pseudo-random based on level, row, col
-fn level_rand(level: u32, row: u32, col: u32) -> f32 {
+/// Block type for one cell of a generated level (level 9+); probabilities increase with level
+pub fn generated_block_type(level: u32, row: u32, col: u32) -> BlockType {
+    seeded_block_type(level, level, row, col)
+}
+
+/// Block type with `level` probabilities and a layout picked by an independent `seed`
+pub fn seeded_block_type(level: u32, seed: u32, row: u32, col: u32) -> BlockType {
+    let durable_chance = (0.10 + level.saturating_sub(9) as f32 * 0.05).min(0.35);
+    let steel_chance = (0.05 + level.saturating_sub(9) as f32 * 0.03).min(0.15);
+    let explosive_chance = (0.05 + level.saturating_sub(9) as f32 * 0.02).min(0.12);
+
+    let roll = level_rand(seed, row, col);
+    if roll < steel_chance {
+        BlockType::Steel
+    } else if roll < steel_chance + explosive_chance {
+        BlockType::Explosive
+    } else if roll < steel_chance + explosive_chance + durable_chance {
+        let hits = if level >= 6 && level_rand(seed, row + 100, col) < 0.3 {
+            3
+        } else {
+            2
+        };
+        BlockType::Durable { hits_remaining: hits }
+    } else {
+        BlockType::Normal
+    }
+}
+
+/// Deterministic pseudo-random based on level (or layout seed), row, col
+pub fn level_rand(level: u32, row: u32, col: u32) -> f32 {
     let seed = level
         .wrapping_mul(7919)
         .wrapping_add(row.wrapping_mul(1301))
@@ -429,9 +537,84 @@ fn level_rand(level: u32, row: u32, col: u32) -> f32 {
     (n & 0x7FFFFFFF) as f32 / 0x7FFFFFFF as f32
 }
 
+/// Field zones from the level data: a few story levels and every generated level bend the ball.
+/// Rects are in playfield coordinates (inside the walls).
+pub fn level_field_zones(level: u32) -> Vec<(FieldForce, Rect)> {
+    let left = -WINDOW_WIDTH / 2.0 + WALL_THICKNESS;
+    let right = WINDOW_WIDTH / 2.0 - WALL_THICKNESS;
+    let top = WINDOW_HEIGHT / 2.0 - WALL_THICKNESS;
+    let bottom = -WINDOW_HEIGHT / 2.0;
+
+    let wind_band = |dir: f32| (FieldForce::Accel(Vec2::X * dir * FIELD_WIND), Rect::new(left, -120.0, right, 40.0));
+    let speed_halves = |slow_left: bool| {
+        let (slow, fast) = (FieldForce::Speed(FIELD_SLOW_SCALE), FieldForce::Speed(FIELD_FAST_SCALE));
+        let (a, b) = if slow_left { (slow, fast) } else { (fast, slow) };
+        vec![(a, Rect::new(left, -220.0, 0.0, 80.0)), (b, Rect::new(0.0, -220.0, right, 80.0))]
+    };
+
+    match level {
+        3 => vec![wind_band(1.0)],
+        4 => speed_halves(true),
+        1..=8 => Vec::new(),
+        n => match n % 3 {
+            0 => vec![(FieldForce::Accel(Vec2::NEG_Y * FIELD_GRAVITY), Rect::new(left, bottom, right, top))],
+            1 => vec![wind_band(if n.is_multiple_of(2) { 1.0 } else { -1.0 })],
+            _ => speed_halves(n.is_multiple_of(2)),
+        },
+    }
+}
+
+/// Ball kind from the level data: levels 5–8 each serve their own, the rest a normal ball
+pub fn level_ball_kind(level: u32) -> BallKind {
+    match level {
+        5 => BallKind::Heavy,
+        6 => BallKind::Splitter,
+        7 => BallKind::Ghost,
+        8 => BallKind::Bouncy,
+        _ => BallKind::Normal,
+    }
+}
+
+/// Spawn the level's field zones as faint tinted regions (story levels only)
+pub fn spawn_field_zones(
+    mut commands: Commands,
+    level: Res<Level>,
+    endless: Option<Res<EndlessMode>>,
+    daily: Option<Res<DailyChallenge>>,
+    versus: Option<Res<VersusMode>>,
+    puzzle: Option<Res<PuzzleMode>>,
+) {
+    if endless.is_some() || daily.is_some() || versus.is_some() || puzzle.is_some() {
+        return;
+    }
+    for (force, rect) in level_field_zones(level.current) {
+        commands.spawn((
+            Sprite {
+                color: force.color().with_alpha(FIELD_ZONE_ALPHA),
+                custom_size: Some(rect.size()),
+                ..default()
+            },
+            Transform::from_translation(rect.center().extend(-1.0)),
+            FieldZone {
+                force,
+                size: rect.size(),
+            },
+        ));
+    }
+}
+
 /// Spawn walls around the play area
-pub fn spawn_walls(mut commands: Commands) {
-    let wall_color = Color::srgb(0.22, 0.20, 0.32);
+pub fn spawn_walls(mut commands: Commands, versus: Option<Res<VersusMode>>) {
+    let fields = if versus.is_some() { VERSUS_FIELDS } else { 1 };
+    for field in 0..fields {
+        spawn_field_walls(&mut commands, PlayfieldId(field));
+    }
+}
+
+/// Spawn the four walls around one field
+fn spawn_field_walls(commands: &mut Commands, field: PlayfieldId) {
+    let x0 = field.origin_x();
+    let wall_color = GameSettings::default().wall_color(); // Recolored for high contrast by `apply_display_settings`
 
     // Top wall
     commands.spawn((
@@ -440,8 +623,9 @@ pub fn spawn_walls(mut commands: Commands) {
             custom_size: Some(Vec2::new(WINDOW_WIDTH, WALL_THICKNESS)),
             ..default()
         },
-        Transform::from_xyz(0.0, WINDOW_HEIGHT / 2.0 - WALL_THICKNESS / 2.0, 0.0),
+        Transform::from_xyz(x0, WINDOW_HEIGHT / 2.0 - WALL_THICKNESS / 2.0, 0.0),
         Wall::Top,
+        field,
         Collider {
             size: Vec2::new(WINDOW_WIDTH, WALL_THICKNESS),
         },
@@ -454,8 +638,9 @@ pub fn spawn_walls(mut commands: Commands) {
             custom_size: Some(Vec2::new(WALL_THICKNESS, WINDOW_HEIGHT)),
             ..default()
         },
-        Transform::from_xyz(-WINDOW_WIDTH / 2.0 + WALL_THICKNESS / 2.0, 0.0, 0.0),
+        Transform::from_xyz(x0 - WINDOW_WIDTH / 2.0 + WALL_THICKNESS / 2.0, 0.0, 0.0),
         Wall::Left,
+        field,
         Collider {
             size: Vec2::new(WALL_THICKNESS, WINDOW_HEIGHT),
         },
@@ -468,8 +653,9 @@ pub fn spawn_walls(mut commands: Commands) {
             custom_size: Some(Vec2::new(WALL_THICKNESS, WINDOW_HEIGHT)),
             ..default()
         },
-        Transform::from_xyz(WINDOW_WIDTH / 2.0 - WALL_THICKNESS / 2.0, 0.0, 0.0),
+        Transform::from_xyz(x0 + WINDOW_WIDTH / 2.0 - WALL_THICKNESS / 2.0, 0.0, 0.0),
         Wall::Right,
+        field,
         Collider {
             size: Vec2::new(WALL_THICKNESS, WINDOW_HEIGHT),
         },
@@ -482,8 +668,9 @@ pub fn spawn_walls(mut commands: Commands) {
             custom_size: Some(Vec2::new(WINDOW_WIDTH, WALL_THICKNESS)),
             ..default()
         },
-        Transform::from_xyz(0.0, -WINDOW_HEIGHT / 2.0 - WALL_THICKNESS / 2.0, 0.0),
+        Transform::from_xyz(x0, -WINDOW_HEIGHT / 2.0 - WALL_THICKNESS / 2.0, 0.0),
         Wall::Bottom,
+        field,
         Collider {
             size: Vec2::new(WINDOW_WIDTH, WALL_THICKNESS),
         },
@@ -491,7 +678,19 @@ pub fn spawn_walls(mut commands: Commands) {
 }
 
 /// Spawn score, level, and high score UI
-pub fn spawn_ui(mut commands: Commands, asset_server: Res<AssetServer>, high_scores: Res<HighScores>) {
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_ui(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    high_scores: Res<HighScores>,
+    lives: Res<Lives>,
+    letters: Res<BonusLetters>,
+    time_attack: Option<Res<TimeAttackMode>>,
+    daily: Option<Res<DailyChallenge>>,
+    coop: Option<Res<CoopMode>>,
+    versus: Option<Res<VersusMode>>,
+    puzzle: Option<Res<PuzzleMode>>,
+) {
     let warm_white = Color::srgb(1.0, 0.96, 0.88);
     let lavender = Color::srgb(0.55, 0.50, 0.65);
     let font_handle: Handle<Font> = asset_server.load(GAME_FONT_PATH);
@@ -506,42 +705,139 @@ pub fn spawn_ui(mut commands: Commands, asset_server: Res<AssetServer>, high_sco
         ..default()
     }).insert(BackgroundColor(Color::srgba(0.07, 0.07, 0.16, 0.8)));
 
-    // Score text (top-left)
-    commands.spawn((
-        Text::new("スコア 0"),
-        TextFont {
-            font: font_handle.clone(),
-            font_size: 24.0,
-            font_smoothing: FontSmoothing::None,
-        },
-        TextColor(warm_white),
-        Node {
+    // Versus shows a status line per player instead of the shared score and lives
+    if let Some(versus) = &versus {
+        for field in 0..VERSUS_FIELDS {
+            commands.spawn((
+                Text::new(versus_status(versus, field)),
+                TextFont {
+                    font: font_handle.clone(),
+                    font_size: 16.0,
+                    font_smoothing: FontSmoothing::None,
+                },
+                TextColor(warm_white),
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(52.0),
+                    left: if field == 0 { Val::Px(20.0) } else { Val::Percent(50.0) },
+                    margin: UiRect::left(Val::Px(if field == 0 { 0.0 } else { 20.0 })),
+                    ..default()
+                },
+                VersusStatusText { field },
+            ));
+        }
+    } else {
+        // Score text (top-left)
+        commands.spawn((
+            Text::new("スコア 0"),
+            TextFont {
+                font: font_handle.clone(),
+                font_size: 24.0,
+                font_smoothing: FontSmoothing::None,
+            },
+            TextColor(warm_white),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(8.0),
+                left: Val::Px(20.0),
+                ..default()
+            },
+            ScoreText,
+        ));
+
+        // Spare lives text (left of center; shots left in puzzle mode)
+        let node = Node {
             position_type: PositionType::Absolute,
-            top: Val::Px(8.0),
-            left: Val::Px(20.0),
+            top: Val::Px(12.0),
+            left: Val::Percent(25.0),
             ..default()
-        },
-        ScoreText,
-    ));
-
-    // High score text (top-center)
-    commands.spawn((
-        Text::new(format!("ハイスコア {}", high_scores.best())),
-        TextFont {
+        };
+        let font = TextFont {
             font: font_handle.clone(),
             font_size: 16.0,
             font_smoothing: FontSmoothing::None,
-        },
-        TextColor(lavender),
-        Node {
-            position_type: PositionType::Absolute,
-            top: Val::Px(12.0),
-            left: Val::Percent(50.0),
-            margin: UiRect::left(Val::Px(-30.0)),
-            ..default()
-        },
-        HighScoreText,
-    ));
+        };
+        if let Some(puzzle) = &puzzle {
+            commands.spawn((
+                Text::new(format!("ショット {}", puzzle.shots_left)),
+                font,
+                TextColor(lavender),
+                node,
+                PuzzleShotsText,
+            ));
+        } else {
+            commands.spawn((
+                Text::new(format!("残機 {}", lives.spare)),
+                font,
+                TextColor(lavender),
+                node,
+                LivesText,
+            ));
+        }
+    }
+
+    // Active power-up strips (below the HUD bar, left; player 2's from the middle in co-op)
+    let strips: &[Val] = if coop.is_some() {
+        &[Val::Px(20.0), Val::Percent(50.0)]
+    } else {
+        &[Val::Px(20.0)]
+    };
+    for (player, left) in strips.iter().enumerate() {
+        commands.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(52.0),
+                left: *left,
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(10.0),
+                ..default()
+            },
+            PowerUpHud {
+                font: font_handle.clone(),
+                player,
+            },
+        ));
+    }
+
+    // Versus and puzzle are played without items or a ranking
+    if versus.is_none() && puzzle.is_none() {
+        // Bonus word letters (below the HUD bar, right)
+        commands.spawn((
+            Text::new(letters.display()),
+            TextFont {
+                font: font_handle.clone(),
+                font_size: 16.0,
+                font_smoothing: FontSmoothing::None,
+            },
+            TextColor(lavender),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(52.0),
+                right: Val::Px(20.0),
+                ..default()
+            },
+            BonusWordText,
+        ));
+
+        // High score text (top-center)
+        commands.spawn((
+            Text::new(format!("ハイスコア {}", high_scores.best())),
+            TextFont {
+                font: font_handle.clone(),
+                font_size: 16.0,
+                font_smoothing: FontSmoothing::None,
+            },
+            TextColor(lavender),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(12.0),
+                left: Val::Percent(50.0),
+                margin: UiRect::left(Val::Px(-30.0)),
+                ..default()
+            },
+            HighScoreText,
+        ));
+    }
 
     // Pause button (top-right, left of level text)
     commands
@@ -569,9 +865,38 @@ pub fn spawn_ui(mut commands: Commands, asset_server: Res<AssetServer>, high_sco
             ));
         });
 
+    // Time attack clock (left of the pause button)
+    if let Some(mode) = &time_attack {
+        commands.spawn((
+            Text::new(crate::systems::time_attack::format_clock(mode.level_budget)),
+            TextFont {
+                font: font_handle.clone(),
+                font_size: 24.0,
+                font_smoothing: FontSmoothing::None,
+            },
+            TextColor(warm_white),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(8.0),
+                right: Val::Px(210.0),
+                ..default()
+            },
+            TimeAttackText,
+        ));
+    }
+
     // Level text (top-right)
+    let level_label = if let Some(puzzle) = &puzzle {
+        puzzle_level_label(puzzle)
+    } else if daily.is_some() {
+        "デイリー".to_string()
+    } else if versus.is_some() {
+        "対戦".to_string()
+    } else {
+        "レベル 1".to_string()
+    };
     commands.spawn((
-        Text::new("レベル 1"),
+        Text::new(level_label),
         TextFont {
             font: font_handle,
             font_size: 24.0,
@@ -604,6 +929,8 @@ pub fn load_sounds(mut commands: Commands, asset_server: Res<AssetServer>) {
         bgm: asset_server.load_optional("sounds/bgm.wav"),
         countdown_beep: asset_server.load_optional("sounds/countdown_beep.wav"),
         countdown_go: asset_server.load_optional("sounds/countdown_go.wav"),
+        powerup: asset_server.load_optional("sounds/powerup.wav"),
+        hazard: asset_server.load_optional("sounds/hazard.wav"),
     };
     commands.insert_resource(sounds);
 }
@@ -716,6 +1043,48 @@ mod tests {
         assert!(blocks.iter().any(|bt| matches!(bt, BlockType::Durable { .. })), "Level 8 should have Durable blocks");
     }
 
+    #[test]
+    fn single_span_matches_standard_block() {
+        assert_eq!(block_span_size(BlockSpan::SINGLE), Vec2::new(BLOCK_WIDTH, BLOCK_HEIGHT));
+        assert_eq!(grid_block_center(2, 3, BlockSpan::SINGLE), Vec2::new(grid_x(3), grid_y(2)));
+    }
+
+    #[test]
+    fn multi_cell_block_covers_its_cells() {
+        let span = BlockSpan::new(3, 2);
+        let size = block_span_size(span);
+        let center = grid_block_center(1, 4, span);
+
+        // Outer edges line up with the outer edges of the corner cells
+        let left = grid_x(4) - BLOCK_WIDTH / 2.0;
+        let right = grid_x(6) + BLOCK_WIDTH / 2.0;
+        let top = grid_y(1) + BLOCK_HEIGHT / 2.0;
+        let bottom = grid_y(2) - BLOCK_HEIGHT / 2.0;
+        assert!((center.x - size.x / 2.0 - left).abs() < 0.01);
+        assert!((center.x + size.x / 2.0 - right).abs() < 0.01);
+        assert!((center.y + size.y / 2.0 - top).abs() < 0.01);
+        assert!((center.y - size.y / 2.0 - bottom).abs() < 0.01);
+    }
+
+    #[test]
+    fn spawn_grid_block_uses_span_size() {
+        let mut app = test_app();
+        app.add_systems(Update, |mut commands: Commands| {
+            spawn_grid_block(&mut commands, 0, 0, BlockSpan::new(2, 1), BlockType::Normal);
+        });
+        app.update();
+
+        let (sprite, collider) = app
+            .world_mut()
+            .query::<(&Sprite, &Collider)>()
+            .iter(app.world())
+            .next()
+            .unwrap();
+        let expected = block_span_size(BlockSpan::new(2, 1));
+        assert_eq!(sprite.custom_size, Some(expected));
+        assert_eq!(collider.size, expected);
+    }
+
     #[test]
     fn level_9_uses_generated() {
         // Level 9+ should use auto-generated; verify it still spawns blocks
@@ -723,4 +1092,33 @@ mod tests {
         assert!(!blocks.is_empty(), "Level 9 (generated) should spawn blocks");
         assert!(has_clearable_blocks(&blocks), "Level 9 must be clearable");
     }
+
+    #[test]
+    fn ball_kinds_follow_level_data() {
+        assert_eq!(level_ball_kind(1), BallKind::Normal);
+        assert_eq!(level_ball_kind(5), BallKind::Heavy);
+        assert_eq!(level_ball_kind(8), BallKind::Bouncy);
+        assert_eq!(level_ball_kind(9), BallKind::Normal, "Generated levels serve a normal ball");
+    }
+
+    #[test]
+    fn field_zones_follow_level_data() {
+        assert!(level_field_zones(1).is_empty());
+        assert!(matches!(level_field_zones(3)[0].0, FieldForce::Accel(a) if a.x > 0.0 && a.y == 0.0));
+        assert_eq!(level_field_zones(4).len(), 2);
+        assert!(matches!(level_field_zones(9)[0].0, FieldForce::Accel(a) if a.y < 0.0));
+
+        // Every zone stays inside the walls
+        let inner = Rect::new(
+            -WINDOW_WIDTH / 2.0 + WALL_THICKNESS,
+            -WINDOW_HEIGHT / 2.0,
+            WINDOW_WIDTH / 2.0 - WALL_THICKNESS,
+            WINDOW_HEIGHT / 2.0 - WALL_THICKNESS,
+        );
+        for level in 1..=20 {
+            for (_, rect) in level_field_zones(level) {
+                assert_eq!(inner.union(rect), inner, "Level {level} zone leaves the field");
+            }
+        }
+    }
 }
@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+
+use crate::components::*;
+use crate::constants::*;
+use crate::resources::*;
+use crate::states::GameState;
+use crate::systems::audio::CollisionEvent;
+
+/// End the run (with the game over sound) when the time attack clock runs out
+pub fn time_attack_clock(
+    mode: Res<TimeAttackMode>,
+    level_stats: Res<LevelStats>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut collision_events: EventWriter<CollisionEvent>,
+) {
+    if mode.remaining(&level_stats) <= 0.0 {
+        next_state.set(GameState::GameOver);
+        collision_events.send(CollisionEvent::GameOver);
+    }
+}
+
+/// Update the time attack clock display (turns red near zero)
+pub fn update_time_attack_text(
+    mode: Res<TimeAttackMode>,
+    level_stats: Res<LevelStats>,
+    mut query: Query<(&mut Text, &mut TextColor), With<TimeAttackText>>,
+) {
+    let remaining = mode.remaining(&level_stats);
+    for (mut text, mut color) in &mut query {
+        *text = Text::new(format_clock(remaining));
+        color.0 = if remaining < TIME_ATTACK_WARNING_TIME {
+            Color::srgb(0.92, 0.44, 0.44) // Salmon
+        } else {
+            Color::srgb(1.0, 0.96, 0.88) // Warm white
+        };
+    }
+}
+
+/// Carry the remaining time into the next level and add the clear bonus
+pub fn award_time_bonus(mut mode: ResMut<TimeAttackMode>, level_stats: Res<LevelStats>) {
+    let bonus = TimeAttackMode::clear_bonus(level_stats.time_elapsed);
+    mode.level_budget = mode.remaining(&level_stats) + bonus;
+    mode.last_bonus = bonus;
+}
+
+/// Clock text as m:ss (rounded up so 0:00 only shows when time is out)
+pub fn format_clock(seconds: f32) -> String {
+    let secs = seconds.max(0.0).ceil() as u32;
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    #[test]
+    fn clock_formats_minutes_and_seconds() {
+        assert_eq!(format_clock(90.0), "1:30");
+        assert_eq!(format_clock(9.2), "0:10");
+        assert_eq!(format_clock(-1.0), "0:00");
+    }
+
+    #[test]
+    fn clock_reaching_zero_ends_run() {
+        let mut app = test_app();
+        app.insert_resource(TimeAttackMode::default());
+        app.world_mut().resource_mut::<LevelStats>().time_elapsed = TIME_ATTACK_START;
+
+        app.init_resource::<CollectedEvents>();
+        app.add_systems(Update, (time_attack_clock, collect_collision_events).chain());
+        app.update();
+
+        assert!(matches!(
+            app.world().resource::<NextState<GameState>>(),
+            NextState::Pending(GameState::GameOver)
+        ));
+        let events = app.world().resource::<CollectedEvents>();
+        assert_eq!(events.events, vec![CollisionEvent::GameOver]);
+    }
+
+    #[test]
+    fn clear_bonus_carries_remaining_time() {
+        let mut app = test_app();
+        app.insert_resource(TimeAttackMode::default());
+        app.world_mut().resource_mut::<LevelStats>().time_elapsed = 40.0;
+
+        app.add_systems(Update, award_time_bonus);
+        app.update();
+
+        let mode = app.world().resource::<TimeAttackMode>();
+        let expected_bonus =
+            TIME_ATTACK_CLEAR_BONUS + (TIME_ATTACK_PAR_TIME - 40.0) * TIME_ATTACK_SPEED_BONUS_RATE;
+        assert_eq!(mode.last_bonus, expected_bonus);
+        assert_eq!(mode.level_budget, TIME_ATTACK_START - 40.0 + expected_bonus);
+    }
+
+    #[test]
+    fn slow_clear_earns_only_base_bonus() {
+        assert_eq!(TimeAttackMode::clear_bonus(TIME_ATTACK_PAR_TIME + 30.0), TIME_ATTACK_CLEAR_BONUS);
+    }
+}
@@ -0,0 +1,441 @@
+use bevy::prelude::*;
+use bevy::render::camera::{ClearColorConfig, ScalingMode, Viewport};
+use bevy::render::view::RenderLayers;
+use bevy::text::FontSmoothing;
+
+use crate::components::*;
+use crate::constants::*;
+use crate::resources::*;
+use crate::states::GameState;
+use crate::systems::audio::CollisionEvent;
+use crate::systems::endless::ROW_STEP;
+use crate::systems::setup::{generated_block_type, grid_x, grid_y, spawn_block};
+
+/// Render layer the main camera keeps to itself during versus (it only draws the HUD)
+const UI_ONLY_LAYER: usize = 1;
+
+/// Player colors shared by the paddles, HUD and result screen
+fn player_color(field: usize) -> Color {
+    if field == 0 {
+        Color::srgb(0.95, 0.85, 0.65) // Cream
+    } else {
+        Color::srgb(0.45, 0.75, 0.95) // Sky blue
+    }
+}
+
+/// Spawn the same opening layout and a danger line in every field
+pub fn spawn_versus_fields(commands: &mut Commands) {
+    for field in (0..VERSUS_FIELDS).map(PlayfieldId) {
+        let x0 = field.origin_x();
+        for row in 0..VERSUS_ROWS {
+            for col in 0..BLOCK_COLS {
+                let block_type = generated_block_type(VERSUS_LEVEL, row as u32, col as u32);
+                let block = spawn_block(commands, x0 + grid_x(col), grid_y(row), block_type, row);
+                commands.entity(block).insert(field);
+            }
+        }
+
+        commands.spawn((
+            Sprite {
+                color: Color::srgba(0.92, 0.44, 0.44, 0.35), // Salmon
+                custom_size: Some(Vec2::new(WINDOW_WIDTH - WALL_THICKNESS * 2.0, 2.0)),
+                ..default()
+            },
+            Transform::from_xyz(x0, VERSUS_DANGER_Y, -0.5),
+            DangerLine,
+        ));
+    }
+}
+
+/// HUD line for one player
+pub fn versus_status(versus: &VersusMode, field: usize) -> String {
+    let side = &versus.fields[field];
+    format!(
+        "{}P  残機 {}  スコア {}  送信 {}",
+        field + 1,
+        side.lives,
+        side.score.value,
+        side.garbage_sent
+    )
+}
+
+/// Give each field its own camera and leave the main camera drawing only the HUD
+#[allow(clippy::type_complexity)]
+pub fn setup_versus_cameras(
+    mut commands: Commands,
+    mut main_camera: Query<(Entity, &mut Camera), (With<Camera2d>, Without<FieldCamera>)>,
+) {
+    for field in (0..VERSUS_FIELDS).map(PlayfieldId) {
+        commands.spawn((
+            Camera2d,
+            Camera {
+                // Field cameras draw first; only the first clears the window
+                order: field.0 as isize - VERSUS_FIELDS as isize,
+                clear_color: if field.0 == 0 {
+                    ClearColorConfig::Default
+                } else {
+                    ClearColorConfig::None
+                },
+                ..default()
+            },
+            OrthographicProjection {
+                scaling_mode: ScalingMode::AutoMin {
+                    min_width: WINDOW_WIDTH,
+                    min_height: WINDOW_HEIGHT,
+                },
+                ..OrthographicProjection::default_2d()
+            },
+            Msaa::Off,
+            Transform::from_xyz(field.origin_x(), 0.0, 0.0),
+            FieldCamera,
+            field,
+        ));
+    }
+
+    for (entity, mut camera) in &mut main_camera {
+        camera.clear_color = ClearColorConfig::None;
+        commands.entity(entity).insert(RenderLayers::layer(UI_ONLY_LAYER));
+    }
+}
+
+/// Keep each field camera on its half of the window (follows resizes)
+pub fn update_versus_viewports(
+    windows: Query<&Window>,
+    mut cameras: Query<(&mut Camera, &PlayfieldId), With<FieldCamera>>,
+) {
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let size = window.physical_size();
+    let half = UVec2::new(size.x / VERSUS_FIELDS as u32, size.y);
+    if half.x == 0 || half.y == 0 {
+        return;
+    }
+
+    for (mut camera, field) in &mut cameras {
+        let position = UVec2::new(field.0 as u32 * half.x, 0);
+        let current = camera.viewport.as_ref().map(|v| (v.physical_position, v.physical_size));
+        if current != Some((position, half)) {
+            camera.viewport = Some(Viewport {
+                physical_position: position,
+                physical_size: half,
+                ..default()
+            });
+        }
+    }
+}
+
+/// Remove the field cameras and give the main camera the whole window back
+#[allow(clippy::type_complexity)]
+pub fn teardown_versus_cameras(
+    mut commands: Commands,
+    field_cameras: Query<Entity, With<FieldCamera>>,
+    mut main_camera: Query<(Entity, &mut Camera), (With<Camera2d>, Without<FieldCamera>)>,
+) {
+    if field_cameras.is_empty() {
+        return;
+    }
+    for entity in &field_cameras {
+        commands.entity(entity).despawn();
+    }
+    for (entity, mut camera) in &mut main_camera {
+        camera.clear_color = ClearColorConfig::Default;
+        commands.entity(entity).remove::<RenderLayers>();
+    }
+}
+
+/// Drop each player's combo when its window runs out
+pub fn tick_versus_combos(time: Res<Time>, mut versus: ResMut<VersusMode>) {
+    for side in &mut versus.fields {
+        if side.combo.count > 0 {
+            side.combo.timer.tick(time.delta());
+            if side.combo.timer.finished() {
+                side.combo.count = 0;
+            }
+        }
+    }
+}
+
+/// Push rows sent by the opponent into a field: everything moves down and the rows appear on top
+pub fn push_garbage_rows(
+    mut commands: Commands,
+    mut versus: ResMut<VersusMode>,
+    mut block_query: Query<(&mut Transform, &PlayfieldId), With<Block>>,
+) {
+    for field in (0..VERSUS_FIELDS).map(PlayfieldId) {
+        let rows = std::mem::take(&mut versus.fields[field.0].garbage_pending);
+        if rows == 0 {
+            continue;
+        }
+
+        for (mut transform, id) in &mut block_query {
+            if *id == field {
+                transform.translation.y -= rows as f32 * ROW_STEP;
+            }
+        }
+        for row in 0..rows as usize {
+            let gap = ((versus.fields[field.0].garbage_rng.roll() * BLOCK_COLS as f32) as usize).min(BLOCK_COLS - 1);
+            spawn_garbage_row(&mut commands, field, row, gap);
+        }
+    }
+}
+
+/// One row of two-hit blocks with a single gap at column `gap`
+fn spawn_garbage_row(commands: &mut Commands, field: PlayfieldId, row: usize, gap: usize) {
+    for col in (0..BLOCK_COLS).filter(|col| *col != gap) {
+        let block_type = BlockType::Durable { hits_remaining: 2 };
+        let block = spawn_block(commands, field.origin_x() + grid_x(col), grid_y(row), block_type, row);
+        commands.entity(block).insert(field);
+    }
+}
+
+/// A field whose blocks reach its danger line loses (steel sinks away harmlessly)
+pub fn versus_danger_check(
+    mut commands: Commands,
+    block_query: Query<(Entity, &Transform, &Collider, &Block, &PlayfieldId)>,
+    mut versus: ResMut<VersusMode>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut collision_events: EventWriter<CollisionEvent>,
+) {
+    for (entity, transform, collider, block, field) in &block_query {
+        if transform.translation.y - collider.size.y / 2.0 > VERSUS_DANGER_Y {
+            continue;
+        }
+        if block.block_type == BlockType::Steel {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        versus.decide(1 - field.0);
+        collision_events.send(CollisionEvent::GameOver);
+        next_state.set(GameState::GameOver);
+        return;
+    }
+}
+
+/// The first player to break every breakable block in their field wins
+pub fn versus_clear_check(
+    block_query: Query<(&Block, &PlayfieldId)>,
+    mut versus: ResMut<VersusMode>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for field in 0..VERSUS_FIELDS {
+        let remaining = block_query
+            .iter()
+            .any(|(block, id)| id.0 == field && block.block_type != BlockType::Steel);
+        if !remaining {
+            versus.decide(field);
+            next_state.set(GameState::GameOver);
+            return;
+        }
+    }
+}
+
+/// Refresh both players' status lines
+pub fn update_versus_hud(versus: Res<VersusMode>, mut query: Query<(&mut Text, &VersusStatusText)>) {
+    if versus.is_changed() {
+        for (mut text, status) in &mut query {
+            *text = Text::new(versus_status(&versus, status.field));
+        }
+    }
+}
+
+/// Setup the versus result screen (replaces the ranking; versus scores are not recorded)
+pub fn setup_versus_result(
+    mut commands: Commands,
+    versus: Res<VersusMode>,
+    asset_server: Res<AssetServer>,
+    bindings: Res<KeyBindings>,
+) {
+    let lavender = Color::srgb(0.55, 0.50, 0.65);
+    let font_handle: Handle<Font> = asset_server.load(GAME_FONT_PATH);
+    let winner = versus.winner.unwrap_or(0);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.12, 0.88)),
+            GameOverUI,
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn((
+                Text::new(format!("{}P の勝ち！", winner + 1)),
+                TextFont {
+                    font: font_handle.clone(),
+                    font_size: 48.0,
+                    font_smoothing: FontSmoothing::None,
+                },
+                TextColor(player_color(winner)),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+
+            // Per-player results
+            for (field, side) in versus.fields.iter().enumerate() {
+                parent.spawn((
+                    Text::new(format!(
+                        "{}P  スコア {}  送ったライン {}",
+                        field + 1,
+                        side.score.value,
+                        side.garbage_sent
+                    )),
+                    TextFont {
+                        font: font_handle.clone(),
+                        font_size: 24.0,
+                        font_smoothing: FontSmoothing::None,
+                    },
+                    TextColor(player_color(field)),
+                    TextLayout::new_with_justify(JustifyText::Center),
+                ));
+            }
+
+            parent.spawn((
+                Text::new(format!("{} / タップ でメニューへ", bindings.primary_label(InputAction::Confirm))),
+                TextFont {
+                    font: font_handle,
+                    font_size: 16.0,
+                    font_smoothing: FontSmoothing::None,
+                },
+                TextColor(lavender),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::*;
+
+    fn field_blocks(app: &mut App, field: usize) -> Vec<f32> {
+        app.world_mut()
+            .query_filtered::<(&Transform, &PlayfieldId), With<Block>>()
+            .iter(app.world())
+            .filter(|(_, id)| id.0 == field)
+            .map(|(t, _)| t.translation.y)
+            .collect()
+    }
+
+    #[test]
+    fn fields_start_with_the_same_layout() {
+        let mut app = test_app();
+        app.insert_resource(VersusMode::default());
+        app.add_systems(Update, crate::systems::setup::spawn_blocks);
+        app.update();
+
+        let first = field_blocks(&mut app, 0);
+        assert_eq!(first.len(), VERSUS_ROWS * BLOCK_COLS);
+        assert_eq!(first.len(), field_blocks(&mut app, 1).len());
+        let far_right = app
+            .world_mut()
+            .query::<(&Transform, &PlayfieldId)>()
+            .iter(app.world())
+            .filter(|(_, id)| id.0 == 1)
+            .all(|(t, _)| (t.translation.x - VERSUS_FIELD_SPACING).abs() < WINDOW_WIDTH / 2.0);
+        assert!(far_right, "Field 2 is laid out around its own origin");
+    }
+
+    #[test]
+    fn every_fourth_combo_block_sends_a_row() {
+        let mut versus = VersusMode::default();
+        versus.fields[0].combo.count = VERSUS_GARBAGE_COMBO - 1;
+        versus.send_garbage(0, 0);
+        assert_eq!(versus.fields[1].garbage_pending, 0);
+
+        versus.fields[0].combo.count = VERSUS_GARBAGE_COMBO;
+        versus.send_garbage(0, VERSUS_GARBAGE_COMBO - 1);
+        assert_eq!(versus.fields[1].garbage_pending, 1);
+        assert_eq!(versus.fields[0].garbage_sent, 1);
+        assert_eq!(versus.fields[0].garbage_pending, 0, "Nothing comes back to the sender");
+
+        // A combo that lapsed and restarted only counts from zero again
+        versus.fields[0].combo.count = 2;
+        versus.send_garbage(0, 0);
+        assert_eq!(versus.fields[1].garbage_pending, 1);
+    }
+
+    #[test]
+    fn combo_lapses_after_window() {
+        let mut app = test_app();
+        let mut versus = VersusMode::default();
+        versus.fields[1].combo.count = 2;
+        versus.fields[1].combo.timer = Timer::from_seconds(0.01, TimerMode::Once);
+        app.insert_resource(versus);
+
+        app.add_systems(Update, tick_versus_combos);
+        app.update();
+
+        assert_eq!(app.world().resource::<VersusMode>().fields[1].combo.count, 0);
+    }
+
+    #[test]
+    fn both_fields_draw_the_same_garbage_gaps() {
+        let mut versus = VersusMode::new(42);
+        let left: Vec<f32> = (0..4).map(|_| versus.fields[0].garbage_rng.roll()).collect();
+        let right: Vec<f32> = (0..4).map(|_| versus.fields[1].garbage_rng.roll()).collect();
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn garbage_lands_only_in_target_field() {
+        let mut app = test_app();
+        let mut versus = VersusMode::default();
+        versus.fields[1].garbage_pending = 1;
+        app.insert_resource(versus);
+        let own = spawn_test_block(app.world_mut(), Vec2::new(0.0, 100.0));
+        let target = spawn_test_block(app.world_mut(), Vec2::new(VERSUS_FIELD_SPACING, 100.0));
+        app.world_mut().entity_mut(target).insert(PlayfieldId(1));
+
+        app.add_systems(Update, push_garbage_rows);
+        app.update();
+
+        assert_eq!(app.world().get::<Transform>(own).unwrap().translation.y, 100.0);
+        assert_eq!(app.world().get::<Transform>(target).unwrap().translation.y, 100.0 - ROW_STEP);
+        assert_eq!(field_blocks(&mut app, 0).len(), 1);
+        assert_eq!(field_blocks(&mut app, 1).len(), 1 + BLOCK_COLS - 1, "Garbage row has one gap");
+        assert_eq!(app.world().resource::<VersusMode>().fields[1].garbage_pending, 0);
+    }
+
+    #[test]
+    fn block_at_danger_line_loses_the_match() {
+        let mut app = test_app();
+        app.insert_resource(VersusMode::default());
+        spawn_test_block(app.world_mut(), Vec2::new(0.0, 100.0));
+        let sunk = spawn_test_block(app.world_mut(), Vec2::new(VERSUS_FIELD_SPACING, VERSUS_DANGER_Y));
+        app.world_mut().entity_mut(sunk).insert(PlayfieldId(1));
+
+        app.add_systems(Update, versus_danger_check);
+        app.update();
+
+        assert_eq!(app.world().resource::<VersusMode>().winner, Some(0));
+        assert!(matches!(
+            app.world().resource::<NextState<GameState>>(),
+            NextState::Pending(GameState::GameOver)
+        ));
+    }
+
+    #[test]
+    fn clearing_own_field_wins() {
+        let mut app = test_app();
+        app.insert_resource(VersusMode::default());
+        spawn_test_block(app.world_mut(), Vec2::new(0.0, 100.0));
+        let steel = spawn_test_block_typed(
+            app.world_mut(),
+            Vec2::new(VERSUS_FIELD_SPACING, 100.0),
+            BlockType::Steel,
+        );
+        app.world_mut().entity_mut(steel).insert(PlayfieldId(1));
+
+        app.add_systems(Update, versus_clear_check);
+        app.update();
+
+        assert_eq!(app.world().resource::<VersusMode>().winner, Some(1), "Steel does not count");
+    }
+}
@@ -7,6 +7,7 @@ use crate::resources::*;
 use crate::states::GameState;
 use crate::systems::audio::CollisionEvent;
 use crate::systems::effects::TrailTimer;
+use crate::systems::powerup::LaserCooldown;
 
 /// Create a minimal Bevy App for testing with all resources and events registered.
 /// Includes a bootstrap frame (first update always has delta=0).
@@ -21,11 +22,23 @@ pub fn test_app() -> App {
     app.init_resource::<Level>();
     app.init_resource::<ScreenShake>();
     app.init_resource::<ComboTracker>();
-    app.insert_resource(HighScores { scores: [0, 0, 0] });
+    app.insert_resource(HighScores::default());
     app.init_resource::<LevelStats>();
+    app.init_resource::<Lives>();
+    app.init_resource::<PowerUpRegistry>();
+    app.init_resource::<BonusLetters>();
+    app.init_resource::<DropRng>();
+    app.init_resource::<SpeedRamp>();
+    app.init_resource::<KeyBindings>();
+    app.init_resource::<StickSettings>();
+    app.init_resource::<MouseSettings>();
+    app.init_resource::<GameSettings>();
+    app.init_resource::<ButtonInput<MouseButton>>();
     app.init_resource::<TrailTimer>();
+    app.init_resource::<LaserCooldown>();
     app.init_state::<GameState>();
     app.add_event::<CollisionEvent>();
+    app.add_event::<bevy::input::mouse::MouseMotion>();
     // Bootstrap frame: first update always produces delta=0
     app.update();
     app
@@ -126,3 +139,53 @@ pub fn collect_collision_events(
         collected.events.push(event.clone());
     }
 }
+
+/// Test app with an 800×800 primary window, a camera whose viewport is computed, and
+/// keyboard / touch input (see `send_touch`)
+pub fn windowed_test_app() -> App {
+    use bevy::input::touch::{touch_screen_input_system, TouchInput, Touches};
+    use bevy::render::camera::{camera_system, ManualTextureViews};
+    use bevy::window::{PrimaryWindow, WindowCreated, WindowResized, WindowScaleFactorChanged};
+
+    let mut app = test_app();
+    app.init_resource::<ButtonInput<KeyCode>>();
+    app.init_resource::<Touches>();
+    app.init_resource::<Assets<Image>>();
+    app.init_resource::<ManualTextureViews>();
+    app.add_event::<WindowCreated>();
+    app.add_event::<WindowResized>();
+    app.add_event::<WindowScaleFactorChanged>();
+    app.add_event::<AssetEvent<Image>>();
+    app.add_event::<TouchInput>();
+    app.world_mut().spawn((
+        Window {
+            resolution: (WINDOW_WIDTH, WINDOW_HEIGHT).into(),
+            ..default()
+        },
+        PrimaryWindow,
+    ));
+    app.world_mut().spawn(Camera2d);
+    app.add_systems(
+        PreUpdate,
+        (
+            camera_system::<OrthographicProjection>,
+            touch_screen_input_system,
+        ),
+    );
+    app
+}
+
+/// Queue a touch event at a screen position for the next update of a `windowed_test_app`
+pub fn send_touch(app: &mut App, phase: bevy::input::touch::TouchPhase, position: Vec2) {
+    let window = app
+        .world_mut()
+        .query_filtered::<Entity, With<bevy::window::PrimaryWindow>>()
+        .single(app.world());
+    app.world_mut().send_event(bevy::input::touch::TouchInput {
+        phase,
+        position,
+        window,
+        force: None,
+        id: 0,
+    });
+}
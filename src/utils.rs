@@ -11,6 +11,14 @@ pub fn aabb_collision(pos_a: Vec2, size_a: Vec2, pos_b: Vec2, size_b: Vec2) -> b
         && pos_a.y + half_a.y > pos_b.y - half_b.y
 }
 
+/// Distance from a point to the nearest edge of a rectangle (0 if inside)
+pub fn point_rect_distance(point: Vec2, rect_pos: Vec2, rect_size: Vec2) -> f32 {
+    let half = rect_size / 2.0;
+    let dx = ((point.x - rect_pos.x).abs() - half.x).max(0.0);
+    let dy = ((point.y - rect_pos.y).abs() - half.y).max(0.0);
+    Vec2::new(dx, dy).length()
+}
+
 /// Simple deterministic random-ish value in [0, 1) from a seed
 pub fn simple_rand(seed: u32) -> f32 {
     let n = seed.wrapping_mul(1103515245).wrapping_add(12345);
@@ -50,11 +58,22 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn point_rect_distance_measures_to_edge() {
+        let size = Vec2::new(20.0, 10.0);
+        // Inside the rectangle
+        assert_eq!(point_rect_distance(Vec2::new(3.0, 2.0), Vec2::ZERO, size), 0.0);
+        // Straight out from the right edge
+        assert!((point_rect_distance(Vec2::new(15.0, 0.0), Vec2::ZERO, size) - 5.0).abs() < 1e-5);
+        // Diagonal from the top-right corner (3-4-5 triangle)
+        assert!((point_rect_distance(Vec2::new(13.0, 9.0), Vec2::ZERO, size) - 5.0).abs() < 1e-5);
+    }
+
     #[test]
     fn simple_rand_range() {
         for seed in 0..1000 {
             let val = simple_rand(seed);
-            assert!(val >= 0.0 && val < 1.0, "seed {seed} produced {val}");
+            assert!((0.0..1.0).contains(&val), "seed {seed} produced {val}");
         }
     }
 
@@ -67,7 +86,7 @@ mod tests {
     fn rand_f32_in_range() {
         for _ in 0..100 {
             let val = rand_f32();
-            assert!(val >= 0.0 && val < 1.0);
+            assert!((0.0..1.0).contains(&val));
         }
     }
 }